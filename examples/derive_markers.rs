@@ -0,0 +1,135 @@
+use noether::laws::check_field_laws;
+use noether::Field;
+use num_traits::{Euclid, Inv, One, Zero};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// The two-element field 𝔽₂, where addition is exclusive or and multiplication is and.
+///
+/// Only the operators are written by hand. `#[derive(Field)]` adds the commutativity,
+/// associativity and distributivity markers, and the blanket impls do the rest. Removing any
+/// operator impl below turns into a compile error naming that operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Field)]
+struct Bit(bool);
+
+impl Add for Bit {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Bit(self.0 != rhs.0)
+    }
+}
+
+impl AddAssign for Bit {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Bit {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Bit(self.0 != rhs.0)
+    }
+}
+
+impl SubAssign for Bit {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Bit {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl Mul for Bit {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Bit(self.0 && rhs.0)
+    }
+}
+
+impl MulAssign for Bit {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Inv for Bit {
+    type Output = Self;
+    fn inv(self) -> Self {
+        assert!(self.0, "Division by zero in F2");
+        self
+    }
+}
+
+impl Div for Bit {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl DivAssign for Bit {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Rem for Bit {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        assert!(rhs.0, "Division by zero in F2");
+        Bit(false)
+    }
+}
+
+impl Euclid for Bit {
+    fn div_euclid(&self, v: &Self) -> Self {
+        *self / *v
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        *self % *v
+    }
+}
+
+impl Zero for Bit {
+    fn zero() -> Self {
+        Bit(false)
+    }
+
+    fn is_zero(&self) -> bool {
+        !self.0
+    }
+}
+
+impl One for Bit {
+    fn one() -> Self {
+        Bit(true)
+    }
+}
+
+fn assert_field<F: Field>() {}
+
+fn main() {
+    // Test 1: the derived markers complete the Field hierarchy
+    assert_field::<Bit>();
+
+    // Test 2: the field laws hold on every triple
+    let elements = [Bit(false), Bit(true)];
+    for &a in &elements {
+        for &b in &elements {
+            for &c in &elements {
+                assert!(
+                    check_field_laws(a, b, c),
+                    "field laws fail at {a:?}, {b:?}, {c:?}"
+                );
+            }
+        }
+    }
+    println!("Bit: field laws hold on all 8 triples");
+}
@@ -1,6 +1,6 @@
 use noether::{
-    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, CommutativeMultiplication,
-    Distributive, FiniteField,
+    AssociativeAddition, AssociativeMultiplication, CanonicalAssociate, CommutativeAddition,
+    CommutativeMultiplication, Distributive, FiniteField,
 };
 use num_traits::{Euclid, Inv, One, Zero};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
@@ -174,6 +174,9 @@ impl<const L: usize, const D: usize> AssociativeMultiplication for FinitePrimeFi
 /// Marker trait for distributive multiplication over addition: a * (b + c) = (a * b) + (a * c)
 impl<const L: usize, const D: usize> Distributive for FinitePrimeField<L, D> {}
 
+/// The default unit makes every non-zero gcd one
+impl<const L: usize, const D: usize> CanonicalAssociate for FinitePrimeField<L, D> {}
+
 impl<const L: usize, const D: usize> FiniteField for FinitePrimeField<L, D> {
     fn characteristic() -> u64 {
         todo!()
@@ -182,6 +185,10 @@ impl<const L: usize, const D: usize> FiniteField for FinitePrimeField<L, D> {
     fn order() -> u64 {
         todo!()
     }
+
+    fn primitive_element() -> Self {
+        todo!()
+    }
 }
 
 fn main() {
@@ -0,0 +1,64 @@
+use noether::laws::{
+    check_commutative_multiplication, check_field_laws, check_order_add_compat,
+    check_order_mul_compat, check_ring_laws,
+};
+use noether::{PrimeField, Ratio};
+
+/// The marker traits only claim algebraic laws. The `laws` module evaluates them on samples,
+/// so a type's markers can be checked exhaustively when it is small, or on a spread of
+/// representative values otherwise.
+fn main() {
+    // Test 1: ring laws over i64 on small values, where no operation overflows
+    let samples: Vec<i64> = (-6..=6).collect();
+    let mut checked = 0;
+    for &a in &samples {
+        for &b in &samples {
+            for &c in &samples {
+                assert!(check_ring_laws(a, b, c), "ring laws fail at {a}, {b}, {c}");
+                assert!(check_commutative_multiplication(a, b));
+                checked += 1;
+            }
+        }
+    }
+    println!("i64: ring laws hold on {checked} triples");
+
+    // Test 2: field laws over every triple of PrimeField<7>
+    type F7 = PrimeField<7>;
+    let elements: Vec<F7> = (0..7).map(F7::new).collect();
+    let mut checked = 0;
+    for &a in &elements {
+        for &b in &elements {
+            for &c in &elements {
+                assert!(
+                    check_field_laws(a, b, c),
+                    "field laws fail at {a}, {b}, {c}"
+                );
+                checked += 1;
+            }
+        }
+    }
+    println!("PrimeField<7>: field laws hold on all {checked} triples");
+
+    // Test 3: the order on Ratio<i64> is compatible with its ring operations
+    let fractions: Vec<Ratio<i64>> = (-3..=3)
+        .flat_map(|n| (1..=3).map(move |d| Ratio::new(n, d)))
+        .collect();
+    let mut checked = 0;
+    for a in &fractions {
+        for b in &fractions {
+            for c in &fractions {
+                assert!(check_order_add_compat(a.clone(), b.clone(), c.clone()));
+                checked += 1;
+            }
+            assert!(check_order_mul_compat(a.clone(), b.clone()));
+        }
+    }
+    println!("Ratio<i64>: order compatibility holds on {checked} triples");
+
+    // Test 4: floating point only satisfies the laws approximately
+    let (a, b, c) = (0.1f64, 0.2, 0.3);
+    println!(
+        "f64: ring laws hold on (0.1, 0.2, 0.3): {}",
+        check_ring_laws(a, b, c)
+    );
+}
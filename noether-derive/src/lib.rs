@@ -0,0 +1,279 @@
+//! Derive macros that emit the algebraic marker traits of `noether`.
+//!
+//! The markers (`CommutativeAddition`, `AssociativeMultiplication`, `Distributive`, ...) carry
+//! no methods, so a derive only has to name the right set for a structure. The `Closed*` traits
+//! and the structure traits themselves follow from noether's blanket impls once the arithmetic
+//! operators are implemented. Each derive also checks those operators at compile time, so a
+//! missing impl is reported by name, e.g. "the trait bound `Foo: Neg` is not satisfied".
+//!
+//! Enable through the `derive` feature of `noether` rather than depending on this crate directly.
+
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+
+const ADDITIVE_MARKERS: &[&str] = &["CommutativeAddition", "AssociativeAddition"];
+
+const RING_MARKERS: &[&str] = &[
+    "CommutativeAddition",
+    "AssociativeAddition",
+    "CommutativeMultiplication",
+    "AssociativeMultiplication",
+    "Distributive",
+];
+
+const FIELD_MARKERS: &[&str] = &[
+    "CommutativeAddition",
+    "AssociativeAddition",
+    "CommutativeMultiplication",
+    "AssociativeMultiplication",
+    "Distributive",
+    "CanonicalAssociate",
+];
+
+const ADDITIVE_OPS: &[&str] = &[
+    "::core::clone::Clone",
+    "::core::cmp::PartialEq",
+    "::core::ops::Add<Output = T>",
+    "::core::ops::AddAssign",
+    "::core::ops::Sub<Output = T>",
+    "::core::ops::SubAssign",
+    "::core::ops::Neg<Output = T>",
+    "::noether::ClosedZero",
+];
+
+const MULTIPLICATIVE_OPS: &[&str] = &[
+    "::core::ops::Mul<Output = T>",
+    "::core::ops::MulAssign",
+    "::noether::ClosedOne",
+];
+
+const DIVISION_OPS: &[&str] = &[
+    "::core::ops::Div<Output = T>",
+    "::core::ops::DivAssign",
+    "::core::ops::Rem<Output = T>",
+    "::noether::ClosedInv",
+    "::num_traits::Euclid",
+];
+
+/// Derives the markers of an abelian group under addition.
+///
+/// Requires `Clone`, `PartialEq`, `Add`, `AddAssign`, `Sub`, `SubAssign`, `Neg` and `Zero`.
+#[proc_macro_derive(AdditiveAbelianGroup)]
+pub fn derive_additive_abelian_group(input: TokenStream) -> TokenStream {
+    expand(
+        input,
+        "AdditiveAbelianGroup",
+        ADDITIVE_MARKERS,
+        &[ADDITIVE_OPS],
+    )
+}
+
+/// Derives the markers of a commutative ring.
+///
+/// Requires the operators of `AdditiveAbelianGroup` plus `Mul`, `MulAssign` and `One`.
+#[proc_macro_derive(CommutativeRing)]
+pub fn derive_commutative_ring(input: TokenStream) -> TokenStream {
+    expand(
+        input,
+        "CommutativeRing",
+        RING_MARKERS,
+        &[ADDITIVE_OPS, MULTIPLICATIVE_OPS],
+    )
+}
+
+/// Derives the markers of a field.
+///
+/// Requires the operators of `CommutativeRing` plus `Div`, `DivAssign`, `Rem`, `Inv` and
+/// `Euclid`. Also emits `CanonicalAssociate` with its default unit, which makes every non-zero
+/// gcd one.
+#[proc_macro_derive(Field)]
+pub fn derive_field(input: TokenStream) -> TokenStream {
+    expand(
+        input,
+        "Field",
+        FIELD_MARKERS,
+        &[ADDITIVE_OPS, MULTIPLICATIVE_OPS, DIVISION_OPS],
+    )
+}
+
+/// The parts of a type definition needed to write impls for it.
+struct TypeHeader {
+    name: String,
+    /// Generic parameters with their bounds, defaults removed, for `impl<...>`.
+    impl_generics: Vec<String>,
+    /// Generic parameter names, for `Name<...>`.
+    type_generics: Vec<String>,
+    where_clause: String,
+}
+
+fn expand(input: TokenStream, structure: &str, markers: &[&str], ops: &[&[&str]]) -> TokenStream {
+    let header = match parse_header(input) {
+        Ok(header) => header,
+        Err(message) => return compile_error(&message),
+    };
+
+    let impl_generics = header.impl_generics.join(", ");
+    let ty = if header.type_generics.is_empty() {
+        header.name.clone()
+    } else {
+        format!("{}<{}>", header.name, header.type_generics.join(", "))
+    };
+    let where_clause = &header.where_clause;
+
+    let mut output = String::new();
+    for marker in markers {
+        output +=
+            &format!("impl<{impl_generics}> ::noether::{marker} for {ty} {where_clause} {{}}\n");
+    }
+
+    // One helper per operator, so a missing impl is reported against that operator
+    let mut helpers = String::new();
+    let mut calls = String::new();
+    for (i, bound) in ops.iter().flat_map(|group| group.iter()).enumerate() {
+        helpers += &format!("fn requires_{i}<T: {bound}>() {{}}\n");
+        calls += &format!("requires_{i}::<{ty}>();\n");
+    }
+    output += &format!(
+        "const _: () = {{
+            {helpers}
+            fn requires_structure<T: ::noether::{structure}>() {{}}
+            #[allow(dead_code)]
+            fn check<{impl_generics}>() {where_clause} {{
+                {calls}
+                requires_structure::<{ty}>();
+            }}
+        }};\n"
+    );
+
+    output
+        .parse()
+        .unwrap_or_else(|_| compile_error("noether-derive produced invalid tokens"))
+}
+
+fn compile_error(message: &str) -> TokenStream {
+    format!("::core::compile_error!({message:?});")
+        .parse()
+        .unwrap()
+}
+
+fn parse_header(input: TokenStream) -> Result<TypeHeader, String> {
+    let mut tokens = input.into_iter().peekable();
+
+    // Skip attributes and visibility up to the item keyword
+    loop {
+        match tokens.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == '#' => {
+                tokens.next();
+            }
+            Some(TokenTree::Ident(ident))
+                if matches!(ident.to_string().as_str(), "struct" | "enum" | "union") =>
+            {
+                break
+            }
+            Some(_) => {}
+            None => return Err("expected a struct, enum or union".to_string()),
+        }
+    }
+
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        _ => return Err("expected a type name".to_string()),
+    };
+
+    // Generic parameters, split on top-level commas
+    let mut params: Vec<Vec<TokenTree>> = Vec::new();
+    if matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '<') {
+        tokens.next();
+        let mut depth = 0usize;
+        let mut current = Vec::new();
+        let mut after_dash = false;
+        for token in tokens.by_ref() {
+            if let TokenTree::Punct(p) = &token {
+                match p.as_char() {
+                    '<' => depth += 1,
+                    // `->` in a bound such as `Fn() -> T` does not close a bracket
+                    '>' if !after_dash => {
+                        if depth == 0 {
+                            break;
+                        }
+                        depth -= 1;
+                    }
+                    ',' if depth == 0 => {
+                        params.push(std::mem::take(&mut current));
+                        continue;
+                    }
+                    _ => {}
+                }
+                after_dash = p.as_char() == '-' && p.spacing() == Spacing::Joint;
+            } else {
+                after_dash = false;
+            }
+            current.push(token);
+        }
+        if !current.is_empty() {
+            params.push(current);
+        }
+    }
+
+    // A where clause precedes the braced body, or follows the parenthesized fields
+    let mut where_clause = Vec::new();
+    let mut in_where = false;
+    for token in tokens {
+        match &token {
+            TokenTree::Ident(ident) if ident.to_string() == "where" => in_where = true,
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => break,
+            TokenTree::Punct(p) if p.as_char() == ';' => break,
+            _ => {}
+        }
+        if in_where {
+            where_clause.push(token);
+        }
+    }
+
+    let mut impl_generics = Vec::new();
+    let mut type_generics = Vec::new();
+    for param in params {
+        impl_generics.push(join(param[..default_start(&param)].iter()));
+        let param_name = match param.first() {
+            Some(TokenTree::Punct(p)) if p.as_char() == '\'' => join(param.iter().take(2)),
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "const" => {
+                join(param.iter().skip(1).take(1))
+            }
+            _ => join(param.iter().take(1)),
+        };
+        type_generics.push(param_name);
+    }
+
+    Ok(TypeHeader {
+        name,
+        impl_generics,
+        type_generics,
+        where_clause: join(where_clause.iter()),
+    })
+}
+
+/// Returns the index of the `=` starting the default of a generic parameter, or its length.
+///
+/// Only an `=` outside angle brackets starts the default; the one in an associated type
+/// binding such as `T: Iterator<Item = u8>` is part of the bound.
+fn default_start(param: &[TokenTree]) -> usize {
+    let mut depth = 0usize;
+    let mut after_dash = false;
+    for (i, token) in param.iter().enumerate() {
+        if let TokenTree::Punct(p) = token {
+            match p.as_char() {
+                '<' => depth += 1,
+                '>' if !after_dash => depth = depth.saturating_sub(1),
+                '=' if depth == 0 => return i,
+                _ => {}
+            }
+            after_dash = p.as_char() == '-' && p.spacing() == Spacing::Joint;
+        } else {
+            after_dash = false;
+        }
+    }
+    param.len()
+}
+
+fn join<'a>(tokens: impl Iterator<Item = &'a TokenTree>) -> String {
+    tokens.cloned().collect::<TokenStream>().to_string()
+}
@@ -0,0 +1,79 @@
+//! Compiles the programs in `tests/ui` against `noether` with the `derive` feature.
+//!
+//! Each `.rs` file is built as its own binary. Files with a matching `.stderr` must fail to
+//! compile with every line of that file appearing in the compiler output; the others must
+//! compile cleanly.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[test]
+fn ui() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let ui_dir = manifest_dir.join("tests/ui");
+    let project = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ui");
+    fs::create_dir_all(project.join("src/bin")).unwrap();
+
+    let mut cases: Vec<PathBuf> = fs::read_dir(&ui_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().map_or(false, |ext| ext == "rs"))
+        .collect();
+    cases.sort();
+    assert!(!cases.is_empty(), "no UI cases in {}", ui_dir.display());
+
+    let noether = manifest_dir.parent().unwrap();
+    fs::write(
+        project.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"noether-ui\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\
+             publish = false\n\n[dependencies]\nnoether = {{ path = {:?}, features = [\"derive\"] }}\n\
+             num-traits = \"0.2.19\"\n\n[workspace]\n",
+            noether
+        ),
+    )
+    .unwrap();
+    // Reuse the workspace lockfile so the build resolves the same versions offline
+    fs::copy(noether.join("Cargo.lock"), project.join("Cargo.lock")).unwrap();
+    for stale in fs::read_dir(project.join("src/bin")).unwrap() {
+        fs::remove_file(stale.unwrap().path()).unwrap();
+    }
+    for case in &cases {
+        fs::copy(
+            case,
+            project.join("src/bin").join(case.file_name().unwrap()),
+        )
+        .unwrap();
+    }
+
+    let mut failures = Vec::new();
+    for case in &cases {
+        let name = case.file_stem().unwrap().to_str().unwrap();
+        let output = Command::new(env!("CARGO"))
+            .args(["check", "--quiet", "--bin", name])
+            .current_dir(&project)
+            .env("CARGO_TARGET_DIR", project.join("target"))
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        match fs::read_to_string(case.with_extension("stderr")) {
+            Ok(expected) => {
+                if output.status.success() {
+                    failures.push(format!("{name}: expected a compile error"));
+                }
+                for line in expected.lines().filter(|line| !line.trim().is_empty()) {
+                    if !stderr.contains(line.trim()) {
+                        failures.push(format!("{name}: missing `{line}` in\n{stderr}"));
+                    }
+                }
+            }
+            Err(_) => {
+                if !output.status.success() {
+                    failures.push(format!("{name}: failed to compile\n{stderr}"));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
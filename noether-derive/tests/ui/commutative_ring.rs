@@ -0,0 +1,75 @@
+// Deriving CommutativeRing on a type with the ring operators completes the hierarchy.
+use noether::CommutativeRing;
+use num_traits::{One, Zero};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// The integers modulo 4, which form a commutative ring but not a field.
+#[derive(Clone, Copy, PartialEq, CommutativeRing)]
+struct Z4(u8);
+
+impl Add for Z4 {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Z4((self.0 + other.0) % 4)
+    }
+}
+
+impl AddAssign for Z4 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Z4 {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl SubAssign for Z4 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Z4 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Z4((4 - self.0) % 4)
+    }
+}
+
+impl Mul for Z4 {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Z4(self.0 * other.0 % 4)
+    }
+}
+
+impl MulAssign for Z4 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Zero for Z4 {
+    fn zero() -> Self {
+        Z4(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Z4 {
+    fn one() -> Self {
+        Z4(1)
+    }
+}
+
+fn assert_ring<R: CommutativeRing>() {}
+
+fn main() {
+    assert_ring::<Z4>();
+}
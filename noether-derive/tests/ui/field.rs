@@ -0,0 +1,116 @@
+// Deriving Field on a type with the field operators completes the hierarchy.
+use noether::Field;
+use num_traits::{Euclid, Inv, One, Zero};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// The two-element field, where addition is exclusive or and multiplication is and.
+#[derive(Clone, Copy, PartialEq, Field)]
+struct Bit(bool);
+
+impl Add for Bit {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Bit(self.0 != rhs.0)
+    }
+}
+
+impl AddAssign for Bit {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Bit {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Bit(self.0 != rhs.0)
+    }
+}
+
+impl SubAssign for Bit {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Bit {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl Mul for Bit {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Bit(self.0 && rhs.0)
+    }
+}
+
+impl MulAssign for Bit {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Inv for Bit {
+    type Output = Self;
+    fn inv(self) -> Self {
+        assert!(self.0, "Division by zero in F2");
+        self
+    }
+}
+
+impl Div for Bit {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl DivAssign for Bit {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Rem for Bit {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        assert!(rhs.0, "Division by zero in F2");
+        Bit(false)
+    }
+}
+
+impl Euclid for Bit {
+    fn div_euclid(&self, v: &Self) -> Self {
+        *self / *v
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        *self % *v
+    }
+}
+
+impl Zero for Bit {
+    fn zero() -> Self {
+        Bit(false)
+    }
+
+    fn is_zero(&self) -> bool {
+        !self.0
+    }
+}
+
+impl One for Bit {
+    fn one() -> Self {
+        Bit(true)
+    }
+}
+
+fn assert_field<F: Field>() {}
+
+fn main() {
+    assert_field::<Bit>();
+}
@@ -0,0 +1,55 @@
+// Bounds with associated type bindings and parameter defaults must survive the derive.
+use noether::AdditiveAbelianGroup;
+use num_traits::Zero;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+#[derive(Clone, PartialEq, AdditiveAbelianGroup)]
+struct W<T: Iterator<Item = u8> + Clone + PartialEq, const N: usize = 3>(Option<T>);
+
+impl<T: Iterator<Item = u8> + Clone + PartialEq, const N: usize> Add for W<T, N> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        W(self.0.or(other.0))
+    }
+}
+
+impl<T: Iterator<Item = u8> + Clone + PartialEq, const N: usize> AddAssign for W<T, N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = self.clone() + other;
+    }
+}
+
+impl<T: Iterator<Item = u8> + Clone + PartialEq, const N: usize> Sub for W<T, N> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + other
+    }
+}
+
+impl<T: Iterator<Item = u8> + Clone + PartialEq, const N: usize> SubAssign for W<T, N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = self.clone() - other;
+    }
+}
+
+impl<T: Iterator<Item = u8> + Clone + PartialEq, const N: usize> Neg for W<T, N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl<T: Iterator<Item = u8> + Clone + PartialEq, const N: usize> Zero for W<T, N> {
+    fn zero() -> Self {
+        W(None)
+    }
+    fn is_zero(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+fn assert_group<G: AdditiveAbelianGroup>() {}
+
+fn main() {
+    assert_group::<W<std::ops::Range<u8>>>();
+}
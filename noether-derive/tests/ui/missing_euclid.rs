@@ -0,0 +1,101 @@
+// Deriving Field without a Euclid impl names the missing trait.
+use noether::Field;
+use num_traits::{Inv, One, Zero};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+#[derive(Clone, Copy, PartialEq, Field)]
+struct NoEuclid(bool);
+
+impl Add for NoEuclid {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        NoEuclid(self.0 != rhs.0)
+    }
+}
+
+impl AddAssign for NoEuclid {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for NoEuclid {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        NoEuclid(self.0 != rhs.0)
+    }
+}
+
+impl SubAssign for NoEuclid {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for NoEuclid {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl Mul for NoEuclid {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        NoEuclid(self.0 && rhs.0)
+    }
+}
+
+impl MulAssign for NoEuclid {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Inv for NoEuclid {
+    type Output = Self;
+    fn inv(self) -> Self {
+        assert!(self.0, "Division by zero in F2");
+        self
+    }
+}
+
+impl Div for NoEuclid {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl DivAssign for NoEuclid {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Rem for NoEuclid {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        assert!(rhs.0, "Division by zero in F2");
+        NoEuclid(false)
+    }
+}
+
+impl Zero for NoEuclid {
+    fn zero() -> Self {
+        NoEuclid(false)
+    }
+
+    fn is_zero(&self) -> bool {
+        !self.0
+    }
+}
+
+impl One for NoEuclid {
+    fn one() -> Self {
+        NoEuclid(true)
+    }
+}
+
+fn main() {}
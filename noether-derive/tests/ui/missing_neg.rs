@@ -0,0 +1,44 @@
+// Deriving a structure without one of its operators names the missing operator.
+use noether::AdditiveAbelianGroup;
+use num_traits::Zero;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+#[derive(Clone, Copy, PartialEq, AdditiveAbelianGroup)]
+struct Missing(i64);
+
+impl Add for Missing {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Missing(self.0 + other.0)
+    }
+}
+
+impl AddAssign for Missing {
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+    }
+}
+
+impl Sub for Missing {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Missing(self.0 - other.0)
+    }
+}
+
+impl SubAssign for Missing {
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0;
+    }
+}
+
+impl Zero for Missing {
+    fn zero() -> Self {
+        Missing(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+fn main() {}
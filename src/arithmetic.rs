@@ -0,0 +1,389 @@
+use crate::{AdditiveMonoid, MultiplicativeGroup, MultiplicativeMonoid, Semiring};
+
+/// Returns the inner product Σ aᵢ · bᵢ of two slices over a semiring.
+///
+/// The empty sum is zero. Over the tropical semiring (min, +) this computes the min-plus
+/// inner product minᵢ(aᵢ + bᵢ).
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn dot<R: Semiring>(a: &[R], b: &[R]) -> R {
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "dot product of slices with different lengths"
+    );
+    a.iter()
+        .zip(b)
+        .fold(R::zero(), |acc, (x, y)| acc + x.clone() * y.clone())
+}
+
+/// Returns baseᵉˣᵖ in a multiplicative monoid by square-and-multiply.
+///
+/// Uses at most 2 · ⌊log₂ exp⌋ + 1 multiplications. The zeroth power is one.
+pub fn pow<M: MultiplicativeMonoid>(base: M, mut exp: u64) -> M {
+    let mut result = M::one();
+    let mut square = base;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result *= square.clone();
+        }
+        exp >>= 1;
+        if exp > 0 {
+            square = square.clone() * square;
+        }
+    }
+    result
+}
+
+/// Returns baseᵉˣᵖ in a multiplicative group, where negative exponents invert the base.
+///
+/// # Panics
+///
+/// Panics if exp is negative and base has no inverse, as for zero in a field.
+pub fn pow_signed<G: MultiplicativeGroup>(base: G, exp: i64) -> G {
+    if exp < 0 {
+        pow(base.inv(), exp.unsigned_abs())
+    } else {
+        pow(base, exp as u64)
+    }
+}
+
+/// Returns whether n is the square of an integer.
+///
+/// Starts from the floating-point square root and corrects it in exact arithmetic, so the
+/// answer is exact for all of u64.
+pub fn is_perfect_square(n: u64) -> bool {
+    let mut root = (n as f64).sqrt() as u64;
+    while (root as u128) * (root as u128) > n as u128 {
+        root -= 1;
+    }
+    while ((root + 1) as u128) * ((root + 1) as u128) <= n as u128 {
+        root += 1;
+    }
+    (root as u128) * (root as u128) == n as u128
+}
+
+/// Returns the multi-scalar product Σ kᵢ · Pᵢ over an additive monoid, by Pippenger's method.
+///
+/// Scalars are split into windows of c bits. For each window, every point is added to the
+/// bucket of its digit, and the buckets are combined with a running sum so that bucket j is
+/// counted j times. Windows are processed from the most significant down, doubling the
+/// accumulator c times between them. With c ≈ log₂ n this costs about 64 · n / log₂ n
+/// additions instead of the 64 · n of separate double-and-add multiplications. The empty sum is
+/// zero.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn msm<M: AdditiveMonoid>(scalars: &[u64], points: &[M]) -> M {
+    assert_eq!(
+        scalars.len(),
+        points.len(),
+        "msm of slices with different lengths"
+    );
+    let window = match scalars.len() {
+        0..=3 => 1,
+        n => (usize::BITS - n.leading_zeros()) as usize - 1,
+    }
+    .min(16);
+    let windows = (64 + window - 1) / window;
+    let mask = (1u64 << window) - 1;
+
+    let mut result = M::zero();
+    for w in (0..windows).rev() {
+        for _ in 0..window {
+            result = result.clone() + result.clone();
+        }
+        let mut buckets = vec![M::zero(); 1 << window];
+        for (scalar, point) in scalars.iter().zip(points) {
+            let digit = (scalar >> (w * window)) & mask;
+            if digit != 0 {
+                buckets[digit as usize] += point.clone();
+            }
+        }
+        // Summing the suffix sums of the buckets counts bucket j exactly j times.
+        let mut running = M::zero();
+        for bucket in buckets.into_iter().skip(1).rev() {
+            running += bucket;
+            result += running.clone();
+        }
+    }
+    result
+}
+
+/// Returns the geometric sum 1 + r + r² + ... + rⁿ over a semiring.
+///
+/// Writing Sₖ = 1 + r + ... + rᵏ⁻¹, the doubling identities S₂ₖ = Sₖ · (1 + rᵏ) and
+/// Sₖ₊₁ = Sₖ + rᵏ build Sₙ₊₁ from the bits of n + 1 in O(log n) multiplications. No division
+/// is needed, so this works in any semiring, including rings where 1 − r is not invertible.
+pub fn geometric_sum<R: Semiring>(r: R, n: u64) -> R {
+    let terms = n as u128 + 1;
+    let mut sum = R::zero();
+    let mut power = R::one();
+    for bit in (0..u128::BITS - terms.leading_zeros()).rev() {
+        sum = sum.clone() + power.clone() * sum;
+        power = power.clone() * power;
+        if (terms >> bit) & 1 == 1 {
+            sum += power.clone();
+            power *= r.clone();
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SplitMix64;
+    use crate::{EcPoint, PrimeField, Ratio, Tropical, Vector, VectorSpace, WeierstrassCurve};
+    use num_traits::{One, Zero};
+
+    type F13 = PrimeField<13>;
+
+    #[test]
+    fn pow_zero_is_one() {
+        assert_eq!(pow(5i64, 0), 1);
+        assert_eq!(pow(0i64, 0), 1);
+        assert_eq!(pow(F13::new(7), 0), F13::one());
+    }
+
+    #[test]
+    fn pow_agrees_with_repeated_multiplication() {
+        for base in -4i64..=4 {
+            let mut naive = 1i64;
+            for exp in 0..20 {
+                assert_eq!(pow(base, exp), naive, "{base}^{exp}");
+                naive *= base;
+            }
+        }
+        for base in 0..13 {
+            let mut naive = F13::one();
+            for exp in 0..30 {
+                assert_eq!(pow(F13::new(base), exp), naive);
+                naive *= F13::new(base);
+            }
+        }
+    }
+
+    #[test]
+    fn pow_with_large_exponents() {
+        // Fermat: a¹² = 1 for every non-zero a in 𝔽₁₃
+        assert_eq!(pow(F13::new(2), 12), F13::one());
+        assert_eq!(pow(F13::new(2), u64::MAX), pow(F13::new(2), u64::MAX % 12));
+    }
+
+    #[test]
+    fn pow_signed_inverts_negative_exponents() {
+        let half = Ratio::new(1i64, 2);
+        assert_eq!(pow_signed(half.clone(), -3), Ratio::from(8));
+        assert_eq!(pow_signed(half, 2), Ratio::new(1, 4));
+        assert_eq!(pow_signed(F13::new(2), -1) * F13::new(2), F13::one());
+    }
+
+    #[test]
+    fn dot_product_of_integer_slices() {
+        assert_eq!(dot(&[1i64, 2, 3], &[4, 5, 6]), 32);
+        assert_eq!(dot(&[-1i64, 2], &[3, 3]), 3);
+        assert_eq!(dot(&[2u32, 7], &[5, 1]), 17);
+    }
+
+    #[test]
+    fn dot_product_over_fields() {
+        let a = [Ratio::new(1i64, 2), Ratio::new(-2, 3)];
+        let b = [Ratio::new(4i64, 1), Ratio::new(3, 4)];
+        assert_eq!(dot(&a, &b), Ratio::new(3, 2));
+        assert_eq!(
+            dot(&[F13::new(5), F13::new(6)], &[F13::new(5), F13::new(2)]),
+            F13::new(11)
+        );
+    }
+
+    #[test]
+    fn dot_product_over_the_tropical_semiring() {
+        let t = Tropical::new;
+        // min(3 + 1, −2 + 7, 5 + 0) = 4
+        assert_eq!(
+            dot(&[t(3.0), t(-2.0), t(5.0)], &[t(1.0), t(7.0), t(0.0)]),
+            t(4.0)
+        );
+        // Relaxing distances to a node through three neighbours, one unreachable.
+        let distances = [t(0.0), t(2.5), Tropical::zero()];
+        let edges = [t(4.0), t(1.0), t(0.5)];
+        assert_eq!(dot(&distances, &edges), t(3.5));
+        assert!(dot::<Tropical<f64>>(&[], &[]).is_zero());
+    }
+
+    #[test]
+    fn dot_product_of_empty_slices_is_zero() {
+        assert_eq!(dot::<i64>(&[], &[]), 0);
+        assert!(dot::<F13>(&[], &[]).is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "different lengths")]
+    fn dot_product_of_mismatched_slices_panics() {
+        let _ = dot(&[1i64, 2], &[3]);
+    }
+
+    type F97 = PrimeField<97>;
+
+    /// The points of y² = x³ + x + 1 over 𝔽₉₇, which generate the whole group.
+    fn curve_points() -> Vec<EcPoint<F97>> {
+        let curve = WeierstrassCurve::new(F97::new(1), F97::new(1));
+        (0..97)
+            .flat_map(|x| (0..97).map(move |y| (x, y)))
+            .filter_map(|(x, y)| curve.point(F97::new(x), F97::new(y)))
+            .collect()
+    }
+
+    #[test]
+    fn msm_over_curve_points_matches_the_naive_sum() {
+        let points = curve_points();
+        let mut rng = SplitMix64::new(264);
+        for n in [1, 2, 5, 17, 40] {
+            let chosen: Vec<EcPoint<F97>> = (0..n)
+                .map(|_| points[rng.next_u64() as usize % points.len()].clone())
+                .collect();
+            let scalars: Vec<u64> = (0..n).map(|_| rng.next_u64()).collect();
+            let naive = scalars
+                .iter()
+                .zip(&chosen)
+                .fold(EcPoint::zero(), |sum, (&k, p)| sum + p.scalar_mul(k));
+            assert_eq!(msm(&scalars, &chosen), naive);
+        }
+    }
+
+    #[test]
+    fn msm_over_vectors_matches_the_naive_sum() {
+        let mut rng = SplitMix64::new(2640);
+        for n in [1, 3, 8, 33] {
+            let vectors: Vec<Vector<F97, 3>> = (0..n)
+                .map(|_| Vector::new([(); 3].map(|_| F97::new(rng.next_u64()))))
+                .collect();
+            let scalars: Vec<u64> = (0..n).map(|_| rng.next_u64()).collect();
+            let naive = scalars
+                .iter()
+                .zip(&vectors)
+                .fold(Vector::zero(), |sum, (&k, v)| sum + v.scale(&F97::new(k)));
+            assert_eq!(msm(&scalars, &vectors), naive);
+        }
+    }
+
+    #[test]
+    fn msm_of_no_terms_is_zero() {
+        assert!(msm::<EcPoint<F97>>(&[], &[]).is_zero());
+        assert!(msm::<Vector<F97, 3>>(&[], &[]).is_zero());
+    }
+
+    #[test]
+    fn msm_of_a_single_term_is_a_scalar_multiple() {
+        let p = curve_points()[5].clone();
+        for k in [0, 1, 2, 96, u64::MAX] {
+            assert_eq!(msm(&[k], std::slice::from_ref(&p)), p.scalar_mul(k));
+        }
+        let v = Vector::new([F97::new(3), F97::new(0), F97::new(50)]);
+        assert_eq!(msm(&[u64::MAX], &[v]), v.scale(&F97::new(u64::MAX)));
+    }
+
+    #[test]
+    #[should_panic(expected = "different lengths")]
+    fn msm_of_mismatched_slices_panics() {
+        let _ = msm(&[1, 2], &[5i64]);
+    }
+
+    #[test]
+    fn perfect_squares() {
+        assert!(is_perfect_square(16));
+        assert!(!is_perfect_square(15));
+        assert!(is_perfect_square(0) && is_perfect_square(1));
+        let squares: Vec<u64> = (0..50).filter(|&n| is_perfect_square(n)).collect();
+        assert_eq!(squares, [0, 1, 4, 9, 16, 25, 36, 49]);
+    }
+
+    #[test]
+    fn perfect_squares_beyond_float_precision() {
+        // Near 2⁶⁴ neighbouring integers share a float, so the root needs exact correction.
+        let root = u32::MAX as u64;
+        assert!(is_perfect_square(root * root));
+        assert!(!is_perfect_square(root * root - 1));
+        assert!(!is_perfect_square(root * root + 1));
+        assert!(!is_perfect_square(u64::MAX));
+        let root = (1u64 << 26) + 1;
+        assert!(is_perfect_square(root * root));
+        assert!(!is_perfect_square(root * root + 1));
+    }
+
+    fn naive_geometric_sum<R: Semiring>(r: R, n: u64) -> R {
+        let mut sum = R::zero();
+        let mut power = R::one();
+        for _ in 0..=n {
+            sum += power.clone();
+            power *= r.clone();
+        }
+        sum
+    }
+
+    #[test]
+    fn geometric_sums_of_integers() {
+        assert_eq!(geometric_sum(2i64, 4), 31);
+        assert_eq!(geometric_sum(3i64, 3), 40);
+        assert_eq!(geometric_sum(-1i64, 5), 0);
+        assert_eq!(geometric_sum(-1i64, 6), 1);
+        for r in -5i64..=5 {
+            for n in 0..20 {
+                assert_eq!(
+                    geometric_sum(r, n),
+                    naive_geometric_sum(r, n),
+                    "r = {r}, n = {n}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn geometric_sums_over_a_prime_field() {
+        type F7 = PrimeField<7>;
+        // 1 + 3 + 2 + 6 = 12 ≡ 5 (mod 7)
+        assert_eq!(geometric_sum(F7::new(3), 3), F7::new(5));
+        // 3 generates 𝔽₇ˣ, so the sum over a full period rᵏ, k < 6, vanishes.
+        assert_eq!(geometric_sum(F7::new(3), 5), F7::zero());
+        for r in 0..7 {
+            for n in 0..30 {
+                assert_eq!(
+                    geometric_sum(F7::new(r), n),
+                    naive_geometric_sum(F7::new(r), n)
+                );
+            }
+        }
+        assert_eq!(
+            geometric_sum(F7::new(3), u64::MAX),
+            geometric_sum(F7::new(3), u64::MAX % 6)
+        );
+    }
+
+    #[test]
+    fn geometric_sum_of_no_powers_beyond_the_first_is_one() {
+        assert_eq!(geometric_sum(9i64, 0), 1);
+        assert_eq!(geometric_sum(0i64, 0), 1);
+        assert_eq!(geometric_sum(F13::new(4), 0), F13::one());
+    }
+
+    #[test]
+    fn geometric_sum_of_one_counts_the_terms() {
+        for n in [0, 1, 2, 7, 100, 1 << 20] {
+            assert_eq!(geometric_sum(1i64, n), n as i64 + 1);
+            assert_eq!(geometric_sum(F13::one(), n), F13::new(n + 1));
+        }
+        assert_eq!(geometric_sum(0i64, 10), 1);
+    }
+
+    #[test]
+    fn geometric_sum_over_the_tropical_semiring() {
+        // min(0, r, 2r, ..., nr) is 0 for r ≥ 0 and nr for r < 0.
+        let t = Tropical::new;
+        assert_eq!(geometric_sum(t(2.0), 10), t(0.0));
+        assert_eq!(geometric_sum(t(-2.0), 10), t(-20.0));
+    }
+}
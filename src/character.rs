@@ -0,0 +1,429 @@
+use crate::{
+    AdditiveAbelianGroup, AssociativeAddition, CommutativeAddition, Complex, PrimeField,
+    ReductionStrategy, Ring, Vector,
+};
+use num_traits::{One, Zero};
+use std::f64::consts::TAU;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Represents the cyclic group ℤ/Nℤ under addition.
+///
+/// # Mathematical Definition
+/// Elements are the residues {0, 1, ..., N − 1}, added modulo N. Unlike `PrimeField`, N need
+/// not be prime, and no multiplication is defined: ℤ/Nℤ has zero divisors for composite N, so
+/// it is only used here as an additive group.
+///
+/// # Properties
+/// - Every finite abelian group is a direct sum of groups ℤ/Nℤ
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Zn<const N: u64> {
+    value: u64,
+}
+
+impl<const N: u64> Zn<N> {
+    /// Creates the residue of value modulo N.
+    ///
+    /// # Panics
+    ///
+    /// Panics if N is zero.
+    pub fn new(value: u64) -> Self {
+        assert!(N > 0, "Zn modulus must be positive");
+        Self { value: value % N }
+    }
+
+    /// Returns the canonical representative in [0, N).
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl<const N: u64> Add for Zn<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        // Widened, so the sum cannot overflow for N near 2⁶⁴
+        Self::new(((self.value as u128 + other.value as u128) % N as u128) as u64)
+    }
+}
+
+impl<const N: u64> AddAssign for Zn<N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: u64> Neg for Zn<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(N - self.value)
+    }
+}
+
+impl<const N: u64> Sub for Zn<N> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<const N: u64> SubAssign for Zn<N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const N: u64> Zero for Zn<N> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const N: u64> CommutativeAddition for Zn<N> {}
+impl<const N: u64> AssociativeAddition for Zn<N> {}
+
+/// Represents a finite abelian group in invariant-factor coordinates.
+///
+/// # Mathematical Definition
+/// G ≅ ℤ/n₁ ⊕ ... ⊕ ℤ/nᵣ, where (n₁, ..., nᵣ) are the `invariants`, and an element is given by
+/// its coordinates (g₁, ..., gᵣ) with 0 ≤ gⱼ < nⱼ.
+///
+/// # Properties
+/// - |G| = n₁ · ... · nᵣ
+/// - `coordinates` is a group isomorphism onto the direct sum: coordinates of a sum are the
+///   sums of coordinates modulo the invariants
+pub trait FiniteAbelianGroup: AdditiveAbelianGroup {
+    /// Returns the orders n₁, ..., nᵣ of the cyclic factors.
+    fn invariants() -> Vec<u64>;
+
+    /// Returns the coordinates of self, one residue per cyclic factor.
+    fn coordinates(&self) -> Vec<u64>;
+
+    /// Returns the element with the given coordinates, the inverse of `coordinates`.
+    fn from_coordinates(coordinates: &[u64]) -> Self;
+
+    /// Returns the number of elements.
+    fn order() -> u64 {
+        Self::invariants().iter().product()
+    }
+
+    /// Returns every element, with the coordinates counting up like the digits of a mixed-radix
+    /// number whose first coordinate varies fastest.
+    fn elements() -> Vec<Self> {
+        let invariants = Self::invariants();
+        (0..Self::order())
+            .map(|mut n| {
+                let digits: Vec<u64> = invariants
+                    .iter()
+                    .map(|&m| {
+                        let digit = n % m;
+                        n /= m;
+                        digit
+                    })
+                    .collect();
+                Self::from_coordinates(&digits)
+            })
+            .collect()
+    }
+}
+
+impl<const N: u64> FiniteAbelianGroup for Zn<N> {
+    fn invariants() -> Vec<u64> {
+        vec![N]
+    }
+
+    fn coordinates(&self) -> Vec<u64> {
+        vec![self.value]
+    }
+
+    fn from_coordinates(coordinates: &[u64]) -> Self {
+        Self::new(coordinates[0])
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> FiniteAbelianGroup for PrimeField<P, R> {
+    /// The additive group of 𝔽ₚ is cyclic of order P.
+    fn invariants() -> Vec<u64> {
+        vec![P]
+    }
+
+    fn coordinates(&self) -> Vec<u64> {
+        vec![self.value()]
+    }
+
+    fn from_coordinates(coordinates: &[u64]) -> Self {
+        Self::new(coordinates[0])
+    }
+}
+
+impl<T: Ring + FiniteAbelianGroup, const K: usize> FiniteAbelianGroup for Vector<T, K> {
+    /// The direct sum of K copies of T, with the invariants of each copy in turn.
+    fn invariants() -> Vec<u64> {
+        T::invariants().repeat(K)
+    }
+
+    fn coordinates(&self) -> Vec<u64> {
+        self.components()
+            .iter()
+            .flat_map(FiniteAbelianGroup::coordinates)
+            .collect()
+    }
+
+    fn from_coordinates(coordinates: &[u64]) -> Self {
+        let width = T::invariants().len();
+        Vector::new(std::array::from_fn(|i| {
+            T::from_coordinates(&coordinates[i * width..(i + 1) * width])
+        }))
+    }
+}
+
+/// Represents a character of a finite abelian group G, a homomorphism χ: G → ℂ*.
+///
+/// # Mathematical Definition
+/// For G ≅ ℤ/n₁ ⊕ ... ⊕ ℤ/nᵣ, every character is χₖ(g) = exp(2πi Σⱼ kⱼ gⱼ / nⱼ) for a unique
+/// index k = (k₁, ..., kᵣ) with 0 ≤ kⱼ < nⱼ. The characters form the dual group Ĝ under
+/// pointwise multiplication, χₖ · χₗ = χₖ₊ₗ.
+///
+/// # Properties
+/// - Ĝ ≅ G, so there are exactly |G| characters
+/// - Orthogonality: Σ_g χ(g) · conj(ψ(g)) is |G| if χ = ψ and 0 otherwise
+/// - Values are |G|-th roots of unity, computed in floating point
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Character<G: FiniteAbelianGroup> {
+    index: Vec<u64>,
+    group: PhantomData<G>,
+}
+
+impl<G: FiniteAbelianGroup> Character<G> {
+    /// Creates the character χₖ, reducing each index entry modulo its invariant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` does not have one entry per cyclic factor of G.
+    pub fn new(index: &[u64]) -> Self {
+        let invariants = G::invariants();
+        assert_eq!(
+            index.len(),
+            invariants.len(),
+            "expected one index per cyclic factor"
+        );
+        Self {
+            index: index.iter().zip(&invariants).map(|(k, n)| k % n).collect(),
+            group: PhantomData,
+        }
+    }
+
+    /// Returns the trivial character, χ(g) = 1 for all g.
+    pub fn trivial() -> Self {
+        Self::new(&vec![0; G::invariants().len()])
+    }
+
+    /// Returns the index k of χₖ.
+    pub fn index(&self) -> &[u64] {
+        &self.index
+    }
+
+    /// Returns χ(g) = exp(2πi Σⱼ kⱼ gⱼ / nⱼ).
+    pub fn evaluate(&self, g: &G) -> Complex<f64> {
+        // Each term is reduced to a fraction of a turn before summing, so large indices do not
+        // lose precision in the angle.
+        let turns: f64 = G::invariants()
+            .iter()
+            .zip(&self.index)
+            .zip(g.coordinates())
+            .map(|((&n, &k), x)| ((k as u128 * x as u128) % n as u128) as f64 / n as f64)
+            .sum();
+        let angle = TAU * turns;
+        Complex::new(angle.cos(), angle.sin())
+    }
+}
+
+impl<G: FiniteAbelianGroup> Mul for Character<G> {
+    type Output = Self;
+
+    /// Returns the pointwise product, (χ · ψ)(g) = χ(g) · ψ(g).
+    fn mul(self, other: Self) -> Self {
+        let index: Vec<u64> = self
+            .index
+            .iter()
+            .zip(&other.index)
+            .zip(G::invariants())
+            .map(|((&k, &l), n)| ((k as u128 + l as u128) % n as u128) as u64)
+            .collect();
+        Self::new(&index)
+    }
+}
+
+impl<G: FiniteAbelianGroup> One for Character<G> {
+    fn one() -> Self {
+        Self::trivial()
+    }
+}
+
+/// Returns the dual group Ĝ, listing χₖ for k running over the coordinates of `G::elements()`.
+pub fn dual_group<G: FiniteAbelianGroup>() -> Vec<Character<G>> {
+    G::elements()
+        .iter()
+        .map(|g| Character::new(&g.coordinates()))
+        .collect()
+}
+
+/// Returns the Fourier transform f̂(χ) = Σ_g f(g) · conj(χ(g)) of a function on G.
+///
+/// `values` lists f on `G::elements()` and the result lists f̂ on `dual_group::<G>()`.
+///
+/// # Complexity
+/// O(|G|²) character evaluations.
+///
+/// # Panics
+///
+/// Panics if `values` does not have one entry per element of G.
+pub fn dft<G: FiniteAbelianGroup>(values: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let elements = G::elements();
+    assert_eq!(
+        values.len(),
+        elements.len(),
+        "expected one value per group element"
+    );
+    dual_group::<G>()
+        .iter()
+        .map(|chi| {
+            elements
+                .iter()
+                .zip(values)
+                .fold(Complex::zero(), |sum, (g, f)| {
+                    sum + *f * chi.evaluate(g).conjugate()
+                })
+        })
+        .collect()
+}
+
+/// Returns the inverse transform f(g) = (1 / |G|) Σ_χ f̂(χ) · χ(g), undoing `dft`.
+///
+/// # Panics
+///
+/// Panics if `coefficients` does not have one entry per character of G.
+pub fn inverse_dft<G: FiniteAbelianGroup>(coefficients: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let dual = dual_group::<G>();
+    assert_eq!(
+        coefficients.len(),
+        dual.len(),
+        "expected one coefficient per character"
+    );
+    let scale = Complex::from(1.0 / dual.len() as f64);
+    G::elements()
+        .iter()
+        .map(|g| {
+            let sum = dual
+                .iter()
+                .zip(coefficients)
+                .fold(Complex::zero(), |sum, (chi, c)| sum + *c * chi.evaluate(g));
+            sum * scale
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SplitMix64;
+
+    fn close(a: Complex<f64>, b: Complex<f64>) -> bool {
+        (a - b).norm_squared() < 1e-18
+    }
+
+    fn check_orthogonality<G: FiniteAbelianGroup>() {
+        let (elements, dual) = (G::elements(), dual_group::<G>());
+        let order = G::order() as f64;
+        for (i, chi) in dual.iter().enumerate() {
+            for (j, psi) in dual.iter().enumerate() {
+                let sum = elements.iter().fold(Complex::zero(), |sum, g| {
+                    sum + chi.evaluate(g) * psi.evaluate(g).conjugate()
+                });
+                let expected = if i == j { order } else { 0.0 };
+                assert!(close(sum, Complex::from(expected)), "rows {i}, {j}");
+            }
+        }
+        // The dual relation, summing over characters instead of elements
+        for (i, g) in elements.iter().enumerate() {
+            for (j, h) in elements.iter().enumerate() {
+                let sum = dual.iter().fold(Complex::zero(), |sum, chi| {
+                    sum + chi.evaluate(g) * chi.evaluate(h).conjugate()
+                });
+                let expected = if i == j { order } else { 0.0 };
+                assert!(close(sum, Complex::from(expected)), "columns {i}, {j}");
+            }
+        }
+    }
+
+    #[test]
+    fn z4_has_four_characters() {
+        let dual = dual_group::<Zn<4>>();
+        assert_eq!(dual.len(), 4);
+        let one = Zn::<4>::new(1);
+        // χₖ is fixed by χₖ(1) = iᵏ
+        let powers_of_i = [
+            Complex::new(1.0, 0.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(-1.0, 0.0),
+            Complex::new(0.0, -1.0),
+        ];
+        for (chi, expected) in dual.iter().zip(powers_of_i) {
+            assert!(close(chi.evaluate(&one), expected));
+        }
+        for chi in &dual {
+            for g in Zn::<4>::elements() {
+                for h in Zn::<4>::elements() {
+                    let product = chi.evaluate(&g) * chi.evaluate(&h);
+                    assert!(close(chi.evaluate(&(g + h)), product));
+                }
+            }
+        }
+        assert_eq!(dual[0], Character::trivial());
+        assert_eq!(dual[1].clone() * dual[3].clone(), Character::one());
+        assert_eq!(dual[2].clone() * dual[3].clone(), dual[1]);
+    }
+
+    #[test]
+    fn characters_are_orthogonal() {
+        check_orthogonality::<Zn<4>>();
+        check_orthogonality::<Zn<6>>();
+        check_orthogonality::<PrimeField<5>>();
+        check_orthogonality::<Vector<PrimeField<3>, 2>>();
+        assert_eq!(dual_group::<Vector<PrimeField<3>, 2>>().len(), 9);
+    }
+
+    #[test]
+    fn dft_round_trips() {
+        let mut rng = SplitMix64::new(246);
+        let mut random = |n: u64| -> Vec<Complex<f64>> {
+            (0..n)
+                .map(|_| Complex::new(rng.range_f64(-1.0, 1.0), rng.range_f64(-1.0, 1.0)))
+                .collect()
+        };
+        for f in [random(4), random(4), vec![Complex::zero(); 4]] {
+            let recovered = inverse_dft::<Zn<4>>(&dft::<Zn<4>>(&f));
+            assert!(recovered.iter().zip(&f).all(|(a, b)| close(*a, *b)));
+        }
+        let f = random(9);
+        let recovered =
+            inverse_dft::<Vector<PrimeField<3>, 2>>(&dft::<Vector<PrimeField<3>, 2>>(&f));
+        assert!(recovered.iter().zip(&f).all(|(a, b)| close(*a, *b)));
+        // The point mass at 0 transforms to the constant 1, and a constant to a point mass.
+        let mut delta = vec![Complex::zero(); 12];
+        delta[0] = Complex::one();
+        assert!(dft::<Zn<12>>(&delta)
+            .iter()
+            .all(|c| close(*c, Complex::one())));
+        let spectrum = dft::<Zn<12>>(&[Complex::one(); 12]);
+        assert!(close(spectrum[0], Complex::from(12.0)));
+        assert!(spectrum[1..].iter().all(|c| close(*c, Complex::zero())));
+    }
+}
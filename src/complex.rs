@@ -0,0 +1,274 @@
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CanonicalAssociate, CommutativeAddition,
+    CommutativeMultiplication, Distributive, Field,
+};
+use num_traits::{Euclid, Inv, One, Zero};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Represents a complex number a + bi with components in a field F.
+///
+/// # Mathematical Definition
+/// The complex numbers over F are the quotient F\[x\] / (x² + 1), with i the class of x:
+/// - (a + bi) + (c + di) = (a + c) + (b + d)i
+/// - (a + bi) · (c + di) = (ac − bd) + (ad + bc)i
+/// - (a + bi)⁻¹ = (a − bi) / (a² + b²)
+///
+/// # Properties
+/// - This is a field exactly when x² + 1 is irreducible over F, i.e. −1 is not a square in F.
+///   That holds for every ordered field, and for 𝔽ₚ with p ≡ 3 (mod 4). Otherwise some
+///   non-zero element has norm zero and inverting it panics
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Complex<F: Field> {
+    re: F,
+    im: F,
+}
+
+impl<F: Field> Complex<F> {
+    /// Creates the complex number re + im · i.
+    pub fn new(re: F, im: F) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns the imaginary unit i.
+    pub fn i() -> Self {
+        Self::new(F::zero(), F::one())
+    }
+
+    /// Returns the real part.
+    pub fn re(&self) -> &F {
+        &self.re
+    }
+
+    /// Returns the imaginary part.
+    pub fn im(&self) -> &F {
+        &self.im
+    }
+
+    /// Returns the conjugate a − bi.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.re.clone(), -self.im.clone())
+    }
+
+    /// Returns the norm a² + b², which equals z · z̄.
+    pub fn norm_squared(&self) -> F {
+        self.re.clone() * self.re.clone() + self.im.clone() * self.im.clone()
+    }
+}
+
+impl<F: Field> From<F> for Complex<F> {
+    fn from(re: F) -> Self {
+        Self::new(re, F::zero())
+    }
+}
+
+impl<F: Field + fmt::Display> fmt::Display for Complex<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}i", self.re, self.im)
+    }
+}
+
+impl<F: Field> Add for Complex<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<F: Field> AddAssign for Complex<F> {
+    fn add_assign(&mut self, other: Self) {
+        self.re += other.re;
+        self.im += other.im;
+    }
+}
+
+impl<F: Field> Neg for Complex<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<F: Field> Sub for Complex<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<F: Field> SubAssign for Complex<F> {
+    fn sub_assign(&mut self, other: Self) {
+        self.re -= other.re;
+        self.im -= other.im;
+    }
+}
+
+impl<F: Field> Mul for Complex<F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.re.clone() * other.re.clone() - self.im.clone() * other.im.clone(),
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl<F: Field> MulAssign for Complex<F> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = std::mem::replace(self, Self::zero()) * other;
+    }
+}
+
+impl<F: Field> Inv for Complex<F> {
+    type Output = Self;
+
+    /// Returns the conjugate divided by the norm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the norm is zero.
+    fn inv(self) -> Self {
+        let norm = self.norm_squared();
+        assert!(
+            !norm.is_zero(),
+            "Inverse of a complex number with zero norm"
+        );
+        let scale = norm.inv();
+        Self::new(self.re * scale.clone(), -self.im * scale)
+    }
+}
+
+impl<F: Field> Div for Complex<F> {
+    type Output = Self;
+
+    /// Multiplies by the inverse of the divisor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor has zero norm.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.inv()
+    }
+}
+
+impl<F: Field> DivAssign for Complex<F> {
+    fn div_assign(&mut self, other: Self) {
+        *self = std::mem::replace(self, Self::zero()) / other;
+    }
+}
+
+impl<F: Field> Rem for Complex<F> {
+    type Output = Self;
+
+    /// Returns zero, as division in a field is exact.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    fn rem(self, other: Self) -> Self {
+        assert!(!other.is_zero(), "Division by a zero complex number");
+        Self::zero()
+    }
+}
+
+impl<F: Field> Euclid for Complex<F> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        self.clone() / v.clone()
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        self.clone() % v.clone()
+    }
+}
+
+impl<F: Field> Zero for Complex<F> {
+    fn zero() -> Self {
+        Self::new(F::zero(), F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<F: Field> One for Complex<F> {
+    fn one() -> Self {
+        Self::new(F::one(), F::zero())
+    }
+}
+
+impl<F: Field> CommutativeAddition for Complex<F> {}
+impl<F: Field> AssociativeAddition for Complex<F> {}
+impl<F: Field> CommutativeMultiplication for Complex<F> {}
+impl<F: Field> AssociativeMultiplication for Complex<F> {}
+impl<F: Field> Distributive for Complex<F> {}
+impl<F: Field> CanonicalAssociate for Complex<F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::check_field_laws;
+    use crate::Ratio;
+
+    type Q = Ratio<i64>;
+
+    fn c(re: (i64, i64), im: (i64, i64)) -> Complex<Q> {
+        Complex::new(Q::new(re.0, re.1), Q::new(im.0, im.1))
+    }
+
+    #[test]
+    fn i_squared_is_minus_one() {
+        let i = Complex::<Q>::i();
+        assert_eq!(i.clone() * i, -Complex::one());
+    }
+
+    #[test]
+    fn inverse_times_self_is_one() {
+        let samples = [
+            c((1, 1), (1, 1)),
+            c((3, 4), (-2, 5)),
+            c((0, 1), (7, 1)),
+            c((-5, 2), (0, 1)),
+        ];
+        for z in samples {
+            assert_eq!(z.clone() * z.inv(), Complex::one());
+        }
+    }
+
+    #[test]
+    fn multiplication_formula() {
+        // (1 + 2i)(3 − i) = 5 + 5i
+        assert_eq!(c((1, 1), (2, 1)) * c((3, 1), (-1, 1)), c((5, 1), (5, 1)));
+    }
+
+    #[test]
+    fn conjugate_and_norm() {
+        let z = c((3, 1), (4, 1));
+        assert_eq!(z.conjugate(), c((3, 1), (-4, 1)));
+        assert_eq!(z.norm_squared(), Q::from(25));
+        assert_eq!(z.clone() * z.conjugate(), Complex::from(Q::from(25)));
+    }
+
+    #[test]
+    fn field_laws_hold() {
+        let (a, b, d) = (c((1, 2), (1, 1)), c((0, 1), (-3, 1)), c((2, 1), (1, 3)));
+        assert!(check_field_laws(a.clone(), b.clone(), d.clone()));
+        assert!(check_field_laws(d, a, b));
+    }
+
+    #[test]
+    #[should_panic]
+    fn inverse_of_zero_panics() {
+        let _ = Complex::<Q>::zero().inv();
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Complex::new(1.5f64, -2.0).to_string(), "1.5 + -2i");
+    }
+}
@@ -0,0 +1,269 @@
+use crate::arithmetic::dot;
+use crate::{AssociativeAddition, CommutativeAddition, Field, Matrix, Ring, Vector, VectorSpace};
+use num_traits::Zero;
+use std::ops::{Add, AddAssign, Index, Neg, Sub, SubAssign};
+
+/// Represents a covector, a linear functional on the coordinate space Fᴺ.
+///
+/// # Mathematical Definition
+/// The dual space (Fᴺ)* consists of the linear maps φ: Fᴺ → F. In coordinates a covector is the
+/// row (φ₀, ..., φₙ₋₁) with φ(v) = Σᵢ φᵢ vᵢ, and the dual basis e₀*, ..., eₙ₋₁* of the standard
+/// basis is defined by eᵢ*(eⱼ) = δᵢⱼ.
+///
+/// # Properties
+/// - (Fᴺ)* is a vector space of dimension N under pointwise addition and scaling
+/// - An inner product identifies Fᴺ with its dual by v ↦ ⟨v, ·⟩; the `From` conversions use the
+///   standard one, and `from_inner_product` and `to_vector` take a Gram matrix
+/// - The double dual is naturally isomorphic to Fᴺ without any choice of inner product: a
+///   vector v acts on covectors by evaluation, v(φ) = φ(v), which is `Vector::evaluate`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoVector<T: Ring, const N: usize> {
+    components: [T; N],
+}
+
+impl<T: Ring, const N: usize> CoVector<T, N> {
+    /// Creates the covector with the given components, its values on the standard basis.
+    pub fn new(components: [T; N]) -> Self {
+        Self { components }
+    }
+
+    /// Returns the components of the covector.
+    pub fn components(&self) -> &[T; N] {
+        &self.components
+    }
+
+    /// Returns φ(v) = Σᵢ φᵢ vᵢ, the pairing of the covector with a vector.
+    pub fn apply(&self, v: &Vector<T, N>) -> T {
+        dot(&self.components, v.components())
+    }
+
+    /// Returns the dual basis e₀*, ..., eₙ₋₁*, with eᵢ*(eⱼ) = δᵢⱼ.
+    pub fn dual_basis() -> [Self; N] {
+        std::array::from_fn(|i| Self::map(|j| if i == j { T::one() } else { T::zero() }))
+    }
+
+    /// Returns the covector w ↦ ⟨v, w⟩ = vᵀ G w for the bilinear form with Gram matrix G.
+    pub fn from_inner_product(v: &Vector<T, N>, gram: &Matrix<T, N, N>) -> Self {
+        Self::map(|i| {
+            (0..N).fold(T::zero(), |sum, j| {
+                sum + v[j].clone() * gram[(j, i)].clone()
+            })
+        })
+    }
+
+    /// Builds a covector whose i-th component is f(i).
+    fn map(f: impl Fn(usize) -> T) -> Self {
+        Self::new(std::array::from_fn(f))
+    }
+}
+
+impl<T: Field, const N: usize> CoVector<T, N> {
+    /// Returns the vector v with ⟨v, ·⟩ = self for the form with Gram matrix G, the inverse of
+    /// `from_inner_product`. Returns `None` if G is singular, when no such vector is unique.
+    pub fn to_vector(&self, gram: &Matrix<T, N, N>) -> Option<Vector<T, N>> {
+        let (v, kernel) = gram
+            .transpose()
+            .solve_general(&Vector::new(self.components.clone()))?;
+        if kernel.is_empty() {
+            Some(v)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Ring, const N: usize> Vector<T, N> {
+    /// Returns v(φ) = φ(v), the action of self on a covector as an element of the double dual.
+    pub fn evaluate(&self, covector: &CoVector<T, N>) -> T {
+        covector.apply(self)
+    }
+}
+
+impl<T: Ring, const N: usize> From<Vector<T, N>> for CoVector<T, N> {
+    /// Returns w ↦ ⟨v, w⟩ for the standard inner product, with the same components as v.
+    fn from(v: Vector<T, N>) -> Self {
+        Self::new(v.components().clone())
+    }
+}
+
+impl<T: Ring, const N: usize> From<CoVector<T, N>> for Vector<T, N> {
+    /// Returns the vector representing φ under the standard inner product.
+    fn from(covector: CoVector<T, N>) -> Self {
+        Vector::new(covector.components)
+    }
+}
+
+impl<T: Ring, const N: usize> Index<usize> for CoVector<T, N> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        &self.components[i]
+    }
+}
+
+impl<T: Ring, const N: usize> Add for CoVector<T, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::map(|i| self.components[i].clone() + other.components[i].clone())
+    }
+}
+
+impl<T: Ring, const N: usize> AddAssign for CoVector<T, N> {
+    fn add_assign(&mut self, other: Self) {
+        for (x, y) in self.components.iter_mut().zip(other.components) {
+            *x += y;
+        }
+    }
+}
+
+impl<T: Ring, const N: usize> Neg for CoVector<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::map(|i| -self.components[i].clone())
+    }
+}
+
+impl<T: Ring, const N: usize> Sub for CoVector<T, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::map(|i| self.components[i].clone() - other.components[i].clone())
+    }
+}
+
+impl<T: Ring, const N: usize> SubAssign for CoVector<T, N> {
+    fn sub_assign(&mut self, other: Self) {
+        for (x, y) in self.components.iter_mut().zip(other.components) {
+            *x -= y;
+        }
+    }
+}
+
+impl<T: Ring, const N: usize> Zero for CoVector<T, N> {
+    fn zero() -> Self {
+        Self::map(|_| T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.components.iter().all(Zero::is_zero)
+    }
+}
+
+impl<T: Field, const N: usize> VectorSpace for CoVector<T, N> {
+    type Scalar = T;
+
+    fn scale(&self, scalar: &T) -> Self {
+        Self::map(|i| scalar.clone() * self.components[i].clone())
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(N)
+    }
+}
+
+impl<T: Ring, const N: usize> CommutativeAddition for CoVector<T, N> {}
+impl<T: Ring, const N: usize> AssociativeAddition for CoVector<T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SplitMix64;
+    use crate::InnerProductSpace;
+
+    fn random_vector(rng: &mut SplitMix64) -> Vector<f64, 3> {
+        Vector::new(std::array::from_fn(|_| rng.range_f64(-5.0, 5.0)))
+    }
+
+    fn random_covector(rng: &mut SplitMix64) -> CoVector<f64, 3> {
+        CoVector::new(std::array::from_fn(|_| rng.range_f64(-5.0, 5.0)))
+    }
+
+    #[test]
+    fn covectors_apply_as_linear_combinations() {
+        let phi = CoVector::new([2.0, -1.0, 0.5]);
+        let v = Vector::new([3.0, 4.0, -2.0]);
+        assert_eq!(phi.apply(&v), 2.0 * 3.0 - 4.0 + 0.5 * -2.0);
+        assert_eq!(CoVector::zero().apply(&v), 0.0);
+        let mut rng = SplitMix64::new(278);
+        for _ in 0..100 {
+            let (phi, psi) = (random_covector(&mut rng), random_covector(&mut rng));
+            let (u, w) = (random_vector(&mut rng), random_vector(&mut rng));
+            let (a, b) = (rng.range_f64(-3.0, 3.0), rng.range_f64(-3.0, 3.0));
+            // Linear in the vector, and the pointwise operations are linear in the covector.
+            let left = phi.apply(&(u.scale(&a) + w.scale(&b)));
+            assert!((left - (a * phi.apply(&u) + b * phi.apply(&w))).abs() < 1e-9);
+            let sum = (phi.scale(&a) - psi).apply(&u);
+            assert!((sum - (a * phi.apply(&u) - psi.apply(&u))).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn dual_basis_is_the_kronecker_delta() {
+        let dual = CoVector::<f64, 4>::dual_basis();
+        for (i, e_star) in dual.iter().enumerate() {
+            for j in 0..4 {
+                let e = Vector::new(std::array::from_fn(|k| if k == j { 1.0 } else { 0.0 }));
+                assert_eq!(e_star.apply(&e), if i == j { 1.0 } else { 0.0 });
+            }
+        }
+        // eᵢ* reads off the i-th coordinate, so any covector is Σᵢ φᵢ eᵢ*.
+        let v = Vector::new([3.0, -1.0, 4.0, 1.5]);
+        for (i, e_star) in dual.iter().enumerate() {
+            assert_eq!(e_star.apply(&v), v[i]);
+        }
+        let phi = CoVector::new([2.0, 0.0, -7.0, 1.0]);
+        let expansion = dual
+            .iter()
+            .enumerate()
+            .fold(CoVector::zero(), |sum, (i, e_star)| {
+                sum + e_star.scale(&phi[i])
+            });
+        assert_eq!(expansion, phi);
+    }
+
+    #[test]
+    fn dual_space_has_the_same_dimension() {
+        let phi = CoVector::new([1.0, 2.0, 3.0]);
+        let v = Vector::new([1.0, 2.0, 3.0]);
+        assert_eq!(phi.dimension(), v.dimension());
+        assert_eq!(CoVector::<f64, 0>::zero().dimension(), Some(0));
+        assert_eq!(CoVector::<f64, 5>::dual_basis().len(), 5);
+    }
+
+    #[test]
+    fn double_dual_evaluates_covectors() {
+        let mut rng = SplitMix64::new(2780);
+        let dual = CoVector::<f64, 3>::dual_basis();
+        for _ in 0..100 {
+            let v = random_vector(&mut rng);
+            let phi = random_covector(&mut rng);
+            assert_eq!(v.evaluate(&phi), phi.apply(&v));
+            // A vector is recovered from its values on the dual basis.
+            let recovered = Vector::new(std::array::from_fn(|i| v.evaluate(&dual[i])));
+            assert_eq!(recovered, v);
+        }
+    }
+
+    #[test]
+    fn inner_products_identify_vectors_with_covectors() {
+        let mut rng = SplitMix64::new(2781);
+        let gram = Matrix::from_rows([[4.0, 1.0, 0.0], [1.0, 3.0, -1.0], [0.0, -1.0, 2.0]]);
+        for _ in 0..100 {
+            let (v, w) = (random_vector(&mut rng), random_vector(&mut rng));
+            let standard = CoVector::from(v);
+            assert_eq!(standard.apply(&w), v.inner_product(&w));
+            assert_eq!(Vector::from(standard), v);
+            let phi = CoVector::from_inner_product(&v, &gram);
+            let expected: f64 = (0..3)
+                .flat_map(|i| (0..3).map(move |j| (i, j)))
+                .map(|(i, j)| v[i] * gram[(i, j)] * w[j])
+                .sum();
+            assert!((phi.apply(&w) - expected).abs() < 1e-9);
+            assert!(phi.to_vector(&gram).unwrap().approx_eq(&v, &1e-9));
+        }
+        let singular = Matrix::from_rows([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(CoVector::new([1.0, 2.0]).to_vector(&singular), None);
+    }
+}
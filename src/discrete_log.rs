@@ -0,0 +1,142 @@
+use crate::arithmetic::pow;
+use crate::FiniteField;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A precomputed baby-step giant-step table for discrete logarithms to a fixed base.
+///
+/// # Mathematical Definition
+/// Let g generate the cyclic group F* of order n = q − 1. For every non-zero t ∈ F there is a
+/// unique k ∈ [0, n) with g^k = t, the discrete logarithm of t to the base g.
+///
+/// # Complexity
+/// With m = ⌈√n⌉, construction stores m baby steps g^j and each query takes at most m giant
+/// steps t · g^(−m·i), so repeated queries avoid rebuilding the table.
+#[derive(Clone, Debug)]
+pub struct DlogTable<F: FiniteField + Hash + Eq> {
+    baby_steps: HashMap<F, u64>,
+    giant_step: F,
+    step: u64,
+}
+
+impl<F: FiniteField + Hash + Eq> DlogTable<F> {
+    /// Builds the table for `base`, returning `None` if it does not generate F*.
+    pub fn new(base: F) -> Option<Self> {
+        let group_order = F::order() - 1;
+        if !is_generator(&base, group_order) {
+            return None;
+        }
+
+        let step = ceil_sqrt(group_order);
+        let mut baby_steps = HashMap::with_capacity(step as usize);
+        let mut power = F::one();
+        for j in 0..step {
+            baby_steps.entry(power.clone()).or_insert(j);
+            power *= base.clone();
+        }
+        // After the loop `power` is g^m, so the giant step is its inverse.
+        Some(Self {
+            baby_steps,
+            giant_step: power.inv(),
+            step,
+        })
+    }
+
+    /// Returns the k ∈ [0, q − 1) with base^k = target, or `None` if target is zero.
+    pub fn log(&self, target: &F) -> Option<u64> {
+        let mut gamma = target.clone();
+        for i in 0..self.step {
+            if let Some(j) = self.baby_steps.get(&gamma) {
+                return Some(i * self.step + j);
+            }
+            gamma *= self.giant_step.clone();
+        }
+        None
+    }
+}
+
+/// Returns whether `base` has multiplicative order exactly `group_order`.
+pub(crate) fn is_generator<F: FiniteField>(base: &F, group_order: u64) -> bool {
+    if base.is_zero() {
+        return false;
+    }
+    let mut n = group_order;
+    let mut p = 2;
+    while p * p <= n {
+        if n % p == 0 {
+            if pow(base.clone(), group_order / p).is_one() {
+                return false;
+            }
+            while n % p == 0 {
+                n /= p;
+            }
+        }
+        p += 1;
+    }
+    n <= 1 || !pow(base.clone(), group_order / n).is_one()
+}
+
+fn ceil_sqrt(n: u64) -> u64 {
+    let mut root = (n as f64).sqrt() as u64;
+    while root * root < n {
+        root += 1;
+    }
+    while root > 0 && (root - 1) * (root - 1) >= n {
+        root -= 1;
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrimeField;
+    use num_traits::Zero;
+
+    type F101 = PrimeField<101>;
+
+    /// Returns the logarithm by trying every exponent in turn.
+    fn brute_force_log(base: F101, target: F101) -> Option<u64> {
+        (0..100).find(|&k| pow(base, k) == target)
+    }
+
+    #[test]
+    fn table_recovers_every_logarithm() {
+        // 2 generates 𝔽₁₀₁*
+        let table = DlogTable::new(F101::new(2)).unwrap();
+        for k in 0..100 {
+            assert_eq!(table.log(&pow(F101::new(2), k)), Some(k));
+        }
+        assert_eq!(table.log(&F101::zero()), None);
+    }
+
+    #[test]
+    fn repeated_queries_match_brute_force() {
+        let base = F101::new(3);
+        let table = DlogTable::new(base).unwrap();
+        for _ in 0..3 {
+            for t in 1..101 {
+                let target = F101::new(t);
+                assert_eq!(table.log(&target), brute_force_log(base, target));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_non_generators() {
+        // 5 has order 25 in 𝔽₁₀₁*, and 1 and 0 generate nothing
+        assert!(DlogTable::new(F101::new(5)).is_none());
+        assert!(DlogTable::new(F101::new(1)).is_none());
+        assert!(DlogTable::new(F101::zero()).is_none());
+        assert!(DlogTable::new(F101::new(100)).is_none());
+    }
+
+    #[test]
+    fn ceil_sqrt_rounds_up() {
+        assert_eq!(ceil_sqrt(0), 0);
+        assert_eq!(ceil_sqrt(1), 1);
+        assert_eq!(ceil_sqrt(99), 10);
+        assert_eq!(ceil_sqrt(100), 10);
+        assert_eq!(ceil_sqrt(101), 11);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, Distributive, Field,
+};
+use num_traits::{One, Zero};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Represents a dual number a + bε with components in a field F, where ε² = 0.
+///
+/// # Mathematical Definition
+/// The dual numbers over F are the quotient F\[x\] / (x²), with ε the class of x:
+/// - (a + bε) + (c + dε) = (a + c) + (b + d)ε
+/// - (a + bε) · (c + dε) = ac + (ad + bc)ε
+///
+/// # Properties
+/// - For a polynomial p, p(a + ε) = p(a) + p′(a)ε, so evaluating over the dual numbers
+///   computes a derivative alongside the value (forward-mode automatic differentiation)
+/// - ε is a zero divisor, so this is not an integral domain. Multiplication commutes, but
+///   `CommutativeMultiplication` is not implemented, since the blanket impls would then make
+///   `Dual` an `IntegralDomain`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Dual<F: Field> {
+    real: F,
+    eps: F,
+}
+
+impl<F: Field> Dual<F> {
+    /// Creates the dual number real + eps · ε.
+    pub fn new(real: F, eps: F) -> Self {
+        Self { real, eps }
+    }
+
+    /// Returns x + ε, the point at which evaluation yields a derivative in the ε part.
+    pub fn variable(x: F) -> Self {
+        Self::new(x, F::one())
+    }
+
+    /// Returns the real part.
+    pub fn real(&self) -> &F {
+        &self.real
+    }
+
+    /// Returns the ε part.
+    pub fn eps(&self) -> &F {
+        &self.eps
+    }
+}
+
+impl<F: Field> From<F> for Dual<F> {
+    fn from(real: F) -> Self {
+        Self::new(real, F::zero())
+    }
+}
+
+impl<F: Field + fmt::Display> fmt::Display for Dual<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}ε", self.real, self.eps)
+    }
+}
+
+impl<F: Field> Add for Dual<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.real + other.real, self.eps + other.eps)
+    }
+}
+
+impl<F: Field> AddAssign for Dual<F> {
+    fn add_assign(&mut self, other: Self) {
+        self.real += other.real;
+        self.eps += other.eps;
+    }
+}
+
+impl<F: Field> Neg for Dual<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.real, -self.eps)
+    }
+}
+
+impl<F: Field> Sub for Dual<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.real - other.real, self.eps - other.eps)
+    }
+}
+
+impl<F: Field> SubAssign for Dual<F> {
+    fn sub_assign(&mut self, other: Self) {
+        self.real -= other.real;
+        self.eps -= other.eps;
+    }
+}
+
+impl<F: Field> Mul for Dual<F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let eps = self.real.clone() * other.eps + self.eps * other.real.clone();
+        Self::new(self.real * other.real, eps)
+    }
+}
+
+impl<F: Field> MulAssign for Dual<F> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.clone() * other;
+    }
+}
+
+impl<F: Field> Zero for Dual<F> {
+    fn zero() -> Self {
+        Self::new(F::zero(), F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.eps.is_zero()
+    }
+}
+
+impl<F: Field> One for Dual<F> {
+    fn one() -> Self {
+        Self::new(F::one(), F::zero())
+    }
+}
+
+impl<F: Field> CommutativeAddition for Dual<F> {}
+impl<F: Field> AssociativeAddition for Dual<F> {}
+impl<F: Field> AssociativeMultiplication for Dual<F> {}
+impl<F: Field> Distributive for Dual<F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::check_ring_laws;
+    use crate::PrimeField;
+
+    type F5 = PrimeField<5>;
+
+    #[test]
+    fn epsilon_squares_to_zero() {
+        let eps = Dual::new(0.0, 1.0);
+        assert!((eps * eps).is_zero());
+        let a = Dual::new(3.0, 2.0);
+        let b = Dual::new(-1.0, 4.0);
+        assert_eq!(a * b, Dual::new(-3.0, 12.0 - 2.0));
+        assert_eq!(a - b + b, a);
+        assert_eq!(Dual::from(2.5), Dual::new(2.5, 0.0));
+        assert_eq!(Dual::variable(7.0).to_string(), "7 + 1ε");
+    }
+
+    #[test]
+    fn ring_laws_hold_over_a_prime_field() {
+        let elements: Vec<Dual<F5>> = (0..25)
+            .map(|n| Dual::new(F5::new(n % 5), F5::new(n / 5)))
+            .collect();
+        for &a in &elements {
+            for &b in elements.iter().step_by(3) {
+                for &c in elements.iter().step_by(7) {
+                    assert!(check_ring_laws(a, b, c));
+                }
+            }
+        }
+    }
+}
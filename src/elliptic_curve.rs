@@ -0,0 +1,444 @@
+use crate::{AssociativeAddition, CommutativeAddition, Field, FiniteField};
+use num_traits::Zero;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// Represents an elliptic curve in short Weierstrass form over a field.
+///
+/// # Mathematical Definition
+/// A Weierstrass curve E over a field F is the set of solutions of
+/// y² = x³ + ax + b with a, b ∈ F, together with a point at infinity O.
+///
+/// # Properties
+/// - The curve is non-singular: 4a³ + 27b² ≠ 0
+/// - The short form requires char(F) ∉ {2, 3}, which `new` checks
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeierstrassCurve<F: Field> {
+    a: F,
+    b: F,
+}
+
+impl<F: Field> WeierstrassCurve<F> {
+    /// Creates the curve y² = x³ + ax + b.
+    ///
+    /// # Panics
+    ///
+    /// Panics if F has characteristic 2 or 3, where the short form does not cover every curve
+    /// and the doubling formula divides by zero, or if the curve is singular, i.e. if
+    /// 4a³ + 27b² = 0.
+    pub fn new(a: F, b: F) -> Self {
+        let two = F::one() + F::one();
+        let three = two.clone() + F::one();
+        if two.is_zero() || three.is_zero() {
+            panic!("short Weierstrass form requires characteristic other than 2 and 3");
+        }
+        let four = two.clone() * two;
+        let twenty_seven = three.clone() * three.clone() * three;
+        let discriminant =
+            four * a.clone() * a.clone() * a.clone() + twenty_seven * b.clone() * b.clone();
+        if discriminant.is_zero() {
+            panic!("Weierstrass curve is singular: 4a³ + 27b² = 0");
+        }
+        Self { a, b }
+    }
+
+    /// Returns the coefficient a.
+    pub fn a(&self) -> &F {
+        &self.a
+    }
+
+    /// Returns the coefficient b.
+    pub fn b(&self) -> &F {
+        &self.b
+    }
+
+    /// Returns whether (x, y) satisfies the curve equation.
+    pub fn contains(&self, x: &F, y: &F) -> bool {
+        let lhs = y.clone() * y.clone();
+        let rhs = x.clone() * x.clone() * x.clone() + self.a.clone() * x.clone() + self.b.clone();
+        lhs == rhs
+    }
+
+    /// Returns the affine point (x, y), or `None` if it does not lie on the curve.
+    pub fn point(&self, x: F, y: F) -> Option<EcPoint<F>> {
+        if !self.contains(&x, &y) {
+            return None;
+        }
+        Some(EcPoint {
+            repr: Repr::Affine {
+                x,
+                y,
+                a: self.a.clone(),
+            },
+        })
+    }
+
+    /// Returns the point at infinity, the identity of the group law.
+    pub fn infinity(&self) -> EcPoint<F> {
+        EcPoint::zero()
+    }
+}
+
+impl<F: FiniteField> WeierstrassCurve<F> {
+    /// Returns #E(F), the number of points on the curve including the point at infinity.
+    ///
+    /// Points are counted by brute force over every (x, y) pair, which takes O(q²) field
+    /// operations and is only intended for small fields.
+    ///
+    /// # Panics
+    ///
+    /// Panics if F is not a prime field, as its elements are enumerated as multiples of one.
+    pub fn curve_order(&self) -> u64 {
+        assert_eq!(
+            F::order(),
+            F::characteristic(),
+            "curve_order can only enumerate prime fields"
+        );
+        let mut elements = Vec::with_capacity(F::order() as usize);
+        let mut element = F::zero();
+        for _ in 0..F::order() {
+            elements.push(element.clone());
+            element += F::one();
+        }
+        let squares: Vec<F> = elements.iter().map(|y| y.clone() * y.clone()).collect();
+
+        let mut count = 1;
+        for x in &elements {
+            let rhs =
+                x.clone() * x.clone() * x.clone() + self.a.clone() * x.clone() + self.b.clone();
+            count += squares.iter().filter(|&square| *square == rhs).count() as u64;
+        }
+        count
+    }
+
+    /// Returns the trace of Frobenius t = q + 1 − #E(F).
+    ///
+    /// The Frobenius endomorphism satisfies x² − tx + q = 0, and Hasse's theorem bounds the
+    /// trace by |t| ≤ 2√q.
+    ///
+    /// # Panics
+    ///
+    /// Panics if F is not a prime field, as the point count is computed by `curve_order`.
+    pub fn trace_of_frobenius(&self) -> i64 {
+        let q = F::order() as i64;
+        let trace = q + 1 - self.curve_order() as i64;
+        debug_assert!(trace * trace <= 4 * q, "trace violates the Hasse bound");
+        trace
+    }
+}
+
+/// Represents a point on a Weierstrass curve, forming an additive abelian group.
+///
+/// # Mathematical Definition
+/// The points of E(F) form an abelian group under the chord-and-tangent law:
+/// - The identity is the point at infinity O
+/// - The inverse of (x, y) is (x, −y)
+/// - P + Q + R = O whenever P, Q and R are collinear
+///
+/// Affine points carry the curve coefficient a needed for doubling, so that the
+/// identity can be constructed without reference to a particular curve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EcPoint<F: Field> {
+    repr: Repr<F>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Repr<F> {
+    Infinity,
+    Affine { x: F, y: F, a: F },
+}
+
+impl<F: Field> EcPoint<F> {
+    /// Returns the x-coordinate, or `None` for the point at infinity.
+    pub fn x(&self) -> Option<&F> {
+        match &self.repr {
+            Repr::Infinity => None,
+            Repr::Affine { x, .. } => Some(x),
+        }
+    }
+
+    /// Returns the y-coordinate, or `None` for the point at infinity.
+    pub fn y(&self) -> Option<&F> {
+        match &self.repr {
+            Repr::Infinity => None,
+            Repr::Affine { y, .. } => Some(y),
+        }
+    }
+
+    /// Returns whether this is the point at infinity.
+    pub fn is_infinity(&self) -> bool {
+        matches!(self.repr, Repr::Infinity)
+    }
+
+    /// Computes k·P using double-and-add.
+    pub fn scalar_mul(&self, k: u64) -> Self {
+        let mut result = Self::zero();
+        let mut addend = self.clone();
+        let mut k = k;
+        while k > 0 {
+            if k & 1 == 1 {
+                result += addend.clone();
+            }
+            addend = addend.clone() + addend;
+            k >>= 1;
+        }
+        result
+    }
+}
+
+impl<F: FiniteField> EcPoint<F> {
+    /// Returns the order of the point: the smallest k ≥ 1 with k·P = O.
+    ///
+    /// The order is found by repeated addition, taking O(k) group operations.
+    pub fn point_order(&self) -> u64 {
+        let mut order = 1;
+        let mut multiple = self.clone();
+        while !multiple.is_zero() {
+            multiple += self.clone();
+            order += 1;
+        }
+        order
+    }
+}
+
+impl<F: Field> Add for EcPoint<F> {
+    type Output = Self;
+
+    /// Applies the chord-and-tangent group law.
+    fn add(self, other: Self) -> Self {
+        let (x1, y1, a) = match self.repr {
+            Repr::Infinity => return other,
+            Repr::Affine { x, y, a } => (x, y, a),
+        };
+        let (x2, y2) = match other.repr {
+            Repr::Infinity => {
+                return Self {
+                    repr: Repr::Affine { x: x1, y: y1, a },
+                }
+            }
+            Repr::Affine { x, y, .. } => (x, y),
+        };
+
+        let lambda = if x1 == x2 {
+            // Either P = −Q (including the vertical tangent at y = 0) or P = Q.
+            if (y1.clone() + y2.clone()).is_zero() {
+                return Self::zero();
+            }
+            let two = F::one() + F::one();
+            let three = two.clone() + F::one();
+            (three * x1.clone() * x1.clone() + a.clone()) / (two * y1.clone())
+        } else {
+            (y2 - y1.clone()) / (x2.clone() - x1.clone())
+        };
+
+        let x3 = lambda.clone() * lambda.clone() - x1.clone() - x2;
+        let y3 = lambda * (x1 - x3.clone()) - y1;
+        Self {
+            repr: Repr::Affine { x: x3, y: y3, a },
+        }
+    }
+}
+
+impl<F: Field> AddAssign for EcPoint<F> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<F: Field> Neg for EcPoint<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let repr = match self.repr {
+            Repr::Infinity => Repr::Infinity,
+            Repr::Affine { x, y, a } => Repr::Affine { x, y: -y, a },
+        };
+        Self { repr }
+    }
+}
+
+impl<F: Field> Sub for EcPoint<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl<F: Field> SubAssign for EcPoint<F> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl<F: Field> Zero for EcPoint<F> {
+    fn zero() -> Self {
+        Self {
+            repr: Repr::Infinity,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.is_infinity()
+    }
+}
+
+impl<F: Field> CommutativeAddition for EcPoint<F> {}
+impl<F: Field> AssociativeAddition for EcPoint<F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrimeField;
+
+    type F23 = PrimeField<23>;
+
+    /// The curve y² = x³ + x + 1 over 𝔽₂₃, which has 28 points.
+    fn curve() -> WeierstrassCurve<F23> {
+        WeierstrassCurve::new(F23::new(1), F23::new(1))
+    }
+
+    fn points(curve: &WeierstrassCurve<F23>) -> Vec<EcPoint<F23>> {
+        let mut points = vec![curve.infinity()];
+        for x in 0..23 {
+            for y in 0..23 {
+                points.extend(curve.point(F23::new(x), F23::new(y)));
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn group_law_is_associative_and_commutative() {
+        let points = points(&curve());
+        assert_eq!(points.len(), 28);
+        for p in points.iter().step_by(3) {
+            for q in points.iter().step_by(2) {
+                assert_eq!(p.clone() + q.clone(), q.clone() + p.clone());
+                for r in points.iter().step_by(5) {
+                    assert_eq!(
+                        (p.clone() + q.clone()) + r.clone(),
+                        p.clone() + (q.clone() + r.clone())
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sums_stay_on_the_curve() {
+        let curve = curve();
+        let points = points(&curve);
+        for p in &points {
+            for q in &points {
+                let sum = p.clone() + q.clone();
+                if let (Some(x), Some(y)) = (sum.x(), sum.y()) {
+                    assert!(curve.contains(x, y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn point_plus_negation_is_identity() {
+        for p in points(&curve()) {
+            assert!((p.clone() + -p.clone()).is_infinity());
+            assert!((p.clone() - p.clone()).is_zero());
+            assert_eq!(p.clone() + EcPoint::zero(), p);
+        }
+    }
+
+    #[test]
+    fn doubling_a_point_of_order_two() {
+        // x³ + x + 1 has the root x = 4 in 𝔽₂₃, so (4, 0) has a vertical tangent
+        let p = curve().point(F23::new(4), F23::new(0)).unwrap();
+        assert!((p.clone() + p).is_infinity());
+    }
+
+    #[test]
+    fn scalar_multiplication_by_the_group_order_is_identity() {
+        let curve = curve();
+        let order = curve.curve_order();
+        assert_eq!(order, 28);
+        for p in points(&curve) {
+            assert!(p.scalar_mul(order).is_infinity());
+        }
+    }
+
+    #[test]
+    fn rejects_points_off_the_curve() {
+        assert!(curve().point(F23::new(0), F23::new(0)).is_none());
+        assert!(curve().point(F23::new(0), F23::new(1)).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "singular")]
+    fn rejects_singular_curves() {
+        let _ = WeierstrassCurve::new(F23::new(0), F23::new(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "characteristic other than 2 and 3")]
+    fn rejects_characteristic_two() {
+        let _ = WeierstrassCurve::new(PrimeField::<2>::new(1), PrimeField::<2>::new(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "characteristic other than 2 and 3")]
+    fn rejects_characteristic_three() {
+        let _ = WeierstrassCurve::new(PrimeField::<3>::new(1), PrimeField::<3>::new(1));
+    }
+
+    #[test]
+    fn double_and_add_matches_repeated_addition() {
+        for p in points(&curve()).iter().step_by(4) {
+            let mut repeated = EcPoint::zero();
+            for k in 0..40 {
+                assert_eq!(p.scalar_mul(k), repeated, "{k}·P");
+                repeated += p.clone();
+            }
+        }
+    }
+
+    #[test]
+    fn point_order_divides_curve_order() {
+        let curve = curve();
+        let order = curve.curve_order();
+        for p in points(&curve) {
+            let k = p.point_order();
+            assert_eq!(order % k, 0);
+            assert!(p.scalar_mul(k).is_infinity());
+            assert!((1..k).all(|j| !p.scalar_mul(j).is_infinity()));
+        }
+        assert_eq!(curve.infinity().point_order(), 1);
+    }
+
+    #[test]
+    fn trace_of_frobenius_satisfies_hasse() {
+        let curves = [(1, 1), (2, 3), (0, 7), (5, 0), (11, 19)];
+        for (a, b) in curves {
+            let curve = WeierstrassCurve::new(F23::new(a), F23::new(b));
+            let trace = curve.trace_of_frobenius();
+            assert_eq!(trace, 23 + 1 - curve.curve_order() as i64);
+            assert!(
+                trace * trace <= 4 * 23,
+                "Hasse bound fails for a = {a}, b = {b}"
+            );
+        }
+        assert_eq!(curve().trace_of_frobenius(), -4);
+    }
+
+    #[test]
+    fn frobenius_eigenvalues_have_norm_p() {
+        use crate::Complex;
+        let trace = curve().trace_of_frobenius() as f64;
+        // The roots of x² − tx + p are (t ± i√(4p − t²)) / 2
+        let discriminant = 4.0 * 23.0 - trace * trace;
+        assert!(discriminant >= 0.0);
+        let alpha = Complex::new(trace / 2.0, discriminant.sqrt() / 2.0);
+        let beta = alpha.conjugate();
+        assert!((*(alpha * beta).re() - 23.0).abs() < 1e-9);
+        assert!((*(alpha + beta).re() - trace).abs() < 1e-9);
+        let residual = alpha * alpha - Complex::from(trace) * alpha + Complex::from(23.0);
+        assert!(residual.norm_squared() < 1e-18);
+    }
+}
@@ -0,0 +1,360 @@
+use crate::{EuclideanDomain, IntegralDomain, Ratio};
+use num_traits::Euclid;
+
+/// Returns a gcd of all elements of `items`, folding the pairwise gcd.
+///
+/// The gcd of the empty slice is zero, the identity of the fold. Each pairwise gcd is the
+/// canonical associate, so the result is too: over ℤ it is non-negative, so `gcd_many(&[-6])`
+/// is 6, over F\[x\] it is monic, and over a field a non-zero gcd is one.
+pub fn gcd_many<T: EuclideanDomain>(items: &[T]) -> T {
+    items.iter().fold(T::zero(), |acc, item| acc.gcd(item))
+}
+
+/// Returns (g, x, y) with a · x + b · y = g, where g is a gcd of a and b.
+///
+/// Runs the iterative extended Euclidean algorithm on the `Euclid` quotients and remainders,
+/// so it applies to every Euclidean domain; the weaker bound lets `BezoutDomain` build on it.
+/// If b is zero the result is (a, 1, 0), and if a is zero it is (b, 0, 1). Unlike
+/// `GcdDomain::gcd`, g is not normalized, so it is only unique up to multiplication by a unit;
+/// `BezoutDomain::bezout` scales all three by the unit that makes g canonical.
+pub fn extended_gcd<T: IntegralDomain + Euclid>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+    while !r.is_zero() {
+        let q = Euclid::div_euclid(&old_r, &r);
+        let next_r = old_r - q.clone() * r.clone();
+        old_r = std::mem::replace(&mut r, next_r);
+        let next_x = old_x - q.clone() * x.clone();
+        old_x = std::mem::replace(&mut x, next_x);
+        let next_y = old_y - q * y.clone();
+        old_y = std::mem::replace(&mut y, next_y);
+    }
+    (old_r, old_x, old_y)
+}
+
+/// Returns an lcm of all elements of `items`, folding lcm(a, b) = (a / gcd(a, b)) · b.
+///
+/// The lcm of the empty slice is one, the identity of the fold, and the lcm of any slice
+/// containing zero is zero. The result is the canonical associate as in `gcd_many`, so over ℤ
+/// it is non-negative and over F\[x\] it is monic.
+pub fn lcm_many<T: EuclideanDomain>(items: &[T]) -> T {
+    normalize(items.iter().fold(T::one(), |acc, item| {
+        if acc.is_zero() || item.is_zero() {
+            return T::zero();
+        }
+        let g = acc.gcd(item);
+        Euclid::div_euclid(&acc, &g) * item.clone()
+    }))
+}
+
+/// Returns the canonical associate of x as gcd(x, 0), which the gcd normalizes.
+fn normalize<T: EuclideanDomain>(x: T) -> T {
+    x.gcd(&T::zero())
+}
+
+/// Returns the partial quotients [q₀; q₁, q₂, ...] of the continued fraction of a / b.
+///
+/// The quotients are those of the Euclidean algorithm on (a, b), so that
+/// a / b = q₀ + 1 / (q₁ + 1 / (q₂ + ...)). Over ℤ this is the regular continued fraction of a
+/// rational number, and over F\[x\] it expands a rational function. The expansion of a / 0 is
+/// empty.
+pub fn continued_fraction<T: EuclideanDomain>(a: &T, b: &T) -> Vec<T> {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    let mut quotients = Vec::new();
+    while !b.is_zero() {
+        let q = Euclid::div_euclid(&a, &b);
+        let r = Euclid::rem_euclid(&a, &b);
+        quotients.push(q);
+        a = std::mem::replace(&mut b, r);
+    }
+    quotients
+}
+
+/// Recovers a fraction n/d from its residue modulo `modulus` under the standard size bounds.
+///
+/// Uses |n| ≤ N = ⌊√(modulus / 2)⌋ and d ≤ ⌊(modulus − 1) / 2N⌋, the largest denominator bound
+/// for which 2 · N · D < modulus, so a reconstruction is unique when it exists. For example 9 is
+/// 1/3 modulo 13. See `rational_reconstruct_bounded` for the details.
+///
+/// # Panics
+///
+/// Panics if `modulus` is not positive.
+pub fn rational_reconstruct(residue: i64, modulus: i64) -> Option<Ratio<i64>> {
+    assert!(modulus > 0, "modulus must be positive");
+    let half = modulus as i128 / 2;
+    let mut numerator_bound = (half as f64).sqrt() as i128;
+    // Correct the floating-point square root to ⌊√half⌋
+    while numerator_bound * numerator_bound > half {
+        numerator_bound -= 1;
+    }
+    while (numerator_bound + 1) * (numerator_bound + 1) <= half {
+        numerator_bound += 1;
+    }
+    let denominator_bound = if numerator_bound == 0 {
+        1
+    } else {
+        (modulus as i128 - 1) / (2 * numerator_bound)
+    };
+    rational_reconstruct_bounded(
+        residue,
+        modulus,
+        numerator_bound as i64,
+        denominator_bound as i64,
+    )
+}
+
+/// Recovers a fraction n/d with n ≡ residue · d (mod modulus), |n| ≤ `numerator_bound` and
+/// 0 < d ≤ `denominator_bound`.
+///
+/// Runs the extended Euclidean algorithm on (modulus, residue) and stops at the first remainder
+/// within the numerator bound (Wang's algorithm). Returns `None` if no fraction within the
+/// bounds exists, or if the candidate found is not in lowest terms. The result is unique
+/// whenever 2 · `numerator_bound` · `denominator_bound` < `modulus`.
+///
+/// # Panics
+///
+/// Panics if `modulus` is not positive.
+pub fn rational_reconstruct_bounded(
+    residue: i64,
+    modulus: i64,
+    numerator_bound: i64,
+    denominator_bound: i64,
+) -> Option<Ratio<i64>> {
+    assert!(modulus > 0, "modulus must be positive");
+    let (mut r0, mut r1) = (modulus as i128, residue.rem_euclid(modulus) as i128);
+    let (mut t0, mut t1) = (0i128, 1i128);
+    while r1 > numerator_bound as i128 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+    if t1 == 0 || t1.abs() > denominator_bound as i128 {
+        return None;
+    }
+    let (mut a, mut b) = (r1, t1.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    if a != 1 {
+        return None;
+    }
+    Some(Ratio::new(r1 as i64, t1 as i64))
+}
+
+/// Returns a coprime basis for `inputs`: pairwise coprime integers greater than one such that
+/// every non-zero input is, up to sign, a product of powers of basis elements.
+///
+/// Uses factor refinement: while two elements a, b of the working list share a factor
+/// g = gcd(a, b) > 1, they are replaced by a / g, g and b / g, discarding ones. Each step
+/// divides the product of the list by g, so the refinement terminates. Zeros and units are
+/// ignored, and the result is sorted and free of duplicates.
+///
+/// # Panics
+///
+/// Panics if an input is `i64::MIN`, whose absolute value is not representable.
+pub fn coprime_basis(inputs: &[i64]) -> Vec<i64> {
+    let mut basis: Vec<i64> = inputs
+        .iter()
+        .map(|n| n.checked_abs().expect("input must not be i64::MIN"))
+        .filter(|&n| n > 1)
+        .collect();
+    'refine: loop {
+        for i in 0..basis.len() {
+            for j in i + 1..basis.len() {
+                let g = gcd_i64(basis[i], basis[j]);
+                if g > 1 {
+                    let (a, b) = (basis[i] / g, basis[j] / g);
+                    basis.swap_remove(j);
+                    basis.swap_remove(i);
+                    basis.extend([a, g, b].into_iter().filter(|&n| n > 1));
+                    continue 'refine;
+                }
+            }
+        }
+        break;
+    }
+    basis.sort_unstable();
+    basis
+}
+
+fn gcd_i64(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SplitMix64;
+    use crate::{DensePolynomial, PrimeField};
+    use num_traits::Zero;
+
+    #[test]
+    fn extended_gcd_satisfies_bezout() {
+        for a in -30i64..=30 {
+            for b in -30i64..=30 {
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(a * x + b * y, g, "bezout({a}, {b})");
+                if g != 0 {
+                    assert_eq!(a % g, 0);
+                    assert_eq!(b % g, 0);
+                } else {
+                    assert_eq!((a, b), (0, 0));
+                }
+            }
+        }
+        assert_eq!(extended_gcd(240i64, 46).0.abs(), 2);
+    }
+
+    #[test]
+    fn extended_gcd_with_a_zero_argument() {
+        assert_eq!(extended_gcd(7i64, 0), (7, 1, 0));
+        assert_eq!(extended_gcd(0i64, 7), (7, 0, 1));
+        assert_eq!(extended_gcd(0i64, 0), (0, 1, 0));
+    }
+
+    #[test]
+    fn extended_gcd_over_a_prime_field() {
+        type F = PrimeField<13>;
+        let (a, b) = (F::new(5), F::new(8));
+        let (g, x, y) = extended_gcd(a, b);
+        assert!(!g.is_zero());
+        assert_eq!(a * x + b * y, g);
+    }
+
+    #[test]
+    fn gcd_and_lcm_of_several_integers() {
+        assert_eq!(gcd_many(&[12i64, 18, 30]), 6);
+        assert_eq!(lcm_many(&[4i64, 6, 8]), 24);
+        assert_eq!(gcd_many(&[-12i64, 18, -30]), 6);
+        assert_eq!(lcm_many(&[4i64, -6]), 12);
+        assert_eq!(gcd_many(&[7i64, 0, 21]), 7);
+        assert_eq!(lcm_many(&[7i64, 0, 21]), 0);
+    }
+
+    #[test]
+    fn empty_and_singleton_slices() {
+        assert_eq!(gcd_many::<i64>(&[]), 0);
+        assert_eq!(lcm_many::<i64>(&[]), 1);
+        assert_eq!(gcd_many(&[-6i64]), 6);
+        assert_eq!(lcm_many(&[-6i64]), 6);
+        assert_eq!(gcd_many(&[0i64]), 0);
+        assert_eq!(lcm_many(&[0i64]), 0);
+    }
+
+    #[test]
+    fn field_elements_normalize_to_one() {
+        type F7 = PrimeField<7>;
+        assert_eq!(gcd_many(&[F7::new(3), F7::new(5)]), F7::new(1));
+        assert_eq!(lcm_many(&[F7::new(3), F7::new(5)]), F7::new(1));
+        assert_eq!(gcd_many(&[F7::new(4)]), F7::new(1));
+        assert!(gcd_many(&[F7::zero()]).is_zero());
+    }
+
+    #[test]
+    fn polynomial_gcd_and_lcm_are_monic() {
+        type F7 = PrimeField<7>;
+        let poly = |coefficients: &[u64]| {
+            DensePolynomial::new(coefficients.iter().map(|&c| F7::new(c)).collect())
+        };
+        // 3(x + 1)(x + 2), 5(x + 1)(x + 3) and 2(x + 1)
+        let items = [poly(&[6, 2, 3]), poly(&[1, 6, 5]), poly(&[2, 2])];
+        assert_eq!(gcd_many(&items), poly(&[1, 1]));
+        // (x + 1)(x + 2)(x + 3) = x³ + 6x² + 11x + 6
+        assert_eq!(lcm_many(&items), poly(&[6, 4, 6, 1]));
+        assert_eq!(lcm_many(&[poly(&[4, 2])]), poly(&[2, 1]));
+    }
+
+    #[test]
+    fn reconstructs_a_third_modulo_thirteen() {
+        assert_eq!(rational_reconstruct(9, 13), Some(Ratio::new(1, 3)));
+        assert_eq!(rational_reconstruct(4, 13), Some(Ratio::new(-1, 3)));
+        assert_eq!(rational_reconstruct(-4, 13), Some(Ratio::new(1, 3)));
+        assert_eq!(rational_reconstruct(12, 13), Some(Ratio::new(-1, 1)));
+        assert_eq!(rational_reconstruct(0, 13), Some(Ratio::new(0, 1)));
+    }
+
+    #[test]
+    fn random_fractions_round_trip() {
+        const P: u64 = 1_000_000_007;
+        let mut rng = SplitMix64::new(244);
+        for _ in 0..1000 {
+            let n = rng.range_i64(-20_000, 20_000);
+            let d = rng.range_i64(1, 20_000);
+            // n · d⁻¹ mod P, with 2 · 20000² < P
+            let residue = PrimeField::<P>::new(n.rem_euclid(P as i64) as u64)
+                / PrimeField::<P>::new(d as u64);
+            let recovered = rational_reconstruct(residue.value() as i64, P as i64);
+            assert_eq!(recovered, Some(Ratio::new(n, d)), "{n}/{d}");
+        }
+    }
+
+    #[test]
+    fn too_small_a_modulus_fails() {
+        // Within |n| ≤ 2 and d ≤ 3 every residue mod 13 except 3 and 10 is a fraction
+        assert_eq!(rational_reconstruct(3, 13), None);
+        assert_eq!(rational_reconstruct(10, 13), None);
+        // 2/7 ≡ 5 (mod 11) is out of range, and 5 reconstructs to −1/2 instead
+        assert_eq!(rational_reconstruct(5, 11), Some(Ratio::new(-1, 2)));
+    }
+
+    #[test]
+    fn explicit_bounds() {
+        assert_eq!(
+            rational_reconstruct_bounded(9, 13, 1, 3),
+            Some(Ratio::new(1, 3))
+        );
+        assert_eq!(rational_reconstruct_bounded(9, 13, 1, 2), None);
+        assert_eq!(rational_reconstruct(0, 1), Some(Ratio::new(0, 1)));
+    }
+
+    /// Divides |n| by basis elements as long as one divides it, returning what is left.
+    fn cofactor(n: i64, basis: &[i64]) -> i64 {
+        let mut n = n.abs();
+        for &p in basis {
+            while n % p == 0 {
+                n /= p;
+            }
+        }
+        n
+    }
+
+    #[test]
+    fn coprime_basis_of_twelve_and_eighteen() {
+        let basis = coprime_basis(&[12, 18]);
+        assert_eq!(basis, vec![2, 3]);
+        assert_eq!(cofactor(12, &basis), 1);
+        assert_eq!(cofactor(18, &basis), 1);
+    }
+
+    #[test]
+    fn coprime_basis_refines_without_factoring() {
+        // 6 and 10 share 2, leaving 3 and 5; 15 is then 3 · 5.
+        assert_eq!(coprime_basis(&[6, 10, 15]), vec![2, 3, 5]);
+        // Powers stay together: 4 = 2² and 8 = 2³ refine to 2.
+        assert_eq!(coprime_basis(&[4, 8]), vec![2]);
+        assert_eq!(coprime_basis(&[-35, 0, 1, -1, 77]), vec![5, 7, 11]);
+        assert!(coprime_basis(&[]).is_empty());
+    }
+
+    #[test]
+    fn random_coprime_bases() {
+        let mut rng = SplitMix64::new(257);
+        for _ in 0..200 {
+            let inputs: Vec<i64> = (0..5).map(|_| rng.range_i64(-5000, 5000)).collect();
+            let basis = coprime_basis(&inputs);
+            for (i, &a) in basis.iter().enumerate() {
+                assert!(a > 1);
+                for &b in &basis[i + 1..] {
+                    assert_eq!(gcd_i64(a, b), 1, "{a} and {b} from {inputs:?}");
+                }
+            }
+            for &n in inputs.iter().filter(|&&n| n != 0) {
+                assert_eq!(cofactor(n, &basis), 1, "{n} over {basis:?}");
+            }
+        }
+    }
+}
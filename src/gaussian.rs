@@ -0,0 +1,336 @@
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CanonicalAssociate, CommutativeAddition,
+    CommutativeMultiplication, Distributive,
+};
+use num_traits::{Euclid, One, Zero};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Represents a Gaussian integer a + bi with a, b ∈ ℤ, an element of ℤ\[i\].
+///
+/// # Mathematical Definition
+/// ℤ\[i\] is the subring of ℂ generated by i, with the Euclidean function N(a + bi) = a² + b².
+/// For b ≠ 0, the quotient a div b rounds each component of the exact quotient a / b ∈ ℚ(i) to
+/// the nearest integer, which leaves a remainder r = a − b · q with N(r) ≤ N(b) / 2 < N(b).
+///
+/// # Properties
+/// - The units are 1, −1, i and −i
+/// - The norm is multiplicative: N(a · b) = N(a) · N(b)
+/// - Components are i64. Division and remainder work in i128 internally and panic if a result
+///   does not fit in i64, while the other operations overflow exactly where i64 arithmetic would
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GaussianInteger {
+    re: i64,
+    im: i64,
+}
+
+impl GaussianInteger {
+    /// Creates the Gaussian integer re + im · i.
+    pub fn new(re: i64, im: i64) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns the imaginary unit i.
+    pub fn i() -> Self {
+        Self::new(0, 1)
+    }
+
+    /// Returns the real part.
+    pub fn re(&self) -> i64 {
+        self.re
+    }
+
+    /// Returns the imaginary part.
+    pub fn im(&self) -> i64 {
+        self.im
+    }
+
+    /// Returns the conjugate a − bi.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Returns the norm a² + b², the Euclidean function of ℤ\[i\].
+    pub fn norm(&self) -> u128 {
+        let re = self.re.unsigned_abs() as u128;
+        let im = self.im.unsigned_abs() as u128;
+        re * re + im * im
+    }
+}
+
+/// Returns x / n rounded to the nearest integer, with ties rounded up, for n > 0.
+fn div_round(x: i128, n: i128) -> i128 {
+    let q = x.div_euclid(n);
+    let r = x.rem_euclid(n);
+    if r >= n - r {
+        q + 1
+    } else {
+        q
+    }
+}
+
+impl From<i64> for GaussianInteger {
+    fn from(re: i64) -> Self {
+        Self::new(re, 0)
+    }
+}
+
+impl fmt::Display for GaussianInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}i", self.re, self.im)
+    }
+}
+
+impl Add for GaussianInteger {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl AddAssign for GaussianInteger {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Neg for GaussianInteger {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl Sub for GaussianInteger {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl SubAssign for GaussianInteger {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul for GaussianInteger {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl MulAssign for GaussianInteger {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Div for GaussianInteger {
+    type Output = Self;
+
+    /// Returns the exact quotient with each component rounded to the nearest integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or if the quotient does not fit in i64, as for
+    /// i64::MIN / −1.
+    fn div(self, other: Self) -> Self {
+        assert!(!other.is_zero(), "Division by a zero Gaussian integer");
+        // self / other = self · conj(other) / N(other), computed in i128. Both fit unless both
+        // components of other are i64::MIN, so an even divisor is halved and its norm doubled.
+        let (a, b) = (self.re as i128, self.im as i128);
+        let (mut c, mut d) = (other.re as i128, other.im as i128);
+        let mut scale = 1;
+        if c % 2 == 0 && d % 2 == 0 {
+            c /= 2;
+            d /= 2;
+            scale = 2;
+        }
+        let norm = scale * (c * c + d * d);
+        let re = div_round(a * c + b * d, norm);
+        let im = div_round(b * c - a * d, norm);
+        Self::new(narrow(re), narrow(im))
+    }
+}
+
+impl Rem for GaussianInteger {
+    type Output = Self;
+
+    /// Returns self − other · (self / other), whose norm is at most half that of other.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero, or if the quotient or the remainder does not fit in i64.
+    /// The remainder can reach 2⁶³ in a component only when N(other) exceeds 2¹²⁶.
+    fn rem(self, other: Self) -> Self {
+        let q = self / other;
+        // The product other · q may not fit in i64
+        let (c, d) = (other.re as i128, other.im as i128);
+        let (x, y) = (q.re as i128, q.im as i128);
+        let re = self.re as i128 - (c * x - d * y);
+        let im = self.im as i128 - (c * y + d * x);
+        Self::new(narrow(re), narrow(im))
+    }
+}
+
+/// Converts an i128 component back to i64.
+///
+/// # Panics
+///
+/// Panics if the component does not fit in i64.
+fn narrow(x: i128) -> i64 {
+    i64::try_from(x).expect("Gaussian integer component overflows i64")
+}
+
+impl Euclid for GaussianInteger {
+    fn div_euclid(&self, v: &Self) -> Self {
+        *self / *v
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        *self % *v
+    }
+}
+
+impl Zero for GaussianInteger {
+    fn zero() -> Self {
+        Self::new(0, 0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re == 0 && self.im == 0
+    }
+}
+
+impl One for GaussianInteger {
+    fn one() -> Self {
+        Self::new(1, 0)
+    }
+}
+
+impl CommutativeAddition for GaussianInteger {}
+impl AssociativeAddition for GaussianInteger {}
+impl CommutativeMultiplication for GaussianInteger {}
+impl AssociativeMultiplication for GaussianInteger {}
+impl Distributive for GaussianInteger {}
+
+impl CanonicalAssociate for GaussianInteger {
+    /// Returns the unit rotating self into the quadrant re > 0, im ≥ 0, which holds exactly
+    /// one of the four associates of a non-zero Gaussian integer.
+    fn normalizing_unit(&self) -> Self {
+        match (self.re.signum(), self.im.signum()) {
+            (0, 0) | (1, 0 | 1) => Self::one(),
+            (-1 | 0, 1) => -Self::i(),
+            (-1, -1 | 0) => -Self::one(),
+            _ => Self::i(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SplitMix64;
+    use crate::{CanonicalAssociate, EuclideanDomain, GcdDomain};
+
+    fn needs_euclidean_domain<T: EuclideanDomain>() {}
+
+    #[test]
+    fn gaussian_integers_are_a_euclidean_domain() {
+        needs_euclidean_domain::<GaussianInteger>();
+        let i = GaussianInteger::i();
+        assert_eq!(i * i, -GaussianInteger::one());
+    }
+
+    #[test]
+    fn division_with_remainder_on_random_inputs() {
+        let mut rng = SplitMix64::new(510);
+        for _ in 0..10_000 {
+            let a = GaussianInteger::new(
+                rng.range_i64(-1_000_000, 1_000_000),
+                rng.range_i64(-1_000_000, 1_000_000),
+            );
+            let b = GaussianInteger::new(rng.range_i64(-1000, 1000), rng.range_i64(-1000, 1000));
+            if b.is_zero() {
+                continue;
+            }
+            let q = Euclid::div_euclid(&a, &b);
+            let r = Euclid::rem_euclid(&a, &b);
+            assert_eq!(b * q + r, a, "{a:?} / {b:?}");
+            assert!(r.norm() < b.norm(), "{a:?} / {b:?}");
+            assert!(2 * r.norm() <= b.norm());
+        }
+    }
+
+    #[test]
+    fn division_at_the_extremes() {
+        let a = GaussianInteger::new(i64::MAX, i64::MIN + 1);
+        let b = GaussianInteger::new(3, -7);
+        let r = Euclid::rem_euclid(&a, &b);
+        assert!(r.norm() < b.norm());
+        // N(i64::MIN · (1 + i)) = 2¹²⁷ does not fit in i128, nor does the numerator of a / a
+        let min = GaussianInteger::new(i64::MIN, i64::MIN);
+        assert_eq!(min / min, GaussianInteger::one());
+        assert_eq!(min % min, GaussianInteger::zero());
+        assert_eq!(
+            min / GaussianInteger::new(i64::MIN, 0),
+            GaussianInteger::new(1, 1)
+        );
+        assert_eq!(
+            min / GaussianInteger::new(0, i64::MIN),
+            GaussianInteger::new(1, -1)
+        );
+        let c = GaussianInteger::new(i64::MAX, i64::MIN);
+        assert_eq!(c / min, GaussianInteger::new(0, 1));
+        assert_eq!(c % min, GaussianInteger::new(-1, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows i64")]
+    fn quotient_overflow_panics() {
+        let _ = GaussianInteger::new(i64::MIN, 0) / -GaussianInteger::one();
+    }
+
+    #[test]
+    fn gcd_of_associates() {
+        // 5 = (2 + i)(2 − i), and 2 + i divides 3 + 4i = (2 + i)²
+        let g = GaussianInteger::new(5, 0).gcd(&GaussianInteger::new(3, 4));
+        assert_eq!(g, GaussianInteger::new(2, 1));
+        let g = GaussianInteger::new(0, -5).gcd(&GaussianInteger::new(-4, 3));
+        assert_eq!(g, GaussianInteger::new(2, 1));
+    }
+
+    #[test]
+    fn associates_share_a_normal_form() {
+        let i = GaussianInteger::i();
+        for (re, im) in [(3, 2), (-3, 2), (0, 4), (-5, 0), (2, -7), (0, -1)] {
+            let a = GaussianInteger::new(re, im);
+            let normal = a.canonical_associate();
+            assert!(normal.re() > 0 && normal.im() >= 0, "{a:?}");
+            for unit in [i, -GaussianInteger::one(), -i] {
+                assert_eq!((a * unit).canonical_associate(), normal);
+            }
+        }
+        assert_eq!(
+            GaussianInteger::zero().canonical_associate(),
+            GaussianInteger::zero()
+        );
+    }
+
+    #[test]
+    fn norm_is_multiplicative() {
+        let a = GaussianInteger::new(3, -2);
+        let b = GaussianInteger::new(-1, 5);
+        assert_eq!((a * b).norm(), a.norm() * b.norm());
+        assert_eq!(a.conjugate(), GaussianInteger::new(3, 2));
+    }
+}
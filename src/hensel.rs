@@ -0,0 +1,271 @@
+use crate::euclidean::extended_gcd;
+use crate::{DensePolynomial, GcdDomain, Polynomial, PrimeField, Ratio};
+use num_traits::{Inv, One};
+
+/// Lifts a factorization of f modulo p to one modulo pᵏ, for k = `target_power`.
+///
+/// f must have integer coefficients and a leading coefficient prime to p, and the factors must
+/// be pairwise coprime over 𝔽ₚ with f ≡ lc(f) · ∏ᵢ gᵢ (mod p) up to their leading
+/// coefficients. The result is the unique list of monic Gᵢ with Gᵢ ≡ gᵢ / lc(gᵢ) (mod p) and
+/// f ≡ lc(f) · ∏ᵢ Gᵢ (mod pᵏ), with coefficients in the symmetric range (−pᵏ/2, pᵏ/2\]. Returns
+/// `None` if any of these conditions fails, or if a factor is constant.
+///
+/// The factors are split off one at a time as f ≡ g · h with h the product of the rest, and
+/// each split is lifted linearly: if f ≡ G · H (mod pʲ) and s · g + t · h = 1 over 𝔽ₚ, the
+/// error e = (f − G · H) / pʲ mod p is written as σ · g + τ · h with τ = t · e mod g, and
+/// G + pʲ τ, H + pʲ σ agree with f modulo pʲ⁺¹. Since deg τ < deg g, the lifts stay monic.
+///
+/// # Complexity
+/// O(r · k · n²) coefficient operations for r factors of a polynomial of degree n.
+///
+/// # Panics
+///
+/// Panics if `target_power` is zero or if pᵏ exceeds `i64::MAX`.
+pub fn hensel_lift<const P: u64>(
+    f: &DensePolynomial<Ratio<i64>>,
+    factors_mod_p: &[DensePolynomial<PrimeField<P>>],
+    target_power: u32,
+) -> Option<Vec<DensePolynomial<Ratio<i64>>>> {
+    assert!(target_power > 0, "target power must be at least one");
+    let modulus = i64::try_from(P)
+        .ok()
+        .and_then(|p| p.checked_pow(target_power))
+        .expect("p^k must fit in an i64") as i128;
+    let p = P as i128;
+
+    let mut integer = Vec::with_capacity(f.coefficients().len());
+    for c in f.coefficients() {
+        if !c.denom().is_one() {
+            return None;
+        }
+        integer.push(*c.numer() as i128);
+    }
+    let lead = *integer.last()?;
+    if lead % p == 0 {
+        return None;
+    }
+
+    let mut factors = Vec::with_capacity(factors_mod_p.len());
+    let mut product = DensePolynomial::new(vec![to_prime_field(lead)]);
+    for factor in factors_mod_p {
+        let factor_lead = *factor.leading_coefficient()?;
+        if factor.degree() == 0 {
+            return None;
+        }
+        let monic = factor.clone() * DensePolynomial::new(vec![factor_lead.inv()]);
+        product *= monic.clone();
+        factors.push(monic);
+    }
+    let reduced = DensePolynomial::new(integer.iter().map(|&c| to_prime_field(c)).collect());
+    if product != reduced {
+        return None;
+    }
+    for (i, g) in factors.iter().enumerate() {
+        if factors[..i].iter().any(|h| !g.gcd(h).is_one()) {
+            return None;
+        }
+    }
+
+    // Dividing by lc(f), a unit modulo pᵏ, leaves a monic target with the same factors.
+    let lead_inverse = inverse_mod(lead, modulus);
+    let mut target: Vec<i128> = integer
+        .iter()
+        .map(|&c| (c * lead_inverse).rem_euclid(modulus))
+        .collect();
+    let mut lifted = Vec::with_capacity(factors.len());
+    for (i, g) in factors
+        .iter()
+        .enumerate()
+        .take(factors.len().saturating_sub(1))
+    {
+        let h = factors[i + 1..]
+            .iter()
+            .fold(DensePolynomial::one(), |acc, factor| acc * factor.clone());
+        let (g_lifted, h_lifted) = lift_pair(&target, g, &h, target_power, modulus);
+        lifted.push(g_lifted);
+        target = h_lifted;
+    }
+    if !factors.is_empty() {
+        lifted.push(target);
+    }
+    Some(
+        lifted
+            .into_iter()
+            .map(|coefficients| {
+                let centered = coefficients.into_iter().map(|c| {
+                    let c = if 2 * c > modulus { c - modulus } else { c };
+                    Ratio::from(c as i64)
+                });
+                DensePolynomial::new(centered.collect())
+            })
+            .collect(),
+    )
+}
+
+/// Lifts f ≡ g · h (mod p) with coprime monic g and h to monic G and H with f ≡ G · H modulo
+/// pᵏ = `modulus`, for a monic f reduced modulo pᵏ.
+fn lift_pair<const P: u64>(
+    f: &[i128],
+    g: &DensePolynomial<PrimeField<P>>,
+    h: &DensePolynomial<PrimeField<P>>,
+    power: u32,
+    modulus: i128,
+) -> (Vec<i128>, Vec<i128>) {
+    // Only t of the Bézout pair s · g + t · h = 1 is needed.
+    let (d, _, t) = extended_gcd(g.clone(), h.clone());
+    let t = t * DensePolynomial::new(vec![d.coefficient(0).inv()]);
+    let lift = |q: &DensePolynomial<PrimeField<P>>| -> Vec<i128> {
+        q.coefficients().iter().map(|c| c.value() as i128).collect()
+    };
+    let (mut g_lifted, mut h_lifted) = (lift(g), lift(h));
+    let mut step = P as i128;
+    for _ in 1..power {
+        let product = mul_mod(&g_lifted, &h_lifted, modulus);
+        let error = DensePolynomial::new(
+            (0..f.len())
+                .map(|i| {
+                    let difference = f[i] - product.get(i).copied().unwrap_or(0);
+                    to_prime_field(difference.rem_euclid(modulus) / step)
+                })
+                .collect(),
+        );
+        let tau = (t.clone() * error.clone()) % g.clone();
+        let sigma = (error - tau.clone() * h.clone()) / g.clone();
+        add_scaled(&mut g_lifted, &lift(&tau), step, modulus);
+        add_scaled(&mut h_lifted, &lift(&sigma), step, modulus);
+        step *= P as i128;
+    }
+    (g_lifted, h_lifted)
+}
+
+fn to_prime_field<const P: u64>(c: i128) -> PrimeField<P> {
+    PrimeField::new(c.rem_euclid(P as i128) as u64)
+}
+
+/// Returns the product of two coefficient vectors modulo m, reducing after every term so the
+/// intermediates stay below m².
+fn mul_mod(a: &[i128], b: &[i128], m: i128) -> Vec<i128> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![0; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] = (product[i + j] + x * y) % m;
+        }
+    }
+    product
+}
+
+/// Adds scale · b to a modulo m, extending a as needed.
+fn add_scaled(a: &mut Vec<i128>, b: &[i128], scale: i128, m: i128) {
+    if a.len() < b.len() {
+        a.resize(b.len(), 0);
+    }
+    for (x, y) in a.iter_mut().zip(b) {
+        *x = (*x + y * scale) % m;
+    }
+}
+
+/// Returns the inverse of a modulo m, for a prime to m.
+fn inverse_mod(a: i128, m: i128) -> i128 {
+    let (_, x, _) = extended_gcd(a.rem_euclid(m), m);
+    x.rem_euclid(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type F5 = PrimeField<5>;
+    type F7 = PrimeField<7>;
+
+    fn integer_poly(coefficients: &[i64]) -> DensePolynomial<Ratio<i64>> {
+        DensePolynomial::new(coefficients.iter().map(|&c| Ratio::from(c)).collect())
+    }
+
+    fn poly_f5(coefficients: &[u64]) -> DensePolynomial<F5> {
+        DensePolynomial::new(coefficients.iter().map(|&c| F5::new(c)).collect())
+    }
+
+    fn integers(p: &DensePolynomial<Ratio<i64>>) -> Vec<i128> {
+        p.coefficients()
+            .iter()
+            .map(|c| *c.numer() as i128)
+            .collect()
+    }
+
+    /// Returns the product of the factors modulo m, as residues in [0, m).
+    fn product_mod(factors: &[Vec<i128>], m: i128) -> Vec<i128> {
+        factors
+            .iter()
+            .fold(vec![1], |acc, factor| mul_mod(&acc, factor, m))
+            .into_iter()
+            .map(|c| c.rem_euclid(m))
+            .collect()
+    }
+
+    #[test]
+    fn lifts_a_factorization_modulo_five() {
+        // 2(x² + 1)(x + 1) with x² + 1 ≡ (x + 2)(x + 3) mod 5, the first factor given as 3x + 1
+        let f = integer_poly(&[2, 2, 2, 2]);
+        let factors = [poly_f5(&[1, 3]), poly_f5(&[3, 1]), poly_f5(&[1, 1])];
+        let lifted = hensel_lift(&f, &factors, 4).unwrap();
+        let m = 625;
+        let lifted: Vec<Vec<i128>> = lifted.iter().map(integers).collect();
+        let expected: Vec<i128> = integers(&f).iter().map(|c| c.rem_euclid(m)).collect();
+        let product = product_mod(&lifted, m);
+        assert_eq!(product_mod(&[product, vec![2]], m), expected);
+        for (lift, factor) in lifted.iter().zip(&factors) {
+            assert_eq!(lift.last(), Some(&1));
+            let reduced = DensePolynomial::new(lift.iter().map(|&c| to_prime_field(c)).collect());
+            assert_eq!(reduced * DensePolynomial::new(vec![factor[1]]), *factor);
+        }
+        // The lifts of x + 2 and x + 3 recombine into the true factor x² + 1, and x + 1 is exact.
+        assert_eq!(product_mod(&lifted[..2], m), [1, 0, 1]);
+        assert_eq!(lifted[2], [1, 1]);
+    }
+
+    #[test]
+    fn lifting_a_root_finds_a_seven_adic_square_root() {
+        // x² − 2 ≡ (x − 3)(x + 3) mod 7, so the lift of x − 3 is x − r with r² ≡ 2 mod 7⁶
+        let f = integer_poly(&[-2, 0, 1]);
+        let factors = [
+            DensePolynomial::new(vec![F7::new(4), F7::new(1)]),
+            DensePolynomial::new(vec![F7::new(3), F7::new(1)]),
+        ];
+        let lifted = hensel_lift(&f, &factors, 6).unwrap();
+        let m = 7i128.pow(6);
+        let r = -integers(&lifted[0])[0];
+        assert_eq!((r * r - 2).rem_euclid(m), 0);
+        assert_eq!(r.rem_euclid(7), 3);
+        assert!(2 * r.abs() <= m);
+        assert_eq!(integers(&lifted[1]), [r, 1]);
+        // A single factor lifts to f / lc(f) itself.
+        let single = [DensePolynomial::new(vec![
+            F7::new(3),
+            F7::new(0),
+            F7::new(2),
+        ])];
+        assert_eq!(hensel_lift(&f, &single, 3), Some(vec![f]));
+    }
+
+    #[test]
+    fn rejects_invalid_factorizations() {
+        // (x + 1)² has a repeated factor mod 5
+        let square = integer_poly(&[1, 2, 1]);
+        let repeated = [poly_f5(&[1, 1]), poly_f5(&[1, 1])];
+        assert_eq!(hensel_lift(&square, &repeated, 3), None);
+        // The factors must multiply to f mod p
+        assert_eq!(
+            hensel_lift(&square, &[poly_f5(&[1, 1]), poly_f5(&[2, 1])], 3),
+            None
+        );
+        // p must not divide the leading coefficient
+        let f = integer_poly(&[1, 0, 5]);
+        assert_eq!(hensel_lift(&f, &[poly_f5(&[1])], 2), None);
+        // f must have integer coefficients
+        let f = DensePolynomial::new(vec![Ratio::new(1, 2), Ratio::from(1)]);
+        assert_eq!(hensel_lift(&f, &[poly_f5(&[3, 1])], 2), None);
+    }
+}
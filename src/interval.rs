@@ -0,0 +1,255 @@
+use crate::RealField;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Represents a closed interval [lo, hi] over a real field, for enclosure arithmetic.
+///
+/// # Mathematical Definition
+/// For intervals X and Y and an operation ∘ ∈ {+, −, ·, /}, the result X ∘ Y is the smallest
+/// interval containing {x ∘ y | x ∈ X, y ∈ Y}. Division is only defined when 0 ∉ Y.
+///
+/// # Properties
+/// - Enclosure: if x ∈ X and y ∈ Y then x ∘ y ∈ X ∘ Y
+/// - Addition and multiplication are associative and commutative, but multiplication only
+///   sub-distributes over addition: X · (Y + Z) ⊆ X · Y + X · Z
+///
+/// # Rounding
+/// Endpoints round outward through the `add_bounds`, `mul_bounds` and `div_bounds` hooks of
+/// `RealField`: each lower endpoint is a lower bound of the exact result and each upper
+/// endpoint an upper bound. For f32 and f64 this widens an inexact endpoint by one ulp, so the
+/// enclosure is rigorous; exact results, and all results in exact fields, are not widened.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Interval<F: RealField> {
+    lo: F,
+    hi: F,
+}
+
+impl<F: RealField> Interval<F> {
+    /// Creates the interval [lo, hi].
+    ///
+    /// # Panics
+    ///
+    /// Panics if lo > hi or the endpoints are unordered.
+    pub fn new(lo: F, hi: F) -> Self {
+        assert!(
+            lo <= hi,
+            "Interval lower bound must not exceed the upper bound"
+        );
+        Self { lo, hi }
+    }
+
+    /// Creates the degenerate interval [x, x].
+    pub fn point(x: F) -> Self {
+        Self {
+            lo: x.clone(),
+            hi: x,
+        }
+    }
+
+    /// Returns the lower endpoint.
+    pub fn lo(&self) -> &F {
+        &self.lo
+    }
+
+    /// Returns the upper endpoint.
+    pub fn hi(&self) -> &F {
+        &self.hi
+    }
+
+    /// Returns the width hi − lo.
+    pub fn width(&self) -> F {
+        self.hi.clone() - self.lo.clone()
+    }
+
+    /// Returns whether x lies in the interval.
+    pub fn contains(&self, x: &F) -> bool {
+        self.lo <= *x && *x <= self.hi
+    }
+
+    /// Divides by other, returning `None` if other contains zero.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.contains(&F::zero()) {
+            return None;
+        }
+        Some(Self::hull([
+            self.lo.div_bounds(&other.lo),
+            self.lo.div_bounds(&other.hi),
+            self.hi.div_bounds(&other.lo),
+            self.hi.div_bounds(&other.hi),
+        ]))
+    }
+
+    /// Returns the interval from the least lower bound to the greatest upper bound.
+    fn hull(bounds: [(F, F); 4]) -> Self {
+        let [(mut lo, mut hi), rest @ ..] = bounds;
+        for (candidate_lo, candidate_hi) in rest {
+            if candidate_lo < lo {
+                lo = candidate_lo;
+            }
+            if candidate_hi > hi {
+                hi = candidate_hi;
+            }
+        }
+        Self { lo, hi }
+    }
+}
+
+impl<F: RealField> Add for Interval<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            lo: self.lo.add_bounds(&other.lo).0,
+            hi: self.hi.add_bounds(&other.hi).1,
+        }
+    }
+}
+
+impl<F: RealField> Sub for Interval<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            lo: self.lo.add_bounds(&-other.hi).0,
+            hi: self.hi.add_bounds(&-other.lo).1,
+        }
+    }
+}
+
+impl<F: RealField> Neg for Interval<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl<F: RealField> Mul for Interval<F> {
+    type Output = Self;
+
+    /// Multiplies intervals by taking the hull of the four endpoint products.
+    fn mul(self, other: Self) -> Self {
+        Self::hull([
+            self.lo.mul_bounds(&other.lo),
+            self.lo.mul_bounds(&other.hi),
+            self.hi.mul_bounds(&other.lo),
+            self.hi.mul_bounds(&other.hi),
+        ])
+    }
+}
+
+impl<F: RealField> Div for Interval<F> {
+    type Output = Self;
+
+    /// Divides intervals.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor contains zero; use `checked_div` to handle that case.
+    fn div(self, other: Self) -> Self {
+        self.checked_div(other)
+            .expect("Division by an interval containing zero")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SplitMix64;
+
+    fn interval(lo: f64, hi: f64) -> Interval<f64> {
+        Interval::new(lo, hi)
+    }
+
+    #[test]
+    fn exact_endpoints_are_not_widened() {
+        assert_eq!(interval(1.0, 2.0) + interval(3.0, 4.0), interval(4.0, 6.0));
+        assert_eq!(
+            interval(1.0, 2.0) - interval(3.0, 4.0),
+            interval(-3.0, -1.0)
+        );
+        assert_eq!(-interval(1.0, 2.0), interval(-2.0, -1.0));
+        assert_eq!(interval(1.0, 2.0).width(), 1.0);
+    }
+
+    #[test]
+    fn multiplication_covers_every_sign_combination() {
+        assert_eq!(
+            interval(-1.0, 2.0) * interval(-3.0, 4.0),
+            interval(-6.0, 8.0)
+        );
+        assert_eq!(
+            interval(1.0, 2.0) * interval(-3.0, -1.0),
+            interval(-6.0, -1.0)
+        );
+        assert_eq!(
+            interval(-2.0, -1.0) * interval(-3.0, -1.0),
+            interval(1.0, 6.0)
+        );
+        assert_eq!(
+            interval(-2.0, 1.0) * interval(3.0, 4.0),
+            interval(-8.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn division() {
+        assert_eq!(
+            interval(1.0, 2.0) / interval(4.0, 8.0),
+            interval(0.125, 0.5)
+        );
+        assert_eq!(
+            interval(-1.0, 2.0) / interval(-4.0, -2.0),
+            interval(-1.0, 0.5)
+        );
+        assert_eq!(interval(1.0, 2.0).checked_div(interval(-1.0, 1.0)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "containing zero")]
+    fn division_by_an_interval_containing_zero_panics() {
+        let _ = interval(1.0, 2.0) / interval(0.0, 1.0);
+    }
+
+    #[test]
+    fn inexact_sums_round_outward() {
+        let sum = interval(0.1, 0.1) + interval(0.2, 0.2);
+        // In units of 2⁻⁵⁵ the operands are 3602879701896397 and twice that, so the exact sum
+        // is 3 · 3602879701896397, which needs 54 bits and is not representable
+        let exact = 3 * 3_602_879_701_896_397i128;
+        let scale = 2f64.powi(55);
+        assert!(((sum.lo() * scale) as i128) < exact);
+        assert!((sum.hi() * scale) as i128 > exact);
+        assert!(sum.contains(&(0.1 + 0.2)));
+    }
+
+    #[test]
+    fn third_is_enclosed() {
+        let third = interval(1.0, 1.0) / interval(3.0, 3.0);
+        assert!(third.lo() < third.hi());
+        assert!((*third.lo()).mul_add(3.0, -1.0) < 0.0);
+        assert!((*third.hi()).mul_add(3.0, -1.0) > 0.0);
+    }
+
+    #[test]
+    fn sampled_points_stay_enclosed() {
+        let mut rng = SplitMix64::new(223);
+        let random_interval = |rng: &mut SplitMix64| {
+            let (a, b) = (rng.range_f64(-10.0, 10.0), rng.range_f64(-10.0, 10.0));
+            interval(a.min(b), a.max(b))
+        };
+        for _ in 0..1000 {
+            let (x, y) = (random_interval(&mut rng), random_interval(&mut rng));
+            let (t, u) = (rng.range_f64(0.0, 1.0), rng.range_f64(0.0, 1.0));
+            let a = (x.lo() + t * x.width()).clamp(*x.lo(), *x.hi());
+            let b = (y.lo() + u * y.width()).clamp(*y.lo(), *y.hi());
+            // The rational function a · b − a + b / (b + 30), pointwise and on intervals
+            let shift = y.clone() + Interval::point(30.0);
+            let enclosure = x.clone() * y.clone() - x + y / shift;
+            let value = a * b - a + b / (b + 30.0);
+            assert!(enclosure.contains(&value), "{value} not in {enclosure:?}");
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::{Complex, DensePolynomial, EuclideanDomain, Field, Matrix, PrimeField, Ratio, Ring};
+use crate::{ReductionStrategy, Vector};
+
+// LaTeX output is for documents, so it differs from `Display` in the ways typesetting needs:
+// fractions use \frac, powers use braces, unit coefficients and zero terms are dropped and the
+// sign of a negative term becomes the operator in front of it. Compound values are
+// parenthesized when they appear as a coefficient.
+
+/// Renders a value as a LaTeX math-mode fragment, without surrounding `$` delimiters.
+///
+/// Available with the `latex` feature.
+pub trait LaTeX {
+    /// Returns the LaTeX source for self.
+    fn to_latex(&self) -> String;
+}
+
+macro_rules! impl_latex_by_display {
+    ($($t:ty),*) => {
+        $(
+            impl LaTeX for $t {
+                fn to_latex(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_latex_by_display!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<const P: u64, R: ReductionStrategy<P>> LaTeX for PrimeField<P, R> {
+    /// Renders the canonical representative in 0..P.
+    fn to_latex(&self) -> String {
+        self.value().to_string()
+    }
+}
+
+impl<T: EuclideanDomain + LaTeX> LaTeX for Ratio<T> {
+    /// Renders a/b as `\frac{a}{b}`, with a leading minus sign outside the fraction, and a
+    /// fraction with denominator one as its numerator alone.
+    fn to_latex(&self) -> String {
+        let numer = self.numer().to_latex();
+        if self.denom().is_one() {
+            return numer;
+        }
+        let denom = self.denom().to_latex();
+        match numer.strip_prefix('-') {
+            Some(magnitude) => format!("-\\frac{{{magnitude}}}{{{denom}}}"),
+            None => format!("\\frac{{{numer}}}{{{denom}}}"),
+        }
+    }
+}
+
+impl<F: Field + LaTeX> LaTeX for Complex<F> {
+    /// Renders a + bi, omitting a zero part and a unit imaginary coefficient, as in `3 - 2i`,
+    /// `i` and `0`.
+    fn to_latex(&self) -> String {
+        let (re, im) = (self.re(), self.im());
+        if im.is_zero() {
+            return re.to_latex();
+        }
+        let imaginary = if im.is_one() {
+            "i".to_string()
+        } else if *im == -F::one() {
+            "-i".to_string()
+        } else {
+            format!("{}i", parenthesize(im.to_latex()))
+        };
+        if re.is_zero() {
+            return imaginary;
+        }
+        match imaginary.strip_prefix('-') {
+            Some(magnitude) => format!("{} - {magnitude}", re.to_latex()),
+            None => format!("{} + {imaginary}", re.to_latex()),
+        }
+    }
+}
+
+impl<F: Field + LaTeX> LaTeX for DensePolynomial<F> {
+    /// Renders the terms from the highest degree down, as in `3x^{2} - x + 1`: zero terms are
+    /// skipped, coefficients ±1 are left implicit except on the constant term, and the zero
+    /// polynomial is `0`.
+    fn to_latex(&self) -> String {
+        let mut latex = String::new();
+        for (degree, coefficient) in self.coefficients().iter().enumerate().rev() {
+            if coefficient.is_zero() {
+                continue;
+            }
+            let power = match degree {
+                0 => String::new(),
+                1 => "x".to_string(),
+                _ => format!("x^{{{degree}}}"),
+            };
+            let (negative, magnitude) = if degree > 0 && coefficient.is_one() {
+                (false, String::new())
+            } else if degree > 0 && *coefficient == -F::one() {
+                (true, String::new())
+            } else {
+                let rendered = parenthesize(coefficient.to_latex());
+                match rendered.strip_prefix('-') {
+                    Some(magnitude) => (true, magnitude.to_string()),
+                    None => (false, rendered),
+                }
+            };
+            let sign = match (latex.is_empty(), negative) {
+                (true, true) => "-",
+                (true, false) => "",
+                (false, true) => " - ",
+                (false, false) => " + ",
+            };
+            latex.push_str(&format!("{sign}{magnitude}{power}"));
+        }
+        if latex.is_empty() {
+            latex.push('0');
+        }
+        latex
+    }
+}
+
+impl<T: Ring + LaTeX, const R: usize, const C: usize> LaTeX for Matrix<T, R, C> {
+    /// Renders a `pmatrix`, with `&` between entries and `\\` between rows.
+    fn to_latex(&self) -> String {
+        let rows: Vec<String> = self
+            .rows()
+            .iter()
+            .map(|row| {
+                let entries: Vec<String> = row.iter().map(LaTeX::to_latex).collect();
+                entries.join(" & ")
+            })
+            .collect();
+        format!("\\begin{{pmatrix}}{}\\end{{pmatrix}}", rows.join(" \\\\ "))
+    }
+}
+
+impl<T: Ring + LaTeX, const N: usize> LaTeX for Vector<T, N> {
+    /// Renders a column vector as an N × 1 `pmatrix`.
+    fn to_latex(&self) -> String {
+        let entries: Vec<String> = self.components().iter().map(LaTeX::to_latex).collect();
+        format!(
+            "\\begin{{pmatrix}}{}\\end{{pmatrix}}",
+            entries.join(" \\\\ ")
+        )
+    }
+}
+
+/// Wraps a rendered value in parentheses when it is a sum, so it can be used as a factor.
+fn parenthesize(latex: String) -> String {
+    if latex.contains(" + ") || latex.contains(" - ") {
+        format!("\\left({latex}\\right)")
+    } else {
+        latex
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_traits::Zero;
+
+    fn q(n: i64, d: i64) -> Ratio<i64> {
+        Ratio::new(n, d)
+    }
+
+    #[test]
+    fn rationals_render_as_fractions() {
+        assert_eq!(q(3, 4).to_latex(), "\\frac{3}{4}");
+        assert_eq!(q(-6, 8).to_latex(), "-\\frac{3}{4}");
+        assert_eq!(q(5, -1).to_latex(), "-5");
+        assert_eq!(Ratio::<i64>::zero().to_latex(), "0");
+    }
+
+    #[test]
+    fn polynomials_render_from_the_leading_term() {
+        let p = DensePolynomial::new(vec![1.0, 2.0, 3.0]);
+        assert_eq!(p.to_latex(), "3x^{2} + 2x + 1");
+        let p = DensePolynomial::new(vec![-1.0, 0.0, 0.0, 1.0, -2.0]);
+        assert_eq!(p.to_latex(), "-2x^{4} + x^{3} - 1");
+        let p = DensePolynomial::new(vec![0.0, -1.0, 1.0]);
+        assert_eq!(p.to_latex(), "x^{2} - x");
+        let p = DensePolynomial::new(vec![-7.0]);
+        assert_eq!(p.to_latex(), "-7");
+        assert_eq!(DensePolynomial::<f64>::zero().to_latex(), "0");
+    }
+
+    #[test]
+    fn polynomial_coefficients_keep_their_own_notation() {
+        let p = DensePolynomial::new(vec![q(1, 2), q(0, 1), q(-3, 4)]);
+        assert_eq!(p.to_latex(), "-\\frac{3}{4}x^{2} + \\frac{1}{2}");
+        let p = DensePolynomial::new(vec![Complex::new(0.0, 1.0), Complex::new(1.0, -2.0)]);
+        assert_eq!(p.to_latex(), "\\left(1 - 2i\\right)x + i");
+        // Residues are rendered in 0..P, so there are no negative coefficients
+        type F7 = PrimeField<7>;
+        let p = DensePolynomial::new(vec![F7::new(6), F7::new(1), F7::new(3)]);
+        assert_eq!(p.to_latex(), "3x^{2} + x + 6");
+    }
+
+    #[test]
+    fn matrices_render_as_pmatrix() {
+        let a = Matrix::from_rows([[1, -2], [0, 4]]);
+        assert_eq!(
+            a.to_latex(),
+            "\\begin{pmatrix}1 & -2 \\\\ 0 & 4\\end{pmatrix}"
+        );
+        let a = Matrix::from_rows([[q(1, 2), q(0, 1)], [q(-1, 3), q(2, 1)]]);
+        assert_eq!(
+            a.to_latex(),
+            "\\begin{pmatrix}\\frac{1}{2} & 0 \\\\ -\\frac{1}{3} & 2\\end{pmatrix}"
+        );
+        assert_eq!(
+            Vector::new([1, 2, 3]).to_latex(),
+            "\\begin{pmatrix}1 \\\\ 2 \\\\ 3\\end{pmatrix}"
+        );
+    }
+
+    #[test]
+    fn complex_numbers_drop_zero_parts() {
+        assert_eq!(Complex::new(3.0, 2.0).to_latex(), "3 + 2i");
+        assert_eq!(Complex::new(3.0, -2.0).to_latex(), "3 - 2i");
+        assert_eq!(Complex::new(0.0, 1.0).to_latex(), "i");
+        assert_eq!(Complex::new(1.0, -1.0).to_latex(), "1 - i");
+        assert_eq!(Complex::new(-2.5, 0.0).to_latex(), "-2.5");
+        assert_eq!(Complex::<f64>::zero().to_latex(), "0");
+        assert_eq!(
+            Complex::new(q(1, 2), q(-1, 3)).to_latex(),
+            "\\frac{1}{2} - \\frac{1}{3}i"
+        );
+    }
+}
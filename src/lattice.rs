@@ -0,0 +1,344 @@
+use crate::{AssociativeJoin, CommutativeJoin, IdempotentJoin, Join, JoinSemiLattice};
+
+/// An endpoint of an integer interval, extended with ±∞.
+///
+/// The derived order places `NegInfinity` below every finite value and `PosInfinity` above it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Bound {
+    NegInfinity,
+    Finite(i64),
+    PosInfinity,
+}
+
+/// The interval abstract domain over the extended integers ℤ ∪ {−∞, +∞}.
+///
+/// # Mathematical Definition
+/// Elements are the empty interval ⊥ and the intervals [lo, hi] with lo ≤ hi, ordered by
+/// inclusion. The top element is [−∞, +∞].
+/// - Join (⊔) is the interval hull: [a, b] ⊔ [c, d] = [min(a, c), max(b, d)]
+/// - Meet (⊓) is the intersection, which is ⊥ when the intervals are disjoint
+///
+/// # Properties
+/// - The lattice has infinite ascending chains, so fixpoint iteration uses `widen` to terminate
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IntervalLattice {
+    Bottom,
+    Range { lo: Bound, hi: Bound },
+}
+
+impl IntervalLattice {
+    /// Returns the finite interval [lo, hi], or ⊥ if lo > hi.
+    pub fn new(lo: i64, hi: i64) -> Self {
+        Self::from_bounds(Bound::Finite(lo), Bound::Finite(hi))
+    }
+
+    /// Returns the interval between two extended bounds, or ⊥ if lo > hi.
+    pub fn from_bounds(lo: Bound, hi: Bound) -> Self {
+        if lo > hi {
+            IntervalLattice::Bottom
+        } else {
+            IntervalLattice::Range { lo, hi }
+        }
+    }
+
+    /// Returns the empty interval ⊥.
+    pub fn bottom() -> Self {
+        IntervalLattice::Bottom
+    }
+
+    /// Returns the interval [−∞, +∞].
+    pub fn top() -> Self {
+        IntervalLattice::Range {
+            lo: Bound::NegInfinity,
+            hi: Bound::PosInfinity,
+        }
+    }
+
+    /// Returns whether n lies in the interval.
+    pub fn contains(&self, n: i64) -> bool {
+        match self {
+            IntervalLattice::Bottom => false,
+            IntervalLattice::Range { lo, hi } => *lo <= Bound::Finite(n) && Bound::Finite(n) <= *hi,
+        }
+    }
+
+    /// Returns the intersection of two intervals.
+    pub fn meet(&self, other: &Self) -> Self {
+        match (self, other) {
+            (IntervalLattice::Range { lo: a, hi: b }, IntervalLattice::Range { lo: c, hi: d }) => {
+                Self::from_bounds(*a.max(c), *b.min(d))
+            }
+            _ => IntervalLattice::Bottom,
+        }
+    }
+
+    /// Widens self by the next iterate, jumping any bound that moved outward to infinity.
+    ///
+    /// The result is an upper bound of both arguments. Each bound can jump at most once, so
+    /// any sequence of widenings stabilises after finitely many steps.
+    pub fn widen(&self, next: &Self) -> Self {
+        match (self, next) {
+            (IntervalLattice::Bottom, _) => *next,
+            (_, IntervalLattice::Bottom) => *self,
+            (IntervalLattice::Range { lo: a, hi: b }, IntervalLattice::Range { lo: c, hi: d }) => {
+                IntervalLattice::Range {
+                    lo: if c < a { Bound::NegInfinity } else { *a },
+                    hi: if d > b { Bound::PosInfinity } else { *b },
+                }
+            }
+        }
+    }
+}
+
+impl AssociativeJoin for IntervalLattice {}
+impl CommutativeJoin for IntervalLattice {}
+impl IdempotentJoin for IntervalLattice {}
+
+impl Join for IntervalLattice {
+    /// Returns the interval hull.
+    fn join(self, other: &Self) -> Self {
+        match (self, other) {
+            (IntervalLattice::Bottom, _) => *other,
+            (_, IntervalLattice::Bottom) => self,
+            (IntervalLattice::Range { lo: a, hi: b }, IntervalLattice::Range { lo: c, hi: d }) => {
+                IntervalLattice::Range {
+                    lo: a.min(*c),
+                    hi: b.max(*d),
+                }
+            }
+        }
+    }
+
+    /// The identity element: the empty interval ⊥.
+    fn identity(&self) -> Self {
+        IntervalLattice::Bottom
+    }
+}
+
+impl JoinSemiLattice for IntervalLattice {}
+
+/// Computes the least fixpoint of a monotone function by Kleene iteration from ⊥.
+///
+/// Starting from x₀ = ⊥ (the join identity of `seed`), iterates xₙ₊₁ = xₙ ⊔ f(xₙ) until
+/// xₙ₊₁ = xₙ. Returns `None` if no fixpoint is reached within `max_iterations`, which can
+/// happen on lattices with infinite ascending chains; use `least_fixpoint_with_widening` there.
+pub fn least_fixpoint<L>(seed: &L, f: impl Fn(&L) -> L, max_iterations: usize) -> Option<L>
+where
+    L: JoinSemiLattice + Clone + PartialEq,
+{
+    least_fixpoint_with_widening(seed, f, |_, next| next.clone(), max_iterations)
+}
+
+/// Computes a post-fixpoint of a monotone function, accelerating the iteration with a widening.
+///
+/// Iterates xₙ₊₁ = xₙ ∇ (xₙ ⊔ f(xₙ)) from ⊥ until xₙ₊₁ = xₙ. When ∇ is a widening operator
+/// the iteration terminates even on infinite ascending chains, at the cost of returning an
+/// over-approximation of the least fixpoint. Returns `None` if no fixpoint is reached within
+/// `max_iterations`.
+pub fn least_fixpoint_with_widening<L>(
+    seed: &L,
+    f: impl Fn(&L) -> L,
+    widen: impl Fn(&L, &L) -> L,
+    max_iterations: usize,
+) -> Option<L>
+where
+    L: JoinSemiLattice + Clone + PartialEq,
+{
+    let mut current = seed.identity();
+    for _ in 0..max_iterations {
+        let next = widen(&current, &current.clone().join(&f(&current)));
+        if next == current {
+            return Some(current);
+        }
+        current = next;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_is_the_interval_hull() {
+        let joined = IntervalLattice::new(1, 3).join(&IntervalLattice::new(5, 7));
+        assert_eq!(joined, IntervalLattice::new(1, 7));
+        assert!(joined.contains(4));
+        let bottom = IntervalLattice::bottom();
+        assert_eq!(
+            bottom.join(&IntervalLattice::new(2, 2)),
+            IntervalLattice::new(2, 2)
+        );
+        assert_eq!(
+            IntervalLattice::new(2, 2).join(&bottom),
+            IntervalLattice::new(2, 2)
+        );
+        assert_eq!(
+            IntervalLattice::new(0, 1).join(&IntervalLattice::top()),
+            IntervalLattice::top()
+        );
+    }
+
+    #[test]
+    fn meet_is_the_intersection() {
+        let met = IntervalLattice::new(1, 5).meet(&IntervalLattice::new(3, 9));
+        assert_eq!(met, IntervalLattice::new(3, 5));
+        assert_eq!(IntervalLattice::top().meet(&met), met);
+        assert_eq!(
+            IntervalLattice::new(1, 3).meet(&IntervalLattice::new(3, 4)),
+            IntervalLattice::new(3, 3)
+        );
+    }
+
+    #[test]
+    fn disjoint_meet_is_bottom() {
+        let met = IntervalLattice::new(1, 3).meet(&IntervalLattice::new(5, 7));
+        assert_eq!(met, IntervalLattice::Bottom);
+        assert!(!met.contains(4));
+        assert_eq!(IntervalLattice::new(5, 1), IntervalLattice::Bottom);
+        assert_eq!(
+            IntervalLattice::bottom().meet(&IntervalLattice::top()),
+            IntervalLattice::Bottom
+        );
+    }
+
+    #[test]
+    fn widening_jumps_unstable_bounds_to_infinity() {
+        let x = IntervalLattice::new(0, 1);
+        assert_eq!(
+            x.widen(&IntervalLattice::new(0, 2)),
+            IntervalLattice::from_bounds(Bound::Finite(0), Bound::PosInfinity)
+        );
+        assert_eq!(
+            x.widen(&IntervalLattice::new(-1, 1)),
+            IntervalLattice::from_bounds(Bound::NegInfinity, Bound::Finite(1))
+        );
+        assert_eq!(
+            x.widen(&IntervalLattice::new(-5, 5)),
+            IntervalLattice::top()
+        );
+        // Stable or shrinking bounds are kept
+        assert_eq!(x.widen(&IntervalLattice::new(0, 0)), x);
+        assert_eq!(IntervalLattice::bottom().widen(&x), x);
+        assert_eq!(x.widen(&IntervalLattice::bottom()), x);
+    }
+
+    #[test]
+    fn bounds_are_ordered_with_infinities_outside() {
+        assert!(Bound::NegInfinity < Bound::Finite(i64::MIN));
+        assert!(Bound::Finite(i64::MAX) < Bound::PosInfinity);
+        assert!(Bound::Finite(-1) < Bound::Finite(1));
+        assert!(IntervalLattice::top().contains(i64::MIN));
+    }
+
+    /// Subsets of {0, ..., 7} ordered by inclusion, with union as join.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Bits(u8);
+
+    impl AssociativeJoin for Bits {}
+    impl CommutativeJoin for Bits {}
+    impl IdempotentJoin for Bits {}
+
+    impl Join for Bits {
+        fn join(self, other: &Self) -> Self {
+            Bits(self.0 | other.0)
+        }
+
+        fn identity(&self) -> Self {
+            Bits(0)
+        }
+    }
+
+    impl JoinSemiLattice for Bits {}
+
+    /// Abstract transfer of `i = i + 1` guarded by `i < limit`, joined with the entry `i = 0`.
+    fn increment_below(limit: Option<i64>) -> impl Fn(&IntervalLattice) -> IntervalLattice {
+        move |x| {
+            let guarded = match limit {
+                Some(limit) => x.meet(&IntervalLattice::new(i64::MIN, limit - 1)),
+                None => *x,
+            };
+            let stepped = match guarded {
+                IntervalLattice::Range { lo, hi } => {
+                    let step = |b| match b {
+                        Bound::Finite(n) => Bound::Finite(n + 1),
+                        infinite => infinite,
+                    };
+                    IntervalLattice::from_bounds(step(lo), step(hi))
+                }
+                IntervalLattice::Bottom => IntervalLattice::Bottom,
+            };
+            IntervalLattice::new(0, 0).join(&stepped)
+        }
+    }
+
+    #[test]
+    fn boolean_fixpoints() {
+        // The subsets of {0} form the boolean lattice false < true, with join as logical or
+        let identity = least_fixpoint(&Bits(0), |x| *x, 10);
+        assert_eq!(identity, Some(Bits(0)));
+        let constant = least_fixpoint(&Bits(0), |_| Bits(1), 10);
+        assert_eq!(constant, Some(Bits(1)));
+    }
+
+    #[test]
+    fn reachability_as_a_least_fixpoint() {
+        // Edges 0 → 1 → 2 → 0 and 3 → 4; from 0 only {0, 1, 2} is reachable
+        let successors = |node: u8| match node {
+            0 => 0b10,
+            1 => 0b100,
+            2 => 0b1,
+            3 => 0b10000,
+            _ => 0,
+        };
+        let step = |x: &Bits| {
+            let next = (0..8)
+                .filter(|i| x.0 >> i & 1 == 1)
+                .fold(0, |acc, i| acc | successors(i));
+            Bits(0b1 | next)
+        };
+        assert_eq!(least_fixpoint(&Bits(0), step, 10), Some(Bits(0b111)));
+    }
+
+    #[test]
+    fn bounded_loop_reaches_its_least_fixpoint() {
+        // i = 0; while i < 10 { i += 1 } gives i ∈ [0, 10]
+        let fixpoint = least_fixpoint(&IntervalLattice::bottom(), increment_below(Some(10)), 100);
+        assert_eq!(fixpoint, Some(IntervalLattice::new(0, 10)));
+        assert_eq!(
+            least_fixpoint(&IntervalLattice::bottom(), increment_below(Some(10)), 5),
+            None
+        );
+    }
+
+    #[test]
+    fn widening_terminates_an_ascending_chain() {
+        // Without a guard the chain [0, 0] ⊂ [0, 1] ⊂ ... never stabilises
+        let unbounded = increment_below(None);
+        assert_eq!(
+            least_fixpoint(&IntervalLattice::bottom(), &unbounded, 1000),
+            None
+        );
+        let widened = least_fixpoint_with_widening(
+            &IntervalLattice::bottom(),
+            &unbounded,
+            IntervalLattice::widen,
+            10,
+        );
+        assert_eq!(
+            widened,
+            Some(IntervalLattice::from_bounds(
+                Bound::Finite(0),
+                Bound::PosInfinity
+            ))
+        );
+        // Widening over-approximates the bounded loop, whose least fixpoint is [0, 10]
+        let widened = least_fixpoint_with_widening(
+            &IntervalLattice::bottom(),
+            increment_below(Some(10)),
+            IntervalLattice::widen,
+            10,
+        );
+        assert!(widened.unwrap().contains(1_000_000));
+    }
+}
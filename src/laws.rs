@@ -0,0 +1,160 @@
+use crate::{
+    AdditiveGroup, AdditiveMagma, AdditiveMonoid, AdditiveSemigroup, CommutativeAddition,
+    CommutativeMultiplication, Field, MultiplicativeMagma, MultiplicativeMonoid,
+    MultiplicativeSemigroup, OrderedRing, Ring, Semiring,
+};
+
+// The marker traits assert algebraic laws without checking them. Each function below evaluates
+// one law on concrete sample values and returns whether it held, so implementations can be
+// exercised exhaustively over small types or fed random samples by a property-testing crate.
+
+/// Returns whether (a + b) + c = a + (b + c).
+pub fn check_associative_addition<T: AdditiveSemigroup>(a: T, b: T, c: T) -> bool {
+    (a.clone() + b.clone()) + c.clone() == a + (b + c)
+}
+
+/// Returns whether a + b = b + a.
+pub fn check_commutative_addition<T: AdditiveMagma + CommutativeAddition>(a: T, b: T) -> bool {
+    a.clone() + b.clone() == b + a
+}
+
+/// Returns whether a + 0 = 0 + a = a.
+pub fn check_additive_identity<T: AdditiveMonoid>(a: T) -> bool {
+    a.clone() + T::zero() == a && T::zero() + a.clone() == a
+}
+
+/// Returns whether a + (−a) = 0 and a − a = 0.
+pub fn check_additive_inverse<T: AdditiveGroup>(a: T) -> bool {
+    (a.clone() + -a.clone()).is_zero() && (a.clone() - a).is_zero()
+}
+
+/// Returns whether (a · b) · c = a · (b · c).
+pub fn check_associative_multiplication<T: MultiplicativeSemigroup>(a: T, b: T, c: T) -> bool {
+    (a.clone() * b.clone()) * c.clone() == a * (b * c)
+}
+
+/// Returns whether a · b = b · a.
+pub fn check_commutative_multiplication<T: MultiplicativeMagma + CommutativeMultiplication>(
+    a: T,
+    b: T,
+) -> bool {
+    a.clone() * b.clone() == b * a
+}
+
+/// Returns whether a · 1 = 1 · a = a.
+pub fn check_multiplicative_identity<T: MultiplicativeMonoid>(a: T) -> bool {
+    a.clone() * T::one() == a && T::one() * a.clone() == a
+}
+
+/// Returns whether a · a⁻¹ = 1 for non-zero a; zero has no inverse, so the law holds vacuously.
+pub fn check_multiplicative_inverse<T: Field>(a: T) -> bool {
+    a.is_zero() || (a.clone() * a.inv()).is_one()
+}
+
+/// Returns whether a · (b + c) = a · b + a · c and (a + b) · c = a · c + b · c.
+pub fn check_distributive<T: Semiring>(a: T, b: T, c: T) -> bool {
+    let left = a.clone() * (b.clone() + c.clone()) == a.clone() * b.clone() + a.clone() * c.clone();
+    let right = (a.clone() + b.clone()) * c.clone() == a * c.clone() + b * c;
+    left && right
+}
+
+/// Returns whether a ≤ b implies a + c ≤ b + c.
+pub fn check_order_add_compat<T: OrderedRing>(a: T, b: T, c: T) -> bool {
+    if a <= b {
+        a + c.clone() <= b + c
+    } else {
+        true
+    }
+}
+
+/// Returns whether 0 ≤ a and 0 ≤ b imply 0 ≤ a · b.
+pub fn check_order_mul_compat<T: OrderedRing>(a: T, b: T) -> bool {
+    let zero = T::zero();
+    if zero <= a && zero <= b {
+        zero <= a * b
+    } else {
+        true
+    }
+}
+
+/// Returns whether every ring law holds on the sample values.
+///
+/// Checks associativity and commutativity of addition, the additive identity and inverses,
+/// associativity of multiplication, the multiplicative identity and distributivity.
+/// Multiplication is not required to commute.
+pub fn check_ring_laws<T: Ring>(a: T, b: T, c: T) -> bool {
+    check_associative_addition(a.clone(), b.clone(), c.clone())
+        && check_commutative_addition(a.clone(), b.clone())
+        && check_additive_identity(a.clone())
+        && check_additive_inverse(a.clone())
+        && check_associative_multiplication(a.clone(), b.clone(), c.clone())
+        && check_multiplicative_identity(a.clone())
+        && check_distributive(a, b, c)
+}
+
+/// Returns whether every field law holds on the sample values.
+///
+/// Checks the ring laws, commutativity of multiplication and multiplicative inverses.
+pub fn check_field_laws<T: Field>(a: T, b: T, c: T) -> bool {
+    check_ring_laws(a.clone(), b.clone(), c)
+        && check_commutative_multiplication(a.clone(), b)
+        && check_multiplicative_inverse(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PrimeField, Ratio};
+
+    #[test]
+    fn ring_laws_hold_over_i64() {
+        let samples = [-7i64, -1, 0, 1, 2, 13];
+        for &a in &samples {
+            for &b in &samples {
+                for &c in &samples {
+                    assert!(check_ring_laws(a, b, c), "ring laws at {a}, {b}, {c}");
+                    assert!(check_commutative_multiplication(a, b));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn field_laws_hold_over_prime_field() {
+        type F7 = PrimeField<7>;
+        for a in 0..7 {
+            for b in 0..7 {
+                for c in 0..7 {
+                    assert!(check_field_laws(F7::new(a), F7::new(b), F7::new(c)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn checks_detect_a_broken_law() {
+        // Floating-point addition is not associative
+        assert!(!check_associative_addition(0.1f64, 0.2, 0.3));
+        assert!(check_commutative_addition(0.1f64, 0.2));
+        assert!(check_multiplicative_inverse(0.0f64));
+    }
+
+    #[test]
+    fn order_compatibility_over_integers_and_ratios() {
+        let samples = [-3i64, 0, 2, 5];
+        for &a in &samples {
+            for &b in &samples {
+                assert!(check_order_mul_compat(a, b));
+                assert!(check_order_mul_compat(Ratio::new(a, 3), Ratio::new(b, 7)));
+                for &c in &samples {
+                    assert!(check_order_add_compat(a, b, c));
+                    assert!(check_order_add_compat(
+                        Ratio::new(a, 2),
+                        Ratio::new(b, 5),
+                        Ratio::new(c, 3)
+                    ));
+                }
+            }
+        }
+    }
+}
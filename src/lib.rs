@@ -3,6 +3,80 @@ use std::ops::{
     Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
 
+pub mod arithmetic;
+pub mod character;
+pub mod complex;
+pub mod covector;
+pub mod discrete_log;
+pub mod dual;
+pub mod elliptic_curve;
+pub mod euclidean;
+pub mod gaussian;
+pub mod hensel;
+pub mod interval;
+#[cfg(feature = "latex")]
+pub mod latex;
+pub mod lattice;
+pub mod laws;
+pub mod matrix;
+pub mod monomial;
+pub mod polynomial;
+pub mod prime_field;
+mod primitives;
+pub mod ratio;
+pub mod reduction;
+pub mod reed_solomon;
+pub mod shamir;
+#[cfg(any(test, feature = "validate"))]
+#[cfg_attr(not(test), allow(dead_code))]
+mod test_support;
+pub mod tropical;
+#[cfg(feature = "validate")]
+pub mod validate;
+pub mod vector;
+
+pub use arithmetic::{dot, geometric_sum, is_perfect_square, msm, pow, pow_signed};
+pub use character::{dft, dual_group, inverse_dft, Character, FiniteAbelianGroup, Zn};
+pub use complex::Complex;
+pub use covector::CoVector;
+pub use discrete_log::DlogTable;
+pub use dual::Dual;
+pub use elliptic_curve::{EcPoint, WeierstrassCurve};
+pub use euclidean::{
+    continued_fraction, coprime_basis, extended_gcd, gcd_many, lcm_many, rational_reconstruct,
+    rational_reconstruct_bounded,
+};
+pub use gaussian::GaussianInteger;
+pub use hensel::hensel_lift;
+pub use interval::Interval;
+#[cfg(feature = "latex")]
+pub use latex::LaTeX;
+pub use lattice::{least_fixpoint, least_fixpoint_with_widening, Bound, IntervalLattice};
+pub use matrix::{
+    abelian_group_structure, change_of_basis, power_iteration, Matrix, STRASSEN_THRESHOLD,
+};
+pub use monomial::{Monomial, MonomialOrder};
+pub use polynomial::{
+    evaluate_stream, evaluate_stream_low_to_high, newton_root, BarycentricInterpolator,
+    DensePolynomial, FastPolyOps, SparsePolynomial, KARATSUBA_THRESHOLD,
+};
+pub use prime_field::{is_prime, round_into, PrimeField};
+pub use ratio::{ParseRatioError, Ratio};
+pub use reduction::{
+    BarrettReduction, ModularReduction, MontgomeryReduction, NaiveReduction, ReductionStrategy,
+};
+pub use reed_solomon::ReedSolomon;
+pub use shamir::{shamir_reconstruct, shamir_split};
+pub use tropical::Tropical;
+#[cfg(feature = "validate")]
+pub use validate::{validate_laws, Arbitrary, LawViolation};
+pub use vector::{
+    closest_vector, conjugate_gradient, lll_reduce, shortest_vector, Vector, MAX_ENUMERATION_RANK,
+};
+
+#[cfg(feature = "derive")]
+pub use noether_derive::{AdditiveAbelianGroup, CommutativeRing, Field};
+
 // A note on the reasons why certain traits are used:
 //
 // The `Inv` trait is the multiplicative inverse operation.
@@ -302,6 +376,62 @@ pub trait AdditiveAbelianGroup: AdditiveGroup + CommutativeAddition {}
 /// - Commutativity: For all a and b in G, a * b = b * a
 pub trait MultiplicativeAbelianGroup: MultiplicativeGroup + CommutativeMultiplication {}
 
+/// Represents a Semiring, an algebraic structure with two binary operations where addition need not have inverses.
+///
+/// # Mathematical Definition
+/// A semiring (S, +, ·) consists of:
+/// - A set S
+/// - Two binary operations + (addition) and · (multiplication) on S
+///
+/// # Formal Definition
+/// Let (S, +, ·) be a semiring. Then:
+/// 1. (S, +) is a commutative monoid with identity 0
+/// 2. (S, ·) is a monoid with identity 1
+/// 3. Multiplication is distributive over addition:
+///    a. ∀ a, b, c ∈ S, a · (b + c) = (a · b) + (a · c) (left distributivity)
+///    b. ∀ a, b, c ∈ S, (a + b) · c = (a · c) + (b · c) (right distributivity)
+/// 4. ∀ a ∈ S, 0 · a = a · 0 = 0 (annihilation)
+///
+/// # Examples
+/// - The natural numbers (ℕ, +, ·)
+/// - The tropical semiring (ℝ ∪ {+∞}, min, +)
+pub trait Semiring:
+    AdditiveMonoid + CommutativeAddition + MultiplicativeMonoid + Distributive
+{
+}
+
+/// Represents an Ordered Semiring, a semiring with an order compatible with its operations.
+///
+/// # Mathematical Definition
+/// An ordered semiring is a semiring equipped with a partial order ≤ where:
+/// - If a ≤ b then a + c ≤ b + c for all c
+/// - If 0 ≤ a and 0 ≤ b then 0 ≤ a · b
+///
+/// # Properties
+/// - positive_part(a) = max(a, 0) and negative_part(a) = min(a, 0), taken in the semiring's
+///   own order relative to its additive identity
+/// - In the tropical semiring (ℝ ∪ {+∞}, min, +) the identity is +∞, so every element has
+///   positive part +∞ and is its own negative part
+pub trait OrderedSemiring: Semiring + PartialOrd {
+    /// Returns the larger of self and zero, or self if they are incomparable.
+    fn positive_part(self) -> Self {
+        if self < Self::zero() {
+            Self::zero()
+        } else {
+            self
+        }
+    }
+
+    /// Returns the smaller of self and zero, or self if they are incomparable.
+    fn negative_part(self) -> Self {
+        if self > Self::zero() {
+            Self::zero()
+        } else {
+            self
+        }
+    }
+}
+
 /// Represents a Ring, an algebraic structure with two binary operations (addition and multiplication) that satisfy certain axioms.
 ///
 /// # Mathematical Definition
@@ -322,7 +452,41 @@ pub trait MultiplicativeAbelianGroup: MultiplicativeGroup + CommutativeMultiplic
 /// 3. Multiplication is distributive over addition:
 ///    a. ∀ a, b, c ∈ R, a · (b + c) = (a · b) + (a · c) (left distributivity)
 ///    b. ∀ a, b, c ∈ R, (a + b) · c = (a · c) + (b · c) (right distributivity)
-pub trait Ring: AdditiveAbelianGroup + MultiplicativeMonoid + Distributive {}
+pub trait Ring: Semiring + AdditiveAbelianGroup {}
+
+/// Represents an Ordered Ring, a ring with an order compatible with its operations.
+///
+/// # Mathematical Definition
+/// An ordered ring is a ring equipped with a partial order ≤ where:
+/// - If a ≤ b then a + c ≤ b + c for all c (translation invariance)
+/// - If 0 ≤ a and 0 ≤ b then 0 ≤ a · b
+///
+/// # Properties
+/// - If a ≤ b then −b ≤ −a
+/// - Squares are non-negative when the order is total
+/// - The primitive integers and `Ratio` are ordered rings, as long as no operation overflows
+/// - Every element is positive, negative, zero or, in a partial order such as that of `f64`
+///   with NaN, incomparable with zero
+pub trait OrderedRing: Ring + OrderedSemiring {
+    /// Returns whether self is strictly greater than zero.
+    fn is_positive(&self) -> bool {
+        *self > Self::zero()
+    }
+
+    /// Returns whether self is strictly less than zero.
+    fn is_negative(&self) -> bool {
+        *self < Self::zero()
+    }
+
+    /// Returns the absolute value |self|, which is self if self ≥ 0 and −self otherwise.
+    fn abs(self) -> Self {
+        if self >= Self::zero() {
+            self
+        } else {
+            -self
+        }
+    }
+}
 
 /// Represents a Commutative Ring, an algebraic structure where multiplication is commutative.
 ///
@@ -351,6 +515,77 @@ pub trait CommutativeRing: Ring + CommutativeMultiplication {}
 ///    0 ≠ 1
 pub trait IntegralDomain: CommutativeRing {}
 
+/// Chooses a canonical representative, the normal form, in each class of associates.
+///
+/// # Mathematical Definition
+/// Elements a and b of an integral domain are associates when a = u · b for a unit u. A unit
+/// normalization assigns every non-zero a a unit u(a) such that a · u(a) depends only on the
+/// class of a; zero is its own normal form.
+///
+/// # Properties
+/// - The default unit is −(−1 div a) when that quotient is non-zero and one otherwise, which
+///   makes integers non-negative and every non-zero element of a field one
+/// - Polynomials over a field override it with the inverse of the leading coefficient, so
+///   their normal form is monic
+/// - `GcdDomain::gcd` and `BezoutDomain::bezout` return the normal form of the gcd
+pub trait CanonicalAssociate: Ring + Euclid {
+    /// Returns the unit u(self) taking self to its normal form; one for zero.
+    fn normalizing_unit(&self) -> Self {
+        if self.is_zero() {
+            return Self::one();
+        }
+        let unit = -Euclid::div_euclid(&-Self::one(), self);
+        if unit.is_zero() {
+            Self::one()
+        } else {
+            unit
+        }
+    }
+
+    /// Returns self · u(self), the normal form of self among its associates.
+    fn canonical_associate(&self) -> Self {
+        self.clone() * self.normalizing_unit()
+    }
+}
+
+/// Represents a GCD Domain, an integral domain in which any two elements have a greatest common divisor.
+///
+/// # Mathematical Definition
+/// A GCD domain (R, +, ·) is an integral domain where:
+/// - Every pair of elements has a greatest common divisor
+///
+/// # Formal Definition
+/// Let R be an integral domain. R is a GCD domain if ∀ a, b ∈ R, ∃ d ∈ R such that:
+/// 1. d | a and d | b
+/// 2. ∀ c ∈ R, c | a ∧ c | b → c | d
+///
+/// # Properties
+/// - The gcd is unique up to multiplication by a unit
+/// - Every UFD is a GCD domain, but not every GCD domain is a UFD
+pub trait GcdDomain: IntegralDomain {
+    /// Returns a greatest common divisor of self and other. A gcd is only unique up to a unit
+    /// factor, and implementations return a canonical associate: the crate's Euclidean domains
+    /// return the normal form of `CanonicalAssociate`.
+    fn gcd(&self, other: &Self) -> Self;
+}
+
+/// Represents a Bézout Domain, a GCD domain where every gcd is a linear combination of its arguments.
+///
+/// # Mathematical Definition
+/// A Bézout domain (R, +, ·) is an integral domain where:
+/// - Every finitely generated ideal is principal
+///
+/// # Formal Definition
+/// Let R be a GCD domain. R is a Bézout domain if ∀ a, b ∈ R, ∃ x, y ∈ R such that
+/// a · x + b · y = gcd(a, b) (Bézout's identity)
+///
+/// # Properties
+/// - Every PID is a Bézout domain
+pub trait BezoutDomain: GcdDomain {
+    /// Returns (g, x, y) such that self · x + other · y = g, where g is a gcd of self and other.
+    fn bezout(&self, other: &Self) -> (Self, Self, Self);
+}
+
 /// Represents a Unique Factorization Domain (UFD), an integral domain where every non-zero
 /// non-unit element has a unique factorization into irreducible elements.
 ///
@@ -366,7 +601,7 @@ pub trait IntegralDomain: CommutativeRing {}
 /// 2. If a = p₁ · ... · pₙ = q₁ · ... · qₘ are two factorizations of a into irreducible elements,
 ///    then n = m and there exists a bijection σ: {1, ..., n} → {1, ..., n} such that pᵢ is
 ///    associated to qₛᵢ for all i.
-pub trait UniqueFactorizationDomain: IntegralDomain {}
+pub trait UniqueFactorizationDomain: GcdDomain {}
 
 /// Represents a Principal Ideal Domain (PID), an integral domain where every ideal is principal.
 ///
@@ -377,7 +612,7 @@ pub trait UniqueFactorizationDomain: IntegralDomain {}
 /// # Formal Definition
 /// Let R be an integral domain. R is a PID if for every ideal I ⊆ R, there exists an element a ∈ R
 /// such that I = (a) = {ra | r ∈ R}.
-pub trait PrincipalIdealDomain: UniqueFactorizationDomain {}
+pub trait PrincipalIdealDomain: UniqueFactorizationDomain + BezoutDomain {}
 
 /// Represents a Euclidean Domain, an integral domain with a Euclidean function.
 ///
@@ -416,6 +651,115 @@ pub trait FiniteField: Field {
 
     /// Returns the number of elements in the field.
     fn order() -> u64;
+
+    /// Returns a primitive element, a generator of the cyclic multiplicative group F*.
+    ///
+    /// Every non-zero element is a power of it, which is what `embed_into` builds on.
+    fn primitive_element() -> Self;
+
+    /// Returns whether this field is isomorphic to a subfield of G.
+    ///
+    /// 𝔽_{p^m} embeds in 𝔽_{q^n} iff p = q and m divides n.
+    fn is_subfield_of<G: FiniteField>() -> bool {
+        let p = Self::characteristic();
+        if p != G::characteristic() {
+            return false;
+        }
+        let degree = |mut order: u64| {
+            let mut n = 0;
+            while order > 1 {
+                order /= p;
+                n += 1;
+            }
+            n
+        };
+        degree(G::order()) % degree(Self::order()) == 0
+    }
+
+    /// Returns the image of self under a field embedding of F into G, or `None` if F is not
+    /// isomorphic to a subfield of G.
+    ///
+    /// The embedding is fixed by the image of the primitive element g: a root c in G of the
+    /// minimal polynomial ∏ᵢ (x − g^(pⁱ)) of g over 𝔽ₚ, found among the powers of
+    /// h^((|G| − 1) / (|F| − 1)) for the primitive element h of G. A non-zero a = gᵏ then maps to
+    /// cᵏ. Every call returns the image under the same embedding, so the map preserves + and ·;
+    /// when F has several embeddings into G they differ by a Frobenius power.
+    ///
+    /// # Complexity
+    /// O(|F|) field operations per call, for the discrete logarithm of self and the search for
+    /// c, so this is meant for small fields.
+    fn embed_into<G: FiniteField>(&self) -> Option<G> {
+        if !Self::is_subfield_of::<G>() {
+            return None;
+        }
+        if self.is_zero() {
+            return Some(G::zero());
+        }
+        let generator = Self::primitive_element();
+        let (mut power, mut log) = (Self::one(), 0);
+        while power != *self {
+            power *= generator.clone();
+            log += 1;
+        }
+        Some(arithmetic::pow(generator_image::<Self, G>(), log))
+    }
+
+    /// Returns whether self is a square in the field.
+    ///
+    /// Uses Euler's criterion: in odd characteristic a non-zero a is a square iff
+    /// a^((q − 1) / 2) = 1. In characteristic 2 the Frobenius map a ↦ a² is a bijection, so
+    /// every element is a square.
+    fn is_square(&self) -> bool {
+        if self.is_zero() || Self::characteristic() == 2 {
+            return true;
+        }
+        arithmetic::pow(self.clone(), (Self::order() - 1) / 2).is_one()
+    }
+}
+
+/// Returns a root in G of the minimal polynomial over 𝔽ₚ of the primitive element of F, the
+/// image of that element under an embedding of F into G, which must exist.
+fn generator_image<F: FiniteField, G: FiniteField>() -> G {
+    // The conjugates g^(pⁱ) are distinct until they return to g, and their product is the
+    // minimal polynomial, with coefficients from the prime field, low to high.
+    let generator = F::primitive_element();
+    let mut minimal_polynomial = vec![F::one()];
+    let mut conjugate = generator.clone();
+    loop {
+        minimal_polynomial.insert(0, F::zero());
+        for i in 0..minimal_polynomial.len() - 1 {
+            let shifted = minimal_polynomial[i + 1].clone() * conjugate.clone();
+            minimal_polynomial[i] -= shifted;
+        }
+        conjugate = arithmetic::pow(conjugate, F::characteristic());
+        if conjugate == generator {
+            break;
+        }
+    }
+    // A prime field coefficient is k · 1 for some k < p, and maps to k · 1 in G.
+    let coefficients: Vec<G> = minimal_polynomial
+        .iter()
+        .map(|a| {
+            let (mut k, mut image) = (F::zero(), G::zero());
+            while k != *a {
+                k += F::one();
+                image += G::one();
+            }
+            image
+        })
+        .collect();
+    // The roots have order |F| − 1, so they are powers of h^((|G| − 1) / (|F| − 1)).
+    let base = arithmetic::pow(G::primitive_element(), (G::order() - 1) / (F::order() - 1));
+    let mut candidate = base.clone();
+    for _ in 0..F::order() - 1 {
+        let value =
+            polynomial::evaluate_stream(coefficients.iter().rev().cloned(), candidate.clone());
+        if value.is_zero() {
+            return candidate;
+        }
+        candidate *= base.clone();
+    }
+    unreachable!("the minimal polynomial splits in the copy of F inside G")
 }
 
 /// Represents an Ordered Field, a field with a total order compatible with its operations.
@@ -424,14 +768,70 @@ pub trait FiniteField: Field {
 /// An ordered field is a field equipped with a total order ≤ where:
 /// - If a ≤ b then a + c ≤ b + c for all c
 /// - If 0 ≤ a and 0 ≤ b then 0 ≤ a · b
-pub trait OrderedField: Field + PartialOrd {}
+pub trait OrderedField: Field + OrderedRing {}
+
+/// Represents an ordered field with the absolute value and sign induced by its order.
+///
+/// # Mathematical Definition
+/// In an ordered field, |a| = a if a ≥ 0 and −a otherwise, and sgn(a) ∈ {−1, 0, 1}.
+///
+/// # Properties
+/// - |a| ≥ 0, with |a| = 0 iff a = 0
+/// - |−a| = |a| and |a · b| = |a| · |b|
+/// - |a + b| ≤ |a| + |b| (triangle inequality)
+/// - a = sgn(a) · |a|
+/// - Only the order is needed, not completeness, so this covers ℚ as well as the reals
+/// - The absolute value itself is `OrderedRing::abs`, which needs no division
+pub trait AbsoluteValue: OrderedField {
+    /// Returns −1, 0 or 1 according to the sign of self; incomparable values such as NaN give 0.
+    fn signum(self) -> Self {
+        if self > Self::zero() {
+            Self::one()
+        } else if self < Self::zero() {
+            -Self::one()
+        } else {
+            Self::zero()
+        }
+    }
+}
 
 /// Represents a Real Field, a complete ordered field.
 ///
 /// # Mathematical Definition
 /// A real field is an ordered field that is Dedekind-complete:
 /// - Every non-empty subset with an upper bound has a least upper bound
-pub trait RealField: OrderedField {}
+pub trait RealField: OrderedField {
+    /// Returns the distance |self − other| between two elements.
+    fn abs_diff(self, other: Self) -> Self {
+        OrderedRing::abs(self - other)
+    }
+
+    /// Returns whether |self − other| ≤ tol, the boundary being inclusive.
+    fn is_within(&self, other: &Self, tol: &Self) -> bool {
+        self.clone().abs_diff(other.clone()) <= *tol
+    }
+
+    /// Returns (lo, hi) with lo ≤ self + other ≤ hi for the exact sum.
+    ///
+    /// The default returns the computed sum twice, which is right for exact arithmetic.
+    /// Rounding types override it to widen outward, as interval arithmetic relies on it.
+    fn add_bounds(&self, other: &Self) -> (Self, Self) {
+        let sum = self.clone() + other.clone();
+        (sum.clone(), sum)
+    }
+
+    /// Returns (lo, hi) with lo ≤ self · other ≤ hi for the exact product.
+    fn mul_bounds(&self, other: &Self) -> (Self, Self) {
+        let product = self.clone() * other.clone();
+        (product.clone(), product)
+    }
+
+    /// Returns (lo, hi) with lo ≤ self / other ≤ hi for the exact quotient.
+    fn div_bounds(&self, other: &Self) -> (Self, Self) {
+        let quotient = self.clone() / other.clone();
+        (quotient.clone(), quotient)
+    }
+}
 
 /// Represents a Polynomial over a field.
 ///
@@ -470,10 +870,44 @@ pub trait VectorSpace: AdditiveAbelianGroup {
     /// Performs scalar multiplication.
     fn scale(&self, scalar: &Self::Scalar) -> Self;
 
+    /// Divides by a scalar, returning `None` if the scalar is zero.
+    fn try_scalar_div(&self, scalar: &Self::Scalar) -> Option<Self> {
+        if scalar.is_zero() {
+            None
+        } else {
+            Some(self.scale(&scalar.clone().inv()))
+        }
+    }
+
+    /// Divides by a scalar, computed as scaling by its inverse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scalar is zero; use `try_scalar_div` to handle that case.
+    fn scalar_div(&self, scalar: &Self::Scalar) -> Self {
+        self.try_scalar_div(scalar)
+            .expect("Division of a vector by a zero scalar")
+    }
+
     /// Returns the dimension of the vector space, if it's finite-dimensional.
     fn dimension(&self) -> Option<usize>;
 }
 
+/// Represents an Inner Product Space, a vector space with a bilinear pairing into its scalars.
+///
+/// # Mathematical Definition
+/// An inner product space is a vector space V over F with a map ⟨·, ·⟩: V × V → F.
+///
+/// # Properties
+/// - Symmetry: ⟨u, v⟩ = ⟨v, u⟩
+/// - Bilinearity: ⟨au + bv, w⟩ = a⟨u, w⟩ + b⟨v, w⟩
+/// - Over an ordered field, positive definiteness: ⟨v, v⟩ > 0 for v ≠ 0. Over other fields
+///   such as 𝔽ₚ the standard pairing is only a symmetric bilinear form
+pub trait InnerProductSpace: VectorSpace {
+    /// Returns the inner product of self and other.
+    fn inner_product(&self, other: &Self) -> Self::Scalar;
+}
+
 /// Represents a Field Extension.
 ///
 /// # Mathematical Definition
@@ -516,30 +950,31 @@ pub trait FieldExtensionTower: FieldExtension {
 }
 
 // Blanket implementations for basic operation traits
-impl<T: Add<Output = T>> ClosedAdd for T {}
-impl<T: for<'a> Add<&'a T, Output = T>> ClosedAddRef for T {}
-impl<T: Sub<Output = T>> ClosedSub for T {}
-impl<T: for<'a> Sub<&'a T, Output = T>> ClosedSubRef for T {}
-impl<T: Mul<Output = T>> ClosedMul for T {}
-impl<T: for<'a> Mul<&'a T, Output = T>> ClosedMulRef for T {}
-impl<T: Div<Output = T>> ClosedDiv for T {}
-impl<T: for<'a> Div<&'a T, Output = T>> ClosedDivRef for T {}
-impl<T: Rem<Output = T>> ClosedRem for T {}
-impl<T: for<'a> Rem<&'a T, Output = T>> ClosedRemRef for T {}
+// Note: These hold for every right-hand side, so e.g. `T: Add<u64, Output = T>` gives `ClosedAdd<u64>`
+impl<T: Add<Rhs, Output = T>, Rhs> ClosedAdd<Rhs> for T {}
+impl<T: for<'a> Add<&'a Rhs, Output = T>, Rhs> ClosedAddRef<Rhs> for T {}
+impl<T: Sub<Rhs, Output = T>, Rhs> ClosedSub<Rhs> for T {}
+impl<T: for<'a> Sub<&'a Rhs, Output = T>, Rhs> ClosedSubRef<Rhs> for T {}
+impl<T: Mul<Rhs, Output = T>, Rhs> ClosedMul<Rhs> for T {}
+impl<T: for<'a> Mul<&'a Rhs, Output = T>, Rhs> ClosedMulRef<Rhs> for T {}
+impl<T: Div<Rhs, Output = T>, Rhs> ClosedDiv<Rhs> for T {}
+impl<T: for<'a> Div<&'a Rhs, Output = T>, Rhs> ClosedDivRef<Rhs> for T {}
+impl<T: Rem<Rhs, Output = T>, Rhs> ClosedRem<Rhs> for T {}
+impl<T: for<'a> Rem<&'a Rhs, Output = T>, Rhs> ClosedRemRef<Rhs> for T {}
 impl<T: Neg<Output = T>> ClosedNeg for T {}
 impl<T: Inv<Output = T>> ClosedInv for T {}
 
 // Blanket implementations for assignment operation traits
-impl<T: AddAssign> ClosedAddAssign for T {}
-impl<T: for<'a> AddAssign<&'a T>> ClosedAddAssignRef for T {}
-impl<T: SubAssign> ClosedSubAssign for T {}
-impl<T: for<'a> SubAssign<&'a T>> ClosedSubAssignRef for T {}
-impl<T: MulAssign> ClosedMulAssign for T {}
-impl<T: for<'a> MulAssign<&'a T>> ClosedMulAssignRef for T {}
-impl<T: DivAssign> ClosedDivAssign for T {}
-impl<T: for<'a> DivAssign<&'a T>> ClosedDivAssignRef for T {}
-impl<T: RemAssign> ClosedRemAssign for T {}
-impl<T: for<'a> RemAssign<&'a T>> ClosedRemAssignRef for T {}
+impl<T: AddAssign<Rhs>, Rhs> ClosedAddAssign<Rhs> for T {}
+impl<T: for<'a> AddAssign<&'a Rhs>, Rhs> ClosedAddAssignRef<Rhs> for T {}
+impl<T: SubAssign<Rhs>, Rhs> ClosedSubAssign<Rhs> for T {}
+impl<T: for<'a> SubAssign<&'a Rhs>, Rhs> ClosedSubAssignRef<Rhs> for T {}
+impl<T: MulAssign<Rhs>, Rhs> ClosedMulAssign<Rhs> for T {}
+impl<T: for<'a> MulAssign<&'a Rhs>, Rhs> ClosedMulAssignRef<Rhs> for T {}
+impl<T: DivAssign<Rhs>, Rhs> ClosedDivAssign<Rhs> for T {}
+impl<T: for<'a> DivAssign<&'a Rhs>, Rhs> ClosedDivAssignRef<Rhs> for T {}
+impl<T: RemAssign<Rhs>, Rhs> ClosedRemAssign<Rhs> for T {}
+impl<T: for<'a> RemAssign<&'a Rhs>, Rhs> ClosedRemAssignRef<Rhs> for T {}
 
 // Blanket implementations for zero and one
 impl<T: Zero> ClosedZero for T {}
@@ -578,8 +1013,17 @@ impl<T: AdditiveGroup + CommutativeAddition> AdditiveAbelianGroup for T {}
 // MultiplicativeAbelianGroup
 impl<T: MultiplicativeGroup + CommutativeMultiplication> MultiplicativeAbelianGroup for T {}
 
+// Semiring
+impl<T: AdditiveMonoid + CommutativeAddition + MultiplicativeMonoid + Distributive> Semiring for T {}
+
+// OrderedSemiring
+impl<T: Semiring + PartialOrd> OrderedSemiring for T {}
+
 // Ring
-impl<T: AdditiveAbelianGroup + MultiplicativeMonoid + Distributive> Ring for T {}
+impl<T: Semiring + AdditiveAbelianGroup> Ring for T {}
+
+// OrderedRing
+impl<T: Ring + PartialOrd> OrderedRing for T {}
 
 // CommutativeRing
 impl<T: Ring + CommutativeMultiplication> CommutativeRing for T {}
@@ -589,11 +1033,40 @@ impl<T: Ring + CommutativeMultiplication> CommutativeRing for T {}
 
 impl<T: CommutativeRing> IntegralDomain for T {}
 
+// GcdDomain
+// Note: Implemented for integral domains with Euclidean division via the Euclidean algorithm,
+// normalized to the canonical associate
+impl<T: IntegralDomain + CanonicalAssociate> GcdDomain for T {
+    fn gcd(&self, other: &Self) -> Self {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let r = Euclid::rem_euclid(&a, &b);
+            a = b;
+            b = r;
+        }
+        a.canonical_associate()
+    }
+}
+
+// BezoutDomain
+// Note: Implemented for GCD domains with Euclidean division via the extended Euclidean algorithm,
+// with the gcd and both coefficients scaled by the normalizing unit
+impl<T: GcdDomain + CanonicalAssociate> BezoutDomain for T {
+    fn bezout(&self, other: &Self) -> (Self, Self, Self) {
+        let (g, x, y) = euclidean::extended_gcd(self.clone(), other.clone());
+        let unit = g.normalizing_unit();
+        (g * unit.clone(), x * unit.clone(), y * unit)
+    }
+}
+
 // UniqueFactorizationDomain
-impl<T: IntegralDomain> UniqueFactorizationDomain for T {}
+// Note: Restricted to Euclidean types, since a GCD domain need not have unique factorization
+impl<T: GcdDomain + Euclid> UniqueFactorizationDomain for T {}
 
 // PrincipalIdealDomain
-impl<T: UniqueFactorizationDomain> PrincipalIdealDomain for T {}
+// Note: Restricted to Euclidean types, since a Bézout domain need not be Noetherian
+impl<T: UniqueFactorizationDomain + BezoutDomain + Euclid> PrincipalIdealDomain for T {}
 
 // EuclideanDomain
 impl<T: PrincipalIdealDomain + Euclid> EuclideanDomain for T {}
@@ -605,26 +1078,32 @@ impl<T: EuclideanDomain + MultiplicativeAbelianGroup> Field for T {}
 // Note: This cannot be implemented as a blanket impl because it requires specific knowledge about the field's finiteness
 
 // OrderedField
-impl<T: Field + PartialOrd> OrderedField for T {}
+impl<T: Field + OrderedRing> OrderedField for T {}
+
+// AbsoluteValue
+impl<T: OrderedField> AbsoluteValue for T {}
 
 // properties of Join in a semilattice
-/// Ensures that the join operation satisfies associativity:
-/// - For all a, b, c in the semilattice:
-///   (a ⋁ b) ⋁ c == a ⋁ (b ⋁ c)
-/// This property guarantees that grouping does not affect the result of joins.
-pub trait IdempotentJoin {}
-/// Ensures that the join operation satisfies commutativity:
-/// - For all a, b in the semilattice:
-///   a ⋁ b == b ⋁ a
-/// This property allows the order of operands to be swapped without affecting the result.
 
 /// Ensures that the join operation satisfies idempotency:
 /// - For all a in the semilattice:
 ///   a ⋁ a == a
+///
 /// This property means that joining an element with itself does not change the element.
+pub trait IdempotentJoin {}
 
+/// Ensures that the join operation satisfies associativity:
+/// - For all a, b, c in the semilattice:
+///   (a ⋁ b) ⋁ c == a ⋁ (b ⋁ c)
+///
+/// This property guarantees that grouping does not affect the result of joins.
 pub trait AssociativeJoin {}
 
+/// Ensures that the join operation satisfies commutativity:
+/// - For all a, b in the semilattice:
+///   a ⋁ b == b ⋁ a
+///
+/// This property allows the order of operands to be swapped without affecting the result.
 pub trait CommutativeJoin {}
 
 pub trait Join: AssociativeJoin + CommutativeJoin + IdempotentJoin {
@@ -650,3 +1129,477 @@ pub trait JoinSemiLattice: Join {}
 
 // FieldExtensionTower
 // Note: This cannot be implemented as a blanket impl because it requires specific knowledge about the tower structure
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::DensePolynomial;
+    use crate::prime_field::PrimeField;
+    use crate::ratio::Ratio;
+
+    type F7 = PrimeField<7>;
+
+    fn poly(coefficients: &[u64]) -> DensePolynomial<F7> {
+        DensePolynomial::new(coefficients.iter().map(|&c| F7::new(c)).collect())
+    }
+
+    fn gcd_of<T: GcdDomain>(a: &T, b: &T) -> T {
+        a.gcd(b)
+    }
+
+    #[test]
+    fn gcd_domain_accepts_integers_and_polynomials() {
+        assert_eq!(gcd_of(&12i64, &18i64), 6);
+        // (x + 1)(x + 2) and (x + 1)(x + 3) share the factor x + 1
+        let a = poly(&[2, 3, 1]);
+        let b = poly(&[3, 4, 1]);
+        let g = gcd_of(&a, &b);
+        assert_eq!(g.degree(), 1);
+        assert!(Euclid::rem_euclid(&a, &g).is_zero());
+        assert!(Euclid::rem_euclid(&b, &g).is_zero());
+    }
+
+    #[test]
+    fn gcd_is_the_canonical_associate() {
+        for (a, b, g) in [
+            (-4i64, 6i64, 2),
+            (4, -6, 2),
+            (-4, -6, 2),
+            (0, -6, 6),
+            (-6, 0, 6),
+            (0, 0, 0),
+            (-7, -7, 7),
+        ] {
+            assert_eq!(gcd_of(&a, &b), g, "gcd({a}, {b})");
+        }
+        // Over F₇[x] the gcd is monic, however the inputs are scaled: (x + 1)(x + 2) and
+        // (x + 1)(x + 3) have gcd x + 1
+        let a = poly(&[2, 3, 1]);
+        let b = poly(&[3, 4, 1]);
+        assert_eq!(gcd_of(&a, &b), poly(&[1, 1]));
+        let scaled = gcd_of(&(a.clone() * poly(&[3])), &(b.clone() * poly(&[5])));
+        assert_eq!(scaled, poly(&[1, 1]));
+        assert_eq!(gcd_of(&poly(&[4, 4]), &poly(&[0])), poly(&[1, 1]));
+        assert_eq!(gcd_of(&poly(&[3]), &poly(&[0, 5])), poly(&[1]));
+        assert_eq!(gcd_of(&F7::new(3), &F7::new(0)), F7::new(1));
+    }
+
+    #[test]
+    fn bezout_coefficients_follow_the_canonical_gcd() {
+        for (a, b) in [(-4i64, 6i64), (0, -6), (240, -46), (-17, -5)] {
+            let (g, x, y) = a.bezout(&b);
+            assert_eq!(g, gcd_of(&a, &b));
+            assert_eq!(a * x + b * y, g);
+        }
+        let (a, b) = (poly(&[6, 3, 5]), poly(&[3, 4, 1]));
+        let (g, x, y) = a.bezout(&b);
+        assert_eq!(g, gcd_of(&a, &b));
+        assert_eq!(a * x + b * y, g);
+    }
+
+    #[test]
+    fn absolute_value_is_symmetric_and_subadditive() {
+        let samples: Vec<Ratio<i64>> = [(-7, 3), (-1, 2), (0, 1), (2, 5), (9, 4)]
+            .iter()
+            .map(|&(n, d)| Ratio::new(n, d))
+            .collect();
+        for a in &samples {
+            assert_eq!(a.clone().abs(), (-a.clone()).abs());
+            assert!(a.clone().abs() >= Ratio::zero());
+            assert_eq!(a.clone().signum() * a.clone().abs(), a.clone());
+            for b in &samples {
+                assert!((a.clone() + b.clone()).abs() <= a.clone().abs() + b.clone().abs());
+            }
+        }
+    }
+
+    #[test]
+    fn signs_of_ratios() {
+        assert!(Ratio::new(-1i64, 2).is_negative());
+        assert!(!Ratio::new(-1i64, 2).is_positive());
+        assert!(Ratio::new(3i64, -4).is_negative());
+        assert!(Ratio::new(-3i64, -4).is_positive());
+        assert!(!Ratio::<i64>::zero().is_positive() && !Ratio::<i64>::zero().is_negative());
+        assert_eq!(Ratio::new(-5i64, 3).abs(), Ratio::new(5, 3));
+        assert!(Ratio::new(-5i64, 3).abs().is_positive());
+        assert_eq!(Ratio::new(5i64, 3).abs(), Ratio::new(5, 3));
+    }
+
+    #[test]
+    fn signum_is_minus_one_zero_or_one() {
+        assert_eq!(Ratio::new(-3i64, 4).signum(), -Ratio::one());
+        assert_eq!(Ratio::<i64>::zero().signum(), Ratio::zero());
+        assert_eq!(AbsoluteValue::signum(2.5f64), 1.0);
+        assert_eq!(OrderedRing::abs(-2.5f32), 2.5);
+        assert_eq!(AbsoluteValue::signum(f64::NAN), 0.0);
+    }
+
+    #[derive(Clone, Copy)]
+    struct Meters(u32);
+
+    impl Add for Meters {
+        type Output = Self;
+
+        fn add(self, other: Self) -> Self {
+            Meters(self.0 + other.0)
+        }
+    }
+
+    impl Add<u32> for Meters {
+        type Output = Self;
+
+        fn add(self, other: u32) -> Self {
+            Meters(self.0 + other)
+        }
+    }
+
+    impl<'a> AddAssign<&'a Meters> for Meters {
+        fn add_assign(&mut self, other: &'a Meters) {
+            self.0 += other.0;
+        }
+    }
+
+    fn closed_add<T: ClosedAdd>(a: T, b: T) -> T {
+        a + b
+    }
+
+    fn closed_add_with<T: ClosedAdd<Rhs>, Rhs>(a: T, b: Rhs) -> T {
+        a + b
+    }
+
+    fn closed_add_assign_ref<T: ClosedAddAssignRef>(a: &mut T, b: &T) {
+        *a += b;
+    }
+
+    #[test]
+    fn closed_traits_follow_from_the_operators() {
+        assert_eq!(closed_add(Meters(2), Meters(3)).0, 5);
+        assert_eq!(closed_add_with(Meters(2), 7u32).0, 9);
+        let mut total = Meters(1);
+        closed_add_assign_ref(&mut total, &Meters(4));
+        assert_eq!(total.0, 5);
+    }
+
+    #[test]
+    fn abs_diff_is_symmetric() {
+        assert_eq!(RealField::abs_diff(3.0f64, 5.0), 2.0);
+        assert_eq!(RealField::abs_diff(5.0f64, 3.0), 2.0);
+        assert_eq!(RealField::abs_diff(-1.5f64, -1.5), 0.0);
+        assert_eq!(RealField::abs_diff(-2.0f32, 1.0), 3.0);
+    }
+
+    #[test]
+    fn is_within_includes_the_boundary() {
+        assert!(1.0f64.is_within(&1.5, &0.5));
+        assert!(1.5f64.is_within(&1.0, &0.5));
+        assert!(!1.0f64.is_within(&1.5, &0.4999));
+        assert!(2.0f64.is_within(&2.0, &0.0));
+        assert!(!f64::NAN.is_within(&0.0, &1.0));
+    }
+
+    #[test]
+    fn bezout_identity_over_integers() {
+        for (a, b) in [(240i64, 46i64), (-15, 35), (17, 0), (0, -9), (0, 0), (1, 1)] {
+            let (g, x, y) = a.bezout(&b);
+            assert_eq!(a * x + b * y, g, "bezout({a}, {b})");
+            assert_eq!(g.abs(), gcd_of(&a, &b).abs());
+        }
+    }
+
+    #[test]
+    fn bezout_identity_over_polynomials() {
+        type F5 = PrimeField<5>;
+        let poly5 = |coefficients: &[u64]| {
+            DensePolynomial::new(coefficients.iter().map(|&c| F5::new(c)).collect::<Vec<_>>())
+        };
+        let a = poly5(&[1, 0, 2, 1]);
+        let b = poly5(&[3, 1, 1]);
+        let zero = DensePolynomial::zero();
+        for (a, b) in [(&a, &b), (&b, &a), (&a, &zero), (&zero, &b)] {
+            let (g, x, y) = a.bezout(b);
+            assert_eq!(a.clone() * x + b.clone() * y, g);
+            assert!(Euclid::rem_euclid(a, &g).is_zero());
+            assert!(Euclid::rem_euclid(b, &g).is_zero());
+        }
+    }
+
+    /// The field 𝔽₂[x]/(m) for an irreducible m of degree N, with elements stored as the bits of
+    /// their coefficients. Just enough to exercise `FiniteField` beyond the prime fields.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Gf2<const N: u32, const M: u16>(u16);
+
+    type Gf4 = Gf2<2, 0b111>;
+    type Gf8 = Gf2<3, 0b1011>;
+    type Gf16 = Gf2<4, 0b10011>;
+
+    impl<const N: u32, const M: u16> Gf2<N, M> {
+        fn elements() -> impl Iterator<Item = Self> {
+            (0..1 << N).map(Gf2)
+        }
+    }
+
+    impl<const N: u32, const M: u16> Add for Gf2<N, M> {
+        type Output = Self;
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn add(self, other: Self) -> Self {
+            Gf2(self.0 ^ other.0)
+        }
+    }
+
+    impl<const N: u32, const M: u16> AddAssign for Gf2<N, M> {
+        fn add_assign(&mut self, other: Self) {
+            *self = *self + other;
+        }
+    }
+
+    impl<const N: u32, const M: u16> Neg for Gf2<N, M> {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            self
+        }
+    }
+
+    impl<const N: u32, const M: u16> Sub for Gf2<N, M> {
+        type Output = Self;
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn sub(self, other: Self) -> Self {
+            self + other
+        }
+    }
+
+    impl<const N: u32, const M: u16> SubAssign for Gf2<N, M> {
+        fn sub_assign(&mut self, other: Self) {
+            *self = *self - other;
+        }
+    }
+
+    impl<const N: u32, const M: u16> Mul for Gf2<N, M> {
+        type Output = Self;
+
+        fn mul(self, other: Self) -> Self {
+            // Carry-less shift-and-add, reducing by m whenever the degree reaches N
+            let (mut a, mut product) = (self.0, 0);
+            for i in 0..N {
+                if other.0 >> i & 1 == 1 {
+                    product ^= a;
+                }
+                a <<= 1;
+                if a >> N & 1 == 1 {
+                    a ^= M;
+                }
+            }
+            Gf2(product)
+        }
+    }
+
+    impl<const N: u32, const M: u16> MulAssign for Gf2<N, M> {
+        fn mul_assign(&mut self, other: Self) {
+            *self = *self * other;
+        }
+    }
+
+    impl<const N: u32, const M: u16> Inv for Gf2<N, M> {
+        type Output = Self;
+
+        fn inv(self) -> Self {
+            assert!(self.0 != 0, "Inverse of zero");
+            // a^(2ᴺ − 2) = a⁻¹
+            arithmetic::pow(self, (1 << N) - 2)
+        }
+    }
+
+    impl<const N: u32, const M: u16> Div for Gf2<N, M> {
+        type Output = Self;
+
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn div(self, other: Self) -> Self {
+            self * other.inv()
+        }
+    }
+
+    impl<const N: u32, const M: u16> DivAssign for Gf2<N, M> {
+        fn div_assign(&mut self, other: Self) {
+            *self = *self / other;
+        }
+    }
+
+    impl<const N: u32, const M: u16> Rem for Gf2<N, M> {
+        type Output = Self;
+
+        fn rem(self, _: Self) -> Self {
+            Gf2(0)
+        }
+    }
+
+    impl<const N: u32, const M: u16> Euclid for Gf2<N, M> {
+        fn div_euclid(&self, v: &Self) -> Self {
+            *self / *v
+        }
+
+        fn rem_euclid(&self, v: &Self) -> Self {
+            *self % *v
+        }
+    }
+
+    impl<const N: u32, const M: u16> Zero for Gf2<N, M> {
+        fn zero() -> Self {
+            Gf2(0)
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl<const N: u32, const M: u16> One for Gf2<N, M> {
+        fn one() -> Self {
+            Gf2(1)
+        }
+    }
+
+    impl<const N: u32, const M: u16> CommutativeAddition for Gf2<N, M> {}
+    impl<const N: u32, const M: u16> AssociativeAddition for Gf2<N, M> {}
+    impl<const N: u32, const M: u16> CommutativeMultiplication for Gf2<N, M> {}
+    impl<const N: u32, const M: u16> AssociativeMultiplication for Gf2<N, M> {}
+    impl<const N: u32, const M: u16> Distributive for Gf2<N, M> {}
+    impl<const N: u32, const M: u16> CanonicalAssociate for Gf2<N, M> {}
+
+    impl<const N: u32, const M: u16> FiniteField for Gf2<N, M> {
+        fn characteristic() -> u64 {
+            2
+        }
+
+        fn order() -> u64 {
+            1 << N
+        }
+
+        fn primitive_element() -> Self {
+            Self::elements()
+                .skip(1)
+                .find(|&a| (1..(1 << N) - 1).all(|k| !arithmetic::pow(a, k).is_one()))
+                .expect("the multiplicative group is cyclic")
+        }
+    }
+
+    #[test]
+    fn binary_extension_fields_satisfy_the_field_laws() {
+        for a in Gf16::elements() {
+            for b in Gf16::elements() {
+                for c in Gf16::elements().skip(1).step_by(4) {
+                    assert!(laws::check_field_laws(c, a, b));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn subfields_by_degree_divisibility() {
+        assert!(Gf4::is_subfield_of::<Gf16>());
+        assert!(!Gf4::is_subfield_of::<Gf8>());
+        assert!(!Gf8::is_subfield_of::<Gf16>());
+        assert!(Gf16::is_subfield_of::<Gf16>());
+        assert!(!Gf16::is_subfield_of::<Gf4>());
+        assert!(PrimeField::<2>::is_subfield_of::<Gf8>());
+        assert!(!PrimeField::<3>::is_subfield_of::<Gf8>());
+        assert!(F7::is_subfield_of::<F7>());
+        assert!(!F7::is_subfield_of::<PrimeField<5>>());
+    }
+
+    #[test]
+    fn subfields_are_the_fixed_points_of_frobenius() {
+        // 𝔽_{2^m} sits inside G as the roots of a^(2^m) = a, which number 2^gcd(m, n)
+        let fixed = |order: u64| {
+            Gf16::elements()
+                .filter(|&a| arithmetic::pow(a, order) == a)
+                .count()
+        };
+        assert_eq!(fixed(Gf4::order()), 4);
+        assert_eq!(
+            Gf8::elements()
+                .filter(|&a| arithmetic::pow(a, 4) == a)
+                .count(),
+            2
+        );
+        // x ↦ y⁵ maps 𝔽₂[x]/(x² + x + 1) onto those fixed points, as y⁵ has order 3
+        let omega = arithmetic::pow(Gf16::elements().nth(2).unwrap(), 5);
+        let embed = |a: Gf4| {
+            let bit = |i: u32| {
+                if a.0 >> i & 1 == 1 {
+                    Gf16::one()
+                } else {
+                    Gf16::zero()
+                }
+            };
+            bit(0) + bit(1) * omega
+        };
+        for a in Gf4::elements() {
+            assert_eq!(arithmetic::pow(embed(a), 4), embed(a));
+            for b in Gf4::elements() {
+                assert_eq!(embed(a + b), embed(a) + embed(b));
+                assert_eq!(embed(a * b), embed(a) * embed(b));
+            }
+        }
+    }
+
+    #[test]
+    fn embeddings_preserve_the_field_operations() {
+        fn check<F: FiniteField + Copy, G: FiniteField + std::fmt::Debug>(elements: &[F]) {
+            let embed = |a: F| a.embed_into::<G>().unwrap();
+            assert_eq!(embed(F::zero()), G::zero());
+            assert_eq!(embed(F::one()), G::one());
+            for (i, &a) in elements.iter().enumerate() {
+                for &b in &elements[..i] {
+                    assert_ne!(embed(a), embed(b));
+                }
+                for &b in elements {
+                    assert_eq!(embed(a + b), embed(a) + embed(b));
+                    assert_eq!(embed(a * b), embed(a) * embed(b));
+                }
+            }
+        }
+        let gf4: Vec<Gf4> = Gf4::elements().collect();
+        let gf8: Vec<Gf8> = Gf8::elements().collect();
+        let gf16: Vec<Gf16> = Gf16::elements().collect();
+        check::<Gf4, Gf16>(&gf4);
+        check::<Gf8, Gf8>(&gf8);
+        check::<Gf16, Gf16>(&gf16);
+        check::<PrimeField<2>, Gf8>(&[PrimeField::new(0), PrimeField::new(1)]);
+        check::<F7, F7>(&(0..7).map(F7::new).collect::<Vec<_>>());
+        // The image of 𝔽₄ is the set of fixed points of a ↦ a⁴
+        for a in gf4 {
+            let image = a.embed_into::<Gf16>().unwrap();
+            assert_eq!(arithmetic::pow(image, 4), image);
+        }
+        assert_eq!(Gf4::one().embed_into::<Gf8>(), None);
+        assert_eq!(F7::one().embed_into::<PrimeField<5>>(), None);
+    }
+
+    #[test]
+    fn squares_in_a_prime_field() {
+        let squares: Vec<u64> = (0..7)
+            .map(F7::new)
+            .filter(FiniteField::is_square)
+            .map(|a| a.value())
+            .collect();
+        assert_eq!(squares, [0, 1, 2, 4]);
+        // Euler's criterion agrees with squaring every element.
+        type F31 = PrimeField<31>;
+        for a in (0..31).map(F31::new) {
+            let has_root = (0..31).map(F31::new).any(|r| r * r == a);
+            assert_eq!(a.is_square(), has_root);
+        }
+    }
+
+    #[test]
+    fn every_element_of_a_binary_field_is_a_square() {
+        assert!(Gf8::elements().all(|a| a.is_square()));
+        assert!(Gf16::elements().all(|a| a.is_square()));
+        // Squaring permutes the field, which is why this holds.
+        let mut images: Vec<u16> = Gf8::elements().map(|a| (a * a).0).collect();
+        images.sort_unstable();
+        assert_eq!(images, (0..8).collect::<Vec<_>>());
+    }
+}
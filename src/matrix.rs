@@ -0,0 +1,1876 @@
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, Distributive,
+    EuclideanDomain, Field, InnerProductSpace, PrimeField, RealField, ReductionStrategy, Ring,
+    Vector, VectorSpace,
+};
+use num_traits::{Euclid, One, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Represents an R × C matrix with entries in a ring T.
+///
+/// # Mathematical Definition
+/// The matrices Tᴿˣᶜ form an abelian group under entrywise addition, and a vector space of
+/// dimension R · C over T when T is a field. The product of an R × K and a K × C matrix is the
+/// R × C matrix (AB)ᵢⱼ = Σₖ Aᵢₖ Bₖⱼ.
+///
+/// # Properties
+/// - (Tᴿˣᶜ, +) is an abelian group for any ring T, such as `i64`
+/// - Square matrices Tᴺˣᴺ form a ring with the identity matrix as one
+/// - For N ≥ 2 that ring is not commutative and has zero divisors, so only the ring markers
+///   are implemented and square matrices stop short of `CommutativeRing`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Matrix<T: Ring, const R: usize, const C: usize> {
+    rows: [[T; C]; R],
+}
+
+impl<T: Ring, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Creates a matrix from its rows.
+    pub fn from_rows(rows: [[T; C]; R]) -> Self {
+        Self { rows }
+    }
+
+    /// Returns the rows of the matrix.
+    pub fn rows(&self) -> &[[T; C]; R] {
+        &self.rows
+    }
+
+    /// Returns the transpose, with (Aᵀ)ᵢⱼ = Aⱼᵢ.
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        Matrix::from_rows(std::array::from_fn(|j| {
+            std::array::from_fn(|i| self.rows[i][j].clone())
+        }))
+    }
+
+    /// Returns the Hadamard product, the entrywise product (A ∘ B)ᵢⱼ = Aᵢⱼ Bᵢⱼ.
+    ///
+    /// Unlike the matrix product this is commutative whenever T is, and its identity is the
+    /// all-ones matrix.
+    pub fn hadamard(&self, other: &Self) -> Self {
+        self.map(|i, j| self.rows[i][j].clone() * other.rows[i][j].clone())
+    }
+
+    /// Builds a matrix of the same shape whose (i, j) entry is f(i, j).
+    fn map(&self, f: impl Fn(usize, usize) -> T) -> Self {
+        Self::from_rows(std::array::from_fn(|i| std::array::from_fn(|j| f(i, j))))
+    }
+}
+
+impl<T: RealField, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Returns whether every entry of self is within `tol` of the matching entry of other.
+    pub fn approx_eq(&self, other: &Self, tol: &T) -> bool {
+        self.rows
+            .iter()
+            .flatten()
+            .zip(other.rows.iter().flatten())
+            .all(|(a, b)| a.is_within(b, tol))
+    }
+}
+
+impl<T: Ring, const N: usize> Matrix<T, N, N> {
+    /// Returns whether self is symmetric, Aᵀ = A.
+    pub fn is_symmetric(&self) -> bool {
+        (0..N).all(|i| (0..i).all(|j| self.rows[i][j] == self.rows[j][i]))
+    }
+
+    /// Returns whether self is skew-symmetric, Aᵀ = −A.
+    ///
+    /// Outside characteristic 2 this forces a zero diagonal, since Aᵢᵢ = −Aᵢᵢ; in
+    /// characteristic 2 skew-symmetric and symmetric coincide.
+    pub fn is_skew_symmetric(&self) -> bool {
+        (0..N).all(|i| (0..=i).all(|j| self.rows[i][j] == -self.rows[j][i].clone()))
+    }
+
+    /// Returns self · other by Strassen's algorithm, recursing while the block size exceeds
+    /// `threshold` and using the schoolbook product below it.
+    ///
+    /// Each level splits the operands into 2 × 2 block matrices of size ⌈n/2⌉, padding odd
+    /// sizes with a zero row and column, and forms the product from seven block products
+    /// instead of eight. The `Mul` impl takes this path with `STRASSEN_THRESHOLD` for large
+    /// matrices; this method exposes the threshold. A threshold of zero is treated as one.
+    ///
+    /// Over an exact ring the result equals the schoolbook product. Over floating point the
+    /// additions are grouped differently, so the two agree only up to rounding.
+    ///
+    /// # Complexity
+    /// O(N^log₂7) ≈ O(N^2.81) ring operations once N is well above the threshold.
+    pub fn strassen_mul(&self, other: &Self, threshold: usize) -> Self {
+        let product = strassen(&blocks(&self.rows), &blocks(&other.rows), N, threshold);
+        self.map(|i, j| product[i][j].clone())
+    }
+}
+
+impl<T: RealField, const N: usize> Matrix<T, N, N> {
+    /// Returns whether self is orthogonal, Aᵀ A = I, with every entry of Aᵀ A within `tol` of
+    /// the identity.
+    pub fn is_orthogonal(&self, tol: &T) -> bool {
+        (self.transpose() * self.clone()).approx_eq(&Self::one(), tol)
+    }
+}
+
+impl<T: EuclideanDomain, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Returns the Smith normal form, the diagonal matrix D = U · self · V for invertible U
+    /// and V whose diagonal entries d₁ | d₂ | ... | dᵣ are the invariant factors of self.
+    ///
+    /// Each pivot is moved to the diagonal and the rest of its row and column cleared with
+    /// unimodular row and column operations built from Bézout coefficients. When the pivot
+    /// fails to divide a remaining entry, that entry's row is added to the pivot row and the
+    /// clearing repeats with a pivot that is a proper divisor of the old one. The invariant
+    /// factors are only unique up to units, and zero entries come last.
+    pub fn smith_normal_form(&self) -> Self {
+        let mut a = self.clone();
+        for t in 0..R.min(C) {
+            let pivot = (t..R)
+                .flat_map(|i| (t..C).map(move |j| (i, j)))
+                .find(|&(i, j)| !a.rows[i][j].is_zero());
+            let (i, j) = match pivot {
+                Some(position) => position,
+                None => break,
+            };
+            a.rows.swap(t, i);
+            for row in a.rows.iter_mut() {
+                row.swap(t, j);
+            }
+            loop {
+                let mut cleared = true;
+                for i in t + 1..R {
+                    if !a.rows[i][t].is_zero() {
+                        a.eliminate_rows(t, i, t);
+                        cleared = false;
+                    }
+                }
+                for j in t + 1..C {
+                    if !a.rows[t][j].is_zero() {
+                        a.eliminate_columns(t, j, t);
+                        cleared = false;
+                    }
+                }
+                if !cleared {
+                    continue;
+                }
+                let pivot = a.rows[t][t].clone();
+                let blocking_row = (t + 1..R).find(|&i| {
+                    a.rows[i][t + 1..]
+                        .iter()
+                        .any(|x| !Euclid::rem_euclid(x, &pivot).is_zero())
+                });
+                match blocking_row {
+                    Some(i) => {
+                        for k in t..C {
+                            let sum = a.rows[t][k].clone() + a.rows[i][k].clone();
+                            a.rows[t][k] = sum;
+                        }
+                    }
+                    None => break,
+                }
+            }
+        }
+        a
+    }
+
+    /// Returns a row echelon form of self reached by unimodular row operations, together with
+    /// the rank.
+    ///
+    /// Column by column, a row with a non-zero entry is swapped into the next pivot row and the
+    /// entries below it are cleared with Bézout combinations, leaving their gcd as the pivot.
+    /// Only exact division is used, so over ℤ the result is an integer matrix whose non-zero
+    /// rows come first, each starting strictly right of the one above. This is the first stage
+    /// of the Hermite normal form; entries above the pivots are not reduced, and pivots are
+    /// only determined up to units.
+    pub fn echelon_over_pid(&self) -> (Self, usize) {
+        let mut a = self.clone();
+        let mut rank = 0;
+        for k in 0..C {
+            if rank == R {
+                break;
+            }
+            let i = match (rank..R).find(|&i| !a.rows[i][k].is_zero()) {
+                Some(i) => i,
+                None => continue,
+            };
+            a.rows.swap(rank, i);
+            for i in rank + 1..R {
+                if !a.rows[i][k].is_zero() {
+                    a.eliminate_rows(rank, i, k);
+                }
+            }
+            rank += 1;
+        }
+        (a, rank)
+    }
+
+    /// Replaces rows t and i by unimodular combinations of them that zero the entry of row i
+    /// in column k, leaving a gcd of the two entries in row t.
+    fn eliminate_rows(&mut self, t: usize, i: usize, k: usize) {
+        let (p, b) = (self.rows[t][k].clone(), self.rows[i][k].clone());
+        if Euclid::rem_euclid(&b, &p).is_zero() {
+            let factor = Euclid::div_euclid(&b, &p);
+            for c in 0..C {
+                let term = factor.clone() * self.rows[t][c].clone();
+                self.rows[i][c] -= term;
+            }
+            return;
+        }
+        // [x y; −b/g p/g] has determinant (x · p + y · b) / g = 1
+        let (g, x, y) = p.bezout(&b);
+        let (u, v) = (Euclid::div_euclid(&p, &g), Euclid::div_euclid(&b, &g));
+        for c in 0..C {
+            let (top, bottom) = (self.rows[t][c].clone(), self.rows[i][c].clone());
+            self.rows[t][c] = x.clone() * top.clone() + y.clone() * bottom.clone();
+            self.rows[i][c] = u.clone() * bottom - v.clone() * top;
+        }
+    }
+
+    /// Replaces columns t and j by unimodular combinations of them that zero the entry of
+    /// column j in row k, leaving a gcd of the two entries in column t.
+    fn eliminate_columns(&mut self, t: usize, j: usize, k: usize) {
+        let (p, b) = (self.rows[k][t].clone(), self.rows[k][j].clone());
+        if Euclid::rem_euclid(&b, &p).is_zero() {
+            let factor = Euclid::div_euclid(&b, &p);
+            for row in self.rows.iter_mut() {
+                let term = factor.clone() * row[t].clone();
+                row[j] -= term;
+            }
+            return;
+        }
+        let (g, x, y) = p.bezout(&b);
+        let (u, v) = (Euclid::div_euclid(&p, &g), Euclid::div_euclid(&b, &g));
+        for row in self.rows.iter_mut() {
+            let (left, right) = (row[t].clone(), row[j].clone());
+            row[t] = x.clone() * left.clone() + y.clone() * right.clone();
+            row[j] = u.clone() * right - v.clone() * left;
+        }
+    }
+}
+
+impl<T: EuclideanDomain, const N: usize> Matrix<T, N, N> {
+    /// Returns the determinant by fraction-free Bareiss elimination.
+    ///
+    /// Each step updates aᵢⱼ ← (aₖₖ · aᵢⱼ − aᵢₖ · aₖⱼ) / p with p the previous pivot, and the
+    /// division is exact, so over ℤ every intermediate entry is a minor of the input. Zero
+    /// pivots are swapped with a lower row, flipping the sign. The empty matrix has
+    /// determinant one.
+    pub fn determinant(&self) -> T {
+        let mut a = self.rows.clone();
+        let mut negate = false;
+        let mut previous = T::one();
+        for k in 0..N {
+            if a[k][k].is_zero() {
+                match (k + 1..N).find(|&i| !a[i][k].is_zero()) {
+                    Some(i) => {
+                        a.swap(i, k);
+                        negate = !negate;
+                    }
+                    None => return T::zero(),
+                }
+            }
+            for i in k + 1..N {
+                for j in k + 1..N {
+                    let numerator =
+                        a[k][k].clone() * a[i][j].clone() - a[i][k].clone() * a[k][j].clone();
+                    a[i][j] = Euclid::div_euclid(&numerator, &previous);
+                }
+            }
+            previous = a[k][k].clone();
+        }
+        if negate {
+            -previous
+        } else {
+            previous
+        }
+    }
+}
+
+impl<T: Field, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Returns the full solution set of A · x = b, where A is self, as a particular solution
+    /// x₀ together with a basis of the null space of A, so that the solutions are exactly
+    /// x₀ + span(basis). Returns `None` if the system is inconsistent.
+    ///
+    /// Gauss–Jordan elimination brings A to reduced row echelon form, carrying b along. Each
+    /// non-pivot column j is a free parameter: its basis vector has a one in position j and
+    /// minus column j of the reduced matrix in the pivot positions. The particular solution
+    /// sets every free parameter to zero.
+    pub fn solve_general(&self, b: &Vector<T, R>) -> Option<(Vector<T, C>, Vec<Vector<T, C>>)> {
+        let mut rhs = b.clone();
+        let (reduced, pivots) = self.reduce_rows(&mut rhs);
+        if (pivots.len()..R).any(|i| !rhs[i].is_zero()) {
+            return None;
+        }
+        let mut particular = Vector::zero();
+        for (k, &j) in pivots.iter().enumerate() {
+            particular[j] = rhs[k].clone();
+        }
+        let basis = (0..C)
+            .filter(|j| !pivots.contains(j))
+            .map(|free| {
+                let mut v = Vector::zero();
+                v[free] = T::one();
+                for (k, &j) in pivots.iter().enumerate() {
+                    v[j] = -reduced.rows[k][free].clone();
+                }
+                v
+            })
+            .collect();
+        Some((particular, basis))
+    }
+
+    /// Returns the reduced row echelon form of self and the pivot column of each non-zero row,
+    /// in increasing order.
+    ///
+    /// Each pivot is one and is the only non-zero entry of its column. The number of pivots is
+    /// the rank, and each non-pivot column is a free variable of A · x = 0 contributing one
+    /// vector to the null-space basis returned by `solve_general`.
+    pub fn rref_with_pivots(&self) -> (Self, Vec<usize>) {
+        self.reduce_rows(&mut Vector::zero())
+    }
+
+    /// Brings self to reduced row echelon form by Gauss–Jordan elimination, applying the same
+    /// row operations to `rhs`, and returns it with the pivot column of each non-zero row.
+    fn reduce_rows(&self, rhs: &mut Vector<T, R>) -> (Self, Vec<usize>) {
+        let mut a = self.rows.clone();
+        let mut pivots = Vec::new();
+        for j in 0..C {
+            let k = pivots.len();
+            if k == R {
+                break;
+            }
+            let i = match (k..R).find(|&i| !a[i][j].is_zero()) {
+                Some(i) => i,
+                None => continue,
+            };
+            a.swap(i, k);
+            let swapped = rhs[i].clone();
+            rhs[i] = std::mem::replace(&mut rhs[k], swapped);
+            let inverse = T::one() / a[k][j].clone();
+            for entry in &mut a[k] {
+                *entry = entry.clone() * inverse.clone();
+            }
+            rhs[k] = rhs[k].clone() * inverse;
+            let pivot_row = a[k].clone();
+            for i in (0..R).filter(|&i| i != k) {
+                let factor = a[i][j].clone();
+                if factor.is_zero() {
+                    continue;
+                }
+                for (entry, pivot_entry) in a[i].iter_mut().zip(&pivot_row) {
+                    *entry -= factor.clone() * pivot_entry.clone();
+                }
+                let term = factor * rhs[k].clone();
+                rhs[i] -= term;
+            }
+            pivots.push(j);
+        }
+        (Self::from_rows(a), pivots)
+    }
+}
+
+impl<T: Field, const N: usize> Matrix<T, N, N> {
+    /// Solves L · x = b by forward substitution, where L is self.
+    ///
+    /// Only the lower triangle of self is read, and xᵢ = (bᵢ − Σⱼ lᵢⱼ · xⱼ) / lᵢᵢ summing over
+    /// j < i.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a diagonal entry is zero.
+    pub fn solve_lower_triangular(&self, b: &Vector<T, N>) -> Vector<T, N> {
+        let mut x = b.clone();
+        for i in 0..N {
+            for j in 0..i {
+                let term = self.rows[i][j].clone() * x[j].clone();
+                x[i] -= term;
+            }
+            x[i] = x[i].clone() / self.diagonal_pivot(i);
+        }
+        x
+    }
+
+    /// Solves U · x = b by back substitution, where U is self.
+    ///
+    /// Only the upper triangle of self is read, and xᵢ = (bᵢ − Σⱼ uᵢⱼ · xⱼ) / uᵢᵢ summing over
+    /// j > i.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a diagonal entry is zero.
+    pub fn solve_upper_triangular(&self, b: &Vector<T, N>) -> Vector<T, N> {
+        let mut x = b.clone();
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                let term = self.rows[i][j].clone() * x[j].clone();
+                x[i] -= term;
+            }
+            x[i] = x[i].clone() / self.diagonal_pivot(i);
+        }
+        x
+    }
+
+    /// Returns a basis of the eigenspace of λ, the kernel of A − λI, which is empty unless λ is
+    /// an eigenvalue of A.
+    ///
+    /// Its size is the geometric multiplicity of λ.
+    pub fn eigenspace(&self, eigenvalue: &T) -> Vec<Vector<T, N>> {
+        let shifted = self.clone() - Self::from(eigenvalue.clone());
+        match shifted.solve_general(&Vector::zero()) {
+            Some((_, kernel)) => kernel,
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the diagonal entry in row i, checking that it can be divided by.
+    fn diagonal_pivot(&self, i: usize) -> T {
+        let pivot = self.rows[i][i].clone();
+        assert!(
+            !pivot.is_zero(),
+            "Triangular solve with a zero diagonal entry"
+        );
+        pivot
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>, const N: usize> Matrix<PrimeField<P, R>, N, N> {
+    /// Returns the eigenvalues of self in 𝔽ₚ, in increasing order of their representatives.
+    ///
+    /// Every element λ of the field is tried in turn by testing A − λI for singularity, so the
+    /// cost is O(P · N³) and only small primes are practical. Eigenvalues that lie in an
+    /// extension of 𝔽ₚ are not found.
+    pub fn eigenvalues(&self) -> Vec<PrimeField<P, R>> {
+        self.eigenvectors()
+            .into_iter()
+            .map(|(eigenvalue, _)| eigenvalue)
+            .collect()
+    }
+
+    /// Returns each eigenvalue of self in 𝔽ₚ together with a basis of its eigenspace.
+    ///
+    /// The eigenvalues are found as in `eigenvalues` and each eigenspace is the kernel of
+    /// A − λI, as in `eigenspace`.
+    pub fn eigenvectors(&self) -> Eigenspaces<PrimeField<P, R>, N> {
+        (0..P)
+            .map(PrimeField::new)
+            .map(|eigenvalue| {
+                let basis = self.eigenspace(&eigenvalue);
+                (eigenvalue, basis)
+            })
+            .filter(|(_, basis)| !basis.is_empty())
+            .collect()
+    }
+
+    /// Returns whether self is diagonalizable over 𝔽ₚ, which holds exactly when its
+    /// eigenspaces together have dimension N, so that 𝔽ₚᴺ has a basis of eigenvectors.
+    ///
+    /// A matrix whose characteristic polynomial does not split over 𝔽ₚ is reported as not
+    /// diagonalizable, even if it becomes diagonalizable over an extension.
+    pub fn is_diagonalizable(&self) -> bool {
+        let dimension: usize = self
+            .eigenvectors()
+            .iter()
+            .map(|(_, basis)| basis.len())
+            .sum();
+        dimension == N
+    }
+}
+
+impl<const N: usize> Matrix<i64, N, N> {
+    /// Returns the determinant from its residues modulo word-sized primes.
+    ///
+    /// The determinant is computed in `PrimeField<P>` for primes just below 2³¹ and combined by
+    /// the Chinese remainder theorem. Hadamard's inequality |det A| ≤ ∏ᵢ ‖rowᵢ‖₂ decides how
+    /// many primes are needed: once their product exceeds twice the bound, the symmetric
+    /// residue is the determinant. Unlike `determinant`, no intermediate value outgrows a
+    /// machine word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Hadamard bound exceeds 2¹²⁰, beyond the four primes used, or if the
+    /// determinant does not fit in an i64.
+    pub fn determinant_crt(&self) -> i64 {
+        let bound_bits: f64 = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(|&a| (a as f64) * (a as f64)).sum::<f64>())
+            .map(|norm_squared| norm_squared.log2() / 2.0)
+            .sum();
+        assert!(
+            bound_bits <= 120.0,
+            "Hadamard bound too large for determinant_crt"
+        );
+        let steps: [CrtStep<N>; 4] = [
+            crt_step::<2_147_483_647, N>,
+            crt_step::<2_147_483_629, N>,
+            crt_step::<2_147_483_587, N>,
+            crt_step::<2_147_483_579, N>,
+        ];
+        let (mut residue, mut modulus) = (0, 1);
+        for step in steps {
+            (residue, modulus) = step(self, (residue, modulus));
+            // Two bits of slack cover the rounding in the floating-point bound
+            if (modulus as f64).log2() > bound_bits + 2.0 {
+                break;
+            }
+        }
+        let determinant = if residue > modulus / 2 {
+            residue as i128 - modulus as i128
+        } else {
+            residue as i128
+        };
+        i64::try_from(determinant).expect("determinant does not fit in an i64")
+    }
+}
+
+impl<const N: usize> Matrix<f64, N, N> {
+    /// Returns the lower-triangular L with L · Lᵀ = self, if self is positive-definite.
+    ///
+    /// The Cholesky–Banachiewicz recurrence computes L row by row, with
+    /// lⱼⱼ = √(aⱼⱼ − Σₖ lⱼₖ²) and lᵢⱼ = (aᵢⱼ − Σₖ lᵢₖ · lⱼₖ) / lⱼⱼ for i > j. Only the lower triangle
+    /// of self is read, so self is assumed symmetric. The diagonal of L is positive, and a
+    /// pivot that is not positive, or NaN, means self is not positive-definite and gives `None`.
+    pub fn cholesky(&self) -> Option<Self> {
+        let mut l = Self::zero();
+        for i in 0..N {
+            for j in 0..=i {
+                let dot: f64 = (0..j).map(|k| l.rows[i][k] * l.rows[j][k]).sum();
+                if i == j {
+                    let pivot = self.rows[i][i] - dot;
+                    if pivot.partial_cmp(&0.0) != Some(Ordering::Greater) {
+                        return None;
+                    }
+                    l.rows[i][i] = pivot.sqrt();
+                } else {
+                    l.rows[i][j] = (self.rows[i][j] - dot) / l.rows[j][j];
+                }
+            }
+        }
+        Some(l)
+    }
+
+    /// Returns the sign of the determinant: 1, −1, or 0 for a singular matrix.
+    ///
+    /// Gaussian elimination with partial pivoting factors P · A = L · U with L unit lower
+    /// triangular, so det A is ∏ᵢ uᵢᵢ times the sign of the permutation P. Only the signs of the
+    /// pivots are multiplied, so the result is unaffected by a determinant too large or too
+    /// small to represent. A column with no non-zero pivot gives 0; for a nearly singular matrix
+    /// the sign is that of the computed pivots, which rounding may have decided.
+    pub fn determinant_sign(&self) -> i8 {
+        let mut a = self.rows;
+        let mut sign = 1;
+        for k in 0..N {
+            let pivot = (k..N)
+                .max_by(|&i, &j| a[i][k].abs().total_cmp(&a[j][k].abs()))
+                .unwrap_or(k);
+            if a[pivot][k] == 0.0 {
+                return 0;
+            }
+            if pivot != k {
+                a.swap(pivot, k);
+                sign = -sign;
+            }
+            if a[k][k] < 0.0 {
+                sign = -sign;
+            }
+            let (upper, lower) = a.split_at_mut(k + 1);
+            let pivot_row = &upper[k];
+            for row in lower {
+                let factor = row[k] / pivot_row[k];
+                for (x, p) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                    *x -= factor * p;
+                }
+            }
+        }
+        sign
+    }
+}
+
+/// Matrices whose dimensions all exceed this are multiplied by Strassen's algorithm, and it is
+/// the block size below which that recursion switches to the schoolbook product.
+pub const STRASSEN_THRESHOLD: usize = 64;
+
+/// A matrix of runtime size, as rows, for the blocks of Strassen's recursion.
+type Block<T> = Vec<Vec<T>>;
+
+/// Copies the rows of a fixed-size matrix into a block.
+fn blocks<T: Ring, const R: usize, const C: usize>(rows: &[[T; C]; R]) -> Block<T> {
+    rows.iter().map(|row| row.to_vec()).collect()
+}
+
+/// Returns a[i][j], or zero outside the block, so that blocks read as zero-padded.
+fn padded_entry<T: Ring>(a: &[Vec<T>], i: usize, j: usize) -> T {
+    a.get(i)
+        .and_then(|row| row.get(j))
+        .cloned()
+        .unwrap_or_else(T::zero)
+}
+
+/// Returns the m × m block of a, read as zero-padded, whose top-left entry is (r, c).
+fn quadrant<T: Ring>(a: &[Vec<T>], r: usize, c: usize, m: usize) -> Block<T> {
+    (0..m)
+        .map(|i| (0..m).map(|j| padded_entry(a, r + i, c + j)).collect())
+        .collect()
+}
+
+/// Combines two blocks of equal size entry by entry.
+fn combine<T: Ring>(a: &[Vec<T>], b: &[Vec<T>], f: impl Fn(T, T) -> T) -> Block<T> {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| {
+            x.iter()
+                .zip(y)
+                .map(|(s, t)| f(s.clone(), t.clone()))
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the n × n product of a and b, read as zero-padded n × n matrices.
+///
+/// Above the threshold, with m = ⌈n/2⌉ and the quadrants Aᵢⱼ, Bᵢⱼ of size m,
+/// - P₁ = (A₁₁ + A₂₂)(B₁₁ + B₂₂), P₂ = (A₂₁ + A₂₂) B₁₁, P₃ = A₁₁ (B₁₂ − B₂₂),
+/// - P₄ = A₂₂ (B₂₁ − B₁₁), P₅ = (A₁₁ + A₁₂) B₂₂, P₆ = (A₂₁ − A₁₁)(B₁₁ + B₁₂),
+/// - P₇ = (A₁₂ − A₂₂)(B₂₁ + B₂₂),
+///
+/// and C₁₁ = P₁ + P₄ − P₅ + P₇, C₁₂ = P₃ + P₅, C₂₁ = P₂ + P₄, C₂₂ = P₁ − P₂ + P₃ + P₆.
+fn strassen<T: Ring>(a: &[Vec<T>], b: &[Vec<T>], n: usize, threshold: usize) -> Block<T> {
+    if n <= threshold.max(1) {
+        return (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| {
+                        (0..n).fold(T::zero(), |sum, k| {
+                            sum + padded_entry(a, i, k) * padded_entry(b, k, j)
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+    let m = (n + 1) / 2;
+    let (a11, a12) = (quadrant(a, 0, 0, m), quadrant(a, 0, m, m));
+    let (a21, a22) = (quadrant(a, m, 0, m), quadrant(a, m, m, m));
+    let (b11, b12) = (quadrant(b, 0, 0, m), quadrant(b, 0, m, m));
+    let (b21, b22) = (quadrant(b, m, 0, m), quadrant(b, m, m, m));
+    let add = |x: &[Vec<T>], y: &[Vec<T>]| combine(x, y, |s, t| s + t);
+    let sub = |x: &[Vec<T>], y: &[Vec<T>]| combine(x, y, |s, t| s - t);
+    let product = |x: &[Vec<T>], y: &[Vec<T>]| strassen(x, y, m, threshold);
+    let p1 = product(&add(&a11, &a22), &add(&b11, &b22));
+    let p2 = product(&add(&a21, &a22), &b11);
+    let p3 = product(&a11, &sub(&b12, &b22));
+    let p4 = product(&a22, &sub(&b21, &b11));
+    let p5 = product(&add(&a11, &a12), &b22);
+    let p6 = product(&sub(&a21, &a11), &add(&b11, &b12));
+    let p7 = product(&sub(&a12, &a22), &add(&b21, &b22));
+    let c11 = add(&sub(&add(&p1, &p4), &p5), &p7);
+    let c12 = add(&p3, &p5);
+    let c21 = add(&p2, &p4);
+    let c22 = add(&add(&sub(&p1, &p2), &p3), &p6);
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    let block = match (i < m, j < m) {
+                        (true, true) => &c11,
+                        (true, false) => &c12,
+                        (false, true) => &c21,
+                        (false, false) => &c22,
+                    };
+                    block[i % m][j % m].clone()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Eigenvalues, each with a basis of its eigenspace.
+type Eigenspaces<T, const N: usize> = Vec<(T, Vec<Vector<T, N>>)>;
+
+type CrtStep<const N: usize> = fn(&Matrix<i64, N, N>, (u128, u128)) -> (u128, u128);
+
+/// Returns the invariant factors of the finitely generated abelian group presented by
+/// `relations`.
+///
+/// Each row of `relations` is a relation Σⱼ aᵢⱼ · eⱼ = 0 on the generators e₁, ..., e_N, so the
+/// group is ℤᴺ modulo the row space. Its Smith normal form gives the decomposition
+/// ℤ/d₁ ⊕ ... ⊕ ℤ/dₖ ⊕ ℤʳ with d₁ | d₂ | ... | dₖ. The result lists d₁, ..., dₖ in that order
+/// followed by a 0 for each free summand ℤ; trivial factors d = 1 are omitted, so the trivial
+/// group gives an empty list.
+pub fn abelian_group_structure<const M: usize, const N: usize>(
+    relations: &Matrix<i64, M, N>,
+) -> Vec<u64> {
+    let smith = relations.smith_normal_form();
+    (0..N)
+        .map(|i| {
+            if i < M {
+                smith.rows[i][i].unsigned_abs()
+            } else {
+                0
+            }
+        })
+        .filter(|&d| d != 1)
+        .collect()
+}
+
+/// Returns the change-of-basis matrix P taking coordinates with respect to `from` to
+/// coordinates with respect to `to`, or `None` if either family is not a basis of Fᴺ.
+///
+/// Writing B and C for the matrices whose columns are `from` and `to`, a vector with
+/// coordinates c in `from` is B · c, so its coordinates in `to` are P · c with P = C⁻¹ · B.
+/// Column j of P is found by solving C · x = fromⱼ.
+pub fn change_of_basis<F: Field, const N: usize>(
+    from: &[Vector<F, N>; N],
+    to: &[Vector<F, N>; N],
+) -> Option<Matrix<F, N, N>> {
+    let columns = |basis: &[Vector<F, N>; N]| -> Matrix<F, N, N> {
+        Matrix::from_rows(std::array::from_fn(|i| {
+            std::array::from_fn(|j| basis[j][i].clone())
+        }))
+    };
+    let (_, kernel) = columns(from).solve_general(&Vector::zero())?;
+    if !kernel.is_empty() {
+        return None;
+    }
+    let target = columns(to);
+    let mut coordinates = Vec::with_capacity(N);
+    for v in from {
+        let (x, kernel) = target.solve_general(v)?;
+        if !kernel.is_empty() {
+            return None;
+        }
+        coordinates.push(x);
+    }
+    Some(Matrix::from_rows(std::array::from_fn(|i| {
+        std::array::from_fn(|j| coordinates[j][i].clone())
+    })))
+}
+
+/// Returns the dominant eigenvalue of a and a unit eigenvector for it, by power iteration.
+///
+/// Starting from the normalized all-ones vector v, each step replaces v by A v / ‖A v‖ and
+/// estimates the eigenvalue by the Rayleigh quotient λ = ⟨v, A v⟩. Iteration stops once the
+/// residual ‖A v − λ v‖ is at most `tol`. The convergence rate is |λ₂ / λ₁| for the two
+/// eigenvalues of largest magnitude, and a start orthogonal to the dominant eigenvector may
+/// converge to another eigenpair instead.
+///
+/// Returns `None` if the residual is still above `tol` after `iterations` steps, as happens
+/// when no single eigenvalue dominates, for instance when the two of largest magnitude are a
+/// complex-conjugate pair or are λ and −λ.
+pub fn power_iteration<const N: usize>(
+    a: &Matrix<f64, N, N>,
+    iterations: usize,
+    tol: f64,
+) -> Option<(f64, Vector<f64, N>)> {
+    let apply = |v: &Vector<f64, N>| {
+        Vector::new(std::array::from_fn(|i| {
+            (0..N).map(|j| a.rows[i][j] * v[j]).sum::<f64>()
+        }))
+    };
+    let mut v = Vector::new([1.0 / (N as f64).sqrt(); N]);
+    for _ in 0..iterations {
+        let image = apply(&v);
+        let eigenvalue = v.inner_product(&image);
+        let residual = image - v.scale(&eigenvalue);
+        if residual.inner_product(&residual).sqrt() <= tol {
+            return Some((eigenvalue, v));
+        }
+        let norm = image.inner_product(&image).sqrt();
+        if norm == 0.0 {
+            return None;
+        }
+        v = image.scale(&norm.recip());
+    }
+    None
+}
+
+/// Extends a residue modulo m by the determinant modulo P, returning the residue modulo m · P.
+fn crt_step<const P: u64, const N: usize>(
+    matrix: &Matrix<i64, N, N>,
+    (residue, modulus): (u128, u128),
+) -> (u128, u128) {
+    let reduced = Matrix::<PrimeField<P>, N, N>::from_rows(std::array::from_fn(|i| {
+        std::array::from_fn(|j| PrimeField::new(matrix.rows[i][j].rem_euclid(P as i64) as u64))
+    }));
+    let target = reduced.determinant();
+    // Solve residue + modulus · t ≡ target (mod P) for t
+    let current = PrimeField::<P>::new((residue % P as u128) as u64);
+    let step = PrimeField::<P>::new((modulus % P as u128) as u64);
+    let t = ((target - current) / step).value() as u128;
+    (residue + modulus * t, modulus * P as u128)
+}
+
+impl<T: Ring + fmt::Display, const R: usize, const C: usize> fmt::Display for Matrix<T, R, C> {
+    /// Writes one bracketed row per line, with each column right-aligned to its widest entry.
+    ///
+    /// A precision such as `{:.2}` is applied to every entry.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|x| match f.precision() {
+                        Some(precision) => format!("{x:.precision$}"),
+                        None => x.to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+        let widths: Vec<usize> = (0..C)
+            .map(|j| {
+                entries
+                    .iter()
+                    .map(|row| row[j].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        for (i, row) in entries.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for (j, entry) in row.iter().enumerate() {
+                let separator = if j > 0 { "  " } else { "" };
+                write!(f, "{separator}{entry:>width$}", width = widths[j])?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Ring, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    /// Returns the entry at (row, column).
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.rows[i][j]
+    }
+}
+
+impl<T: Ring, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.rows[i][j]
+    }
+}
+
+impl<T: Ring, const R: usize, const C: usize> Add for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.map(|i, j| self.rows[i][j].clone() + other.rows[i][j].clone())
+    }
+}
+
+impl<T: Ring, const R: usize, const C: usize> AddAssign for Matrix<T, R, C> {
+    fn add_assign(&mut self, other: Self) {
+        for (row, other_row) in self.rows.iter_mut().zip(other.rows) {
+            for (entry, other_entry) in row.iter_mut().zip(other_row) {
+                *entry += other_entry;
+            }
+        }
+    }
+}
+
+impl<T: Ring, const R: usize, const C: usize> Neg for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|i, j| -self.rows[i][j].clone())
+    }
+}
+
+impl<T: Ring, const R: usize, const C: usize> Sub for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.map(|i, j| self.rows[i][j].clone() - other.rows[i][j].clone())
+    }
+}
+
+impl<T: Ring, const R: usize, const C: usize> SubAssign for Matrix<T, R, C> {
+    fn sub_assign(&mut self, other: Self) {
+        for (row, other_row) in self.rows.iter_mut().zip(other.rows) {
+            for (entry, other_entry) in row.iter_mut().zip(other_row) {
+                *entry -= other_entry;
+            }
+        }
+    }
+}
+
+impl<T: Ring, const R: usize, const K: usize, const C: usize> Mul<Matrix<T, K, C>>
+    for Matrix<T, R, K>
+{
+    type Output = Matrix<T, R, C>;
+
+    /// Uses `Matrix::strassen_mul` when R, K and C all exceed `STRASSEN_THRESHOLD`, padding
+    /// both operands to square matrices of the largest dimension, and the schoolbook product
+    /// otherwise.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, other: Matrix<T, K, C>) -> Matrix<T, R, C> {
+        if R.min(K).min(C) > STRASSEN_THRESHOLD {
+            let n = R.max(K).max(C);
+            let product = strassen(
+                &blocks(&self.rows),
+                &blocks(&other.rows),
+                n,
+                STRASSEN_THRESHOLD,
+            );
+            return Matrix::from_rows(std::array::from_fn(|i| {
+                std::array::from_fn(|j| product[i][j].clone())
+            }));
+        }
+        Matrix::from_rows(std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                let mut sum = T::zero();
+                for k in 0..K {
+                    sum += self.rows[i][k].clone() * other.rows[k][j].clone();
+                }
+                sum
+            })
+        }))
+    }
+}
+
+impl<T: Ring, const N: usize> MulAssign for Matrix<T, N, N> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = std::mem::replace(self, Self::zero()) * other;
+    }
+}
+
+impl<T: Ring, const R: usize, const C: usize> Zero for Matrix<T, R, C> {
+    fn zero() -> Self {
+        Self::from_rows(std::array::from_fn(|_| std::array::from_fn(|_| T::zero())))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.rows.iter().flatten().all(Zero::is_zero)
+    }
+}
+
+impl<T: Ring, const N: usize> One for Matrix<T, N, N> {
+    /// Returns the identity matrix.
+    fn one() -> Self {
+        Self::from_rows(std::array::from_fn(|i| {
+            std::array::from_fn(|j| if i == j { T::one() } else { T::zero() })
+        }))
+    }
+}
+
+impl<T: Ring, const N: usize> From<T> for Matrix<T, N, N> {
+    /// Returns the scalar matrix c · I, the image of c under the embedding of T into Tᴺˣᴺ.
+    fn from(c: T) -> Self {
+        Self::from_rows(std::array::from_fn(|i| {
+            std::array::from_fn(|j| if i == j { c.clone() } else { T::zero() })
+        }))
+    }
+}
+
+impl<T: Field, const R: usize, const C: usize> VectorSpace for Matrix<T, R, C> {
+    type Scalar = T;
+
+    fn scale(&self, scalar: &T) -> Self {
+        self.map(|i, j| scalar.clone() * self.rows[i][j].clone())
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(R * C)
+    }
+}
+
+impl<T: Ring, const R: usize, const C: usize> CommutativeAddition for Matrix<T, R, C> {}
+impl<T: Ring, const R: usize, const C: usize> AssociativeAddition for Matrix<T, R, C> {}
+impl<T: Ring, const N: usize> AssociativeMultiplication for Matrix<T, N, N> {}
+impl<T: Ring, const N: usize> Distributive for Matrix<T, N, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::check_ring_laws;
+    use crate::test_support::SplitMix64;
+    use crate::{GcdDomain, Ratio};
+
+    fn q(n: i64, d: i64) -> Ratio<i64> {
+        Ratio::new(n, d)
+    }
+
+    fn sample() -> [Matrix<Ratio<i64>, 2, 2>; 3] {
+        [
+            Matrix::from_rows([[q(1, 1), q(2, 1)], [q(0, 1), q(1, 3)]]),
+            Matrix::from_rows([[q(0, 1), q(1, 1)], [q(1, 1), q(0, 1)]]),
+            Matrix::from_rows([[q(-1, 2), q(0, 1)], [q(3, 1), q(2, 1)]]),
+        ]
+    }
+
+    #[test]
+    fn square_matrices_over_ratio_form_a_ring() {
+        let [a, b, c] = sample();
+        assert!(check_ring_laws(a.clone(), b.clone(), c.clone()));
+        assert!(check_ring_laws(c, a, b));
+    }
+
+    #[test]
+    fn multiplication_is_associative_but_not_commutative() {
+        let [a, b, c] = sample();
+        assert_eq!(
+            (a.clone() * b.clone()) * c.clone(),
+            a.clone() * (b.clone() * c)
+        );
+        assert_ne!(a.clone() * b.clone(), b * a);
+    }
+
+    #[test]
+    fn integer_matrices_form_a_ring() {
+        let a = Matrix::from_rows([[1i64, 2], [3, 4]]);
+        let b = Matrix::from_rows([[0i64, -1], [5, 2]]);
+        let c = Matrix::from_rows([[7i64, 0], [-2, 1]]);
+        assert!(check_ring_laws(a, b, c));
+        assert_eq!(a * b, Matrix::from_rows([[10, 3], [20, 5]]));
+    }
+
+    #[test]
+    fn transpose_swaps_shape_and_entries() {
+        let a = Matrix::from_rows([[1i64, 2, 3], [4, 5, 6]]);
+        let t = a.transpose();
+        assert_eq!(t, Matrix::from_rows([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(t[(2, 1)], 6);
+        assert_eq!(t.transpose(), a);
+    }
+
+    #[test]
+    fn zero_and_identity() {
+        let [a, _, _] = sample();
+        let zero = Matrix::<Ratio<i64>, 2, 2>::zero();
+        let one = Matrix::<Ratio<i64>, 2, 2>::one();
+        assert!(zero.is_zero());
+        assert_eq!(a.clone() + zero, a);
+        assert_eq!(a.clone() * one.clone(), a);
+        assert_eq!(one * a.clone(), a);
+        assert!((a.clone() - a).is_zero());
+    }
+
+    fn assert_smith_form<const R: usize, const C: usize>(a: &Matrix<i64, R, C>) -> Vec<i64> {
+        let smith = a.smith_normal_form();
+        let mut diagonal = Vec::new();
+        for i in 0..R {
+            for j in 0..C {
+                if i == j {
+                    diagonal.push(smith[(i, j)].abs());
+                } else {
+                    assert_eq!(smith[(i, j)], 0, "off-diagonal entry in {smith:?}");
+                }
+            }
+        }
+        for pair in diagonal.windows(2) {
+            assert!(pair[1] == 0 || pair[0] != 0 && pair[1] % pair[0] == 0);
+        }
+        diagonal
+    }
+
+    #[test]
+    fn smith_normal_form_of_a_known_matrix() {
+        // The determinantal divisors are 2, 4 and |det| = 624
+        let a = Matrix::from_rows([[2i64, 4, 4], [-6, 6, 12], [10, 4, 16]]);
+        assert_eq!(assert_smith_form(&a), [2, 2, 156]);
+        let b = Matrix::from_rows([[2i64, 0], [0, 3]]);
+        assert_eq!(assert_smith_form(&b), [1, 6]);
+        let c = Matrix::from_rows([[0i64, 0, 0], [0, 0, 4]]);
+        assert_eq!(assert_smith_form(&c), [4, 0]);
+    }
+
+    #[test]
+    fn smith_normal_form_preserves_the_determinant() {
+        let mut rng = SplitMix64::new(228);
+        for _ in 0..200 {
+            let a = Matrix::<i64, 4, 4>::from_rows(std::array::from_fn(|_| {
+                std::array::from_fn(|_| rng.range_i64(-9, 9))
+            }));
+            let diagonal = assert_smith_form(&a);
+            assert_eq!(diagonal.iter().product::<i64>(), a.determinant().abs());
+            let content = a
+                .rows()
+                .iter()
+                .flatten()
+                .fold(0, |g: i64, x| g.gcd(x).abs());
+            assert_eq!(diagonal[0], content);
+        }
+    }
+
+    #[test]
+    fn finite_abelian_groups() {
+        assert_eq!(
+            abelian_group_structure(&Matrix::from_rows([[2, 0], [0, 3]])),
+            [6]
+        );
+        assert_eq!(
+            abelian_group_structure(&Matrix::from_rows([[2, 0], [0, 4]])),
+            [2, 4]
+        );
+        assert_eq!(
+            abelian_group_structure(&Matrix::from_rows([[1, 0], [0, 1]])),
+            []
+        );
+        // ℤ³ modulo 2e₁ + 4e₂ + 6e₃ is ℤ/2 ⊕ ℤ²
+        assert_eq!(
+            abelian_group_structure(&Matrix::from_rows([[2, 4, 6]])),
+            [2, 0, 0]
+        );
+    }
+
+    #[test]
+    fn rank_deficient_presentations_have_free_summands() {
+        assert_eq!(
+            abelian_group_structure(&Matrix::from_rows([[2, 0], [0, 0]])),
+            [2, 0]
+        );
+        assert_eq!(
+            abelian_group_structure(&Matrix::from_rows([[3, 6], [1, 2]])),
+            [0]
+        );
+        assert_eq!(
+            abelian_group_structure(&Matrix::<i64, 2, 2>::zero()),
+            [0, 0]
+        );
+    }
+
+    #[test]
+    fn bareiss_determinant() {
+        let a = Matrix::from_rows([[2i64, -1, 0], [1, 3, 4], [0, 5, -2]]);
+        assert_eq!(a.determinant(), -54);
+        // A zero leading pivot forces a row swap
+        let b = Matrix::from_rows([[0i64, 1], [1, 0]]);
+        assert_eq!(b.determinant(), -1);
+        assert_eq!(Matrix::<i64, 2, 2>::zero().determinant(), 0);
+        assert_eq!(Matrix::<i64, 4, 4>::one().determinant(), 1);
+        assert_eq!(Matrix::<i64, 0, 0>::from_rows([]).determinant(), 1);
+        let [c, _, _] = sample();
+        assert_eq!(c.determinant(), q(1, 3));
+    }
+
+    #[test]
+    fn crt_determinant_matches_bareiss() {
+        let mut rng = SplitMix64::new(222);
+        for _ in 0..100 {
+            let a = Matrix::<i64, 5, 5>::from_rows(std::array::from_fn(|_| {
+                std::array::from_fn(|_| rng.range_i64(-1000, 1000))
+            }));
+            // Bareiss over i128 has room for the products of 4 × 4 minors
+            let wide = Matrix::<i128, 5, 5>::from_rows(a.rows.map(|row| row.map(i128::from)));
+            assert_eq!(i128::from(a.determinant_crt()), wide.determinant());
+        }
+    }
+
+    #[test]
+    fn crt_determinant_beyond_a_single_prime() {
+        let a = Matrix::from_rows([[100_000i64, 7, 0], [0, -100_000, 3], [2, 0, 3]]);
+        let det = a.determinant();
+        assert!(det.unsigned_abs() > 2_147_483_647);
+        assert_eq!(a.determinant_crt(), det);
+        let singular = Matrix::from_rows([[1i64, 2], [2, 4]]);
+        assert_eq!(singular.determinant_crt(), 0);
+    }
+
+    #[test]
+    fn cholesky_reconstructs_a_positive_definite_matrix() {
+        let a = Matrix::from_rows([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+        let l = a.cholesky().unwrap();
+        let expected = Matrix::from_rows([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]);
+        let product = l * l.transpose();
+        for i in 0..3 {
+            assert!(l[(i, i)] > 0.0);
+            for j in 0..3 {
+                assert!((l[(i, j)] - expected[(i, j)]).abs() < 1e-12);
+                assert!((product[(i, j)] - a[(i, j)]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn cholesky_of_a_random_gram_matrix() {
+        let mut rng = SplitMix64::new(225);
+        let b = Matrix::<f64, 4, 4>::from_rows(std::array::from_fn(|_| {
+            std::array::from_fn(|_| rng.range_f64(-1.0, 1.0))
+        }));
+        // B · Bᵀ + I is positive-definite
+        let a = b * b.transpose() + Matrix::one();
+        let l = a.cholesky().unwrap();
+        let product = l * l.transpose();
+        for i in 0..4 {
+            assert!(l[(i, i)] > 0.0);
+            for j in 0..4 {
+                assert!((product[(i, j)] - a[(i, j)]).abs() < 1e-12);
+                if j > i {
+                    assert_eq!(l[(i, j)], 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cholesky_rejects_matrices_that_are_not_positive_definite() {
+        // Eigenvalues 3 and −1
+        assert_eq!(Matrix::from_rows([[1.0, 2.0], [2.0, 1.0]]).cholesky(), None);
+        // Positive semi-definite but singular
+        assert_eq!(Matrix::from_rows([[1.0, 1.0], [1.0, 1.0]]).cholesky(), None);
+        assert_eq!(Matrix::from_rows([[-1.0]]).cholesky(), None);
+        assert_eq!(Matrix::from_rows([[f64::NAN]]).cholesky(), None);
+    }
+
+    fn apply<T: Ring, const R: usize, const C: usize>(
+        a: &Matrix<T, R, C>,
+        x: &Vector<T, C>,
+    ) -> Vector<T, R> {
+        Vector::new(std::array::from_fn(|i| {
+            (0..C).fold(T::zero(), |sum, j| sum + a[(i, j)].clone() * x[j].clone())
+        }))
+    }
+
+    #[test]
+    fn forward_substitution() {
+        // The upper triangle is ignored
+        let l = Matrix::from_rows([[2.0, 9.0, 9.0], [1.0, 4.0, 9.0], [-3.0, 2.0, 0.5]]);
+        let x = l.solve_lower_triangular(&Vector::new([2.0, 9.0, 0.0]));
+        assert_eq!(x, Vector::new([1.0, 2.0, -2.0]));
+        let u = Matrix::from_rows([[1.0, 2.0, 3.0], [9.0, 4.0, 5.0], [9.0, 9.0, 2.0]]);
+        let x = u.solve_upper_triangular(&Vector::new([14.0, 23.0, 6.0]));
+        assert_eq!(x, Vector::new([1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn substitution_through_an_lu_factorization() {
+        let l = Matrix::from_rows([[1.0, 0.0, 0.0], [0.5, 1.0, 0.0], [-2.0, 0.25, 1.0]]);
+        let u = Matrix::from_rows([[4.0, -1.0, 2.0], [0.0, 3.0, 1.0], [0.0, 0.0, -5.0]]);
+        let a = l * u;
+        let mut rng = SplitMix64::new(226);
+        for _ in 0..20 {
+            let b = Vector::new(std::array::from_fn(|_| rng.range_f64(-10.0, 10.0)));
+            // A · x = b splits into L · y = b and U · x = y
+            let x = u.solve_upper_triangular(&l.solve_lower_triangular(&b));
+            let residual = apply(&a, &x) - b;
+            assert!(residual.components().iter().all(|r| r.abs() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn triangular_solves_are_exact_over_ratio() {
+        let l = Matrix::from_rows([[q(3, 1), q(0, 1)], [q(1, 2), q(-2, 3)]]);
+        let x = l.solve_lower_triangular(&Vector::new([q(1, 1), q(1, 1)]));
+        assert_eq!(x, Vector::new([q(1, 3), q(-5, 4)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "zero diagonal")]
+    fn zero_diagonal_panics() {
+        let u = Matrix::from_rows([[1.0, 2.0], [0.0, 0.0]]);
+        let _ = u.solve_upper_triangular(&Vector::new([1.0, 1.0]));
+    }
+
+    #[test]
+    fn approximate_equality_within_a_tolerance() {
+        let a = Matrix::from_rows([[1.0, 2.0], [3.0, 4.0]]);
+        let mut b = a;
+        b[(1, 0)] += 1e-9;
+        b[(0, 1)] -= 5e-10;
+        assert!(a.approx_eq(&b, &1e-8));
+        assert!(a.approx_eq(&b, &2e-9));
+        assert!(!a.approx_eq(&b, &1e-10));
+        assert!(a.approx_eq(&a, &0.0));
+        assert!(!a.approx_eq(&Matrix::from_rows([[1.0, 2.0], [3.0, f64::NAN]]), &1.0));
+    }
+
+    #[test]
+    fn display_aligns_columns() {
+        let a = Matrix::from_rows([[1.0, -2.5, 0.0], [10.0, 3.0, -100.0]]);
+        assert_eq!(a.to_string(), "[ 1  -2.5     0]\n[10     3  -100]");
+        assert_eq!(
+            format!("{a:.1}"),
+            "[ 1.0  -2.5     0.0]\n[10.0   3.0  -100.0]"
+        );
+        let b = Matrix::from_rows([[q(1, 2), q(-3, 1)], [q(5, 7), q(0, 1)]]);
+        assert_eq!(b.to_string(), "[1/2  -3/1]\n[5/7   0/1]");
+        assert_eq!(Matrix::<i64, 0, 0>::from_rows([]).to_string(), "");
+    }
+
+    #[test]
+    fn scaling_over_a_field() {
+        let [a, _, _] = sample();
+        assert_eq!(
+            a.scale(&q(3, 1)),
+            Matrix::from_rows([[q(3, 1), q(6, 1)], [q(0, 1), q(1, 1)]])
+        );
+        assert_eq!(a.dimension(), Some(4));
+    }
+
+    #[test]
+    fn hadamard_product_is_entrywise() {
+        let a = Matrix::from_rows([[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from_rows([[0.5, -1.0], [2.0, 0.0]]);
+        let expected = Matrix::from_rows([[0.5, -2.0], [6.0, 0.0]]);
+        assert_eq!(a.hadamard(&b), expected);
+        assert_eq!(b.hadamard(&a), expected);
+        assert_ne!(a.hadamard(&b), a * b);
+    }
+
+    #[test]
+    fn hadamard_product_agrees_with_matrix_product_on_diagonals() {
+        let a = Matrix::from_rows([[2.0, 0.0], [0.0, -3.0]]);
+        let b = Matrix::from_rows([[5.0, 0.0], [0.0, 0.5]]);
+        assert_eq!(a.hadamard(&b), a * b);
+    }
+
+    #[test]
+    fn power_iteration_finds_the_dominant_eigenpair() {
+        let a = Matrix::from_rows([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+        let root2 = 2f64.sqrt();
+        let (eigenvalue, v) = power_iteration(&a, 200, 1e-10).unwrap();
+        assert!((eigenvalue - (2.0 + root2)).abs() < 1e-9);
+        let expected = Vector::new([0.5, -root2 / 2.0, 0.5]);
+        assert!((v.inner_product(&expected).abs() - 1.0).abs() < 1e-9);
+        let av = Vector::new(std::array::from_fn(|i| {
+            (0..3).map(|j| a[(i, j)] * v[j]).sum::<f64>()
+        }));
+        assert!(av.approx_eq(&v.scale(&eigenvalue), &1e-9));
+    }
+
+    #[test]
+    fn power_iteration_handles_a_negative_dominant_eigenvalue() {
+        let a = Matrix::from_rows([[-5.0, 1.0], [1.0, 2.0]]);
+        let (eigenvalue, _) = power_iteration(&a, 500, 1e-10).unwrap();
+        let expected = (-3.0 - 53f64.sqrt()) / 2.0;
+        assert!((eigenvalue - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn power_iteration_fails_without_a_dominant_eigenvalue() {
+        // Eigenvalues ±1: the iterate oscillates between two directions.
+        let reflection = Matrix::from_rows([[1.0, 0.0], [0.0, -1.0]]);
+        assert_eq!(power_iteration(&reflection, 1000, 1e-10), None);
+        // Eigenvalues ±i: a rotation by a quarter turn has no real eigenvector.
+        let rotation = Matrix::from_rows([[0.0, -1.0], [1.0, 0.0]]);
+        assert_eq!(power_iteration(&rotation, 1000, 1e-10), None);
+    }
+
+    #[test]
+    fn symmetric_matrices_over_a_prime_field() {
+        type F7 = PrimeField<7>;
+        let b = Matrix::<F7, 3, 3>::from_rows([
+            [F7::new(1), F7::new(4), F7::new(0)],
+            [F7::new(2), F7::new(6), F7::new(3)],
+            [F7::new(5), F7::new(1), F7::new(2)],
+        ]);
+        assert!(!b.is_symmetric());
+        assert!((b + b.transpose()).is_symmetric());
+        assert!(!(b + b.transpose()).is_skew_symmetric());
+
+        let skew = b - b.transpose();
+        assert!(skew.is_skew_symmetric());
+        assert!(!skew.is_symmetric());
+        assert!((0..3).all(|i| skew[(i, i)].is_zero()));
+        assert!(Matrix::<F7, 3, 3>::zero().is_symmetric());
+        assert!(Matrix::<F7, 3, 3>::zero().is_skew_symmetric());
+    }
+
+    #[test]
+    fn rotations_are_orthogonal() {
+        let (sin, cos) = 0.7f64.sin_cos();
+        let rotation = Matrix::from_rows([[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]]);
+        assert!(rotation.is_orthogonal(&1e-12));
+        assert!((rotation * rotation).is_orthogonal(&1e-12));
+        let shear = Matrix::from_rows([[1.0, 0.5, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert!(!shear.is_orthogonal(&1e-12));
+        assert!(!rotation.scale(&2.0).is_orthogonal(&1e-12));
+    }
+
+    fn f5_matrix<const R: usize, const C: usize>(
+        rows: [[u64; C]; R],
+    ) -> Matrix<PrimeField<5>, R, C> {
+        Matrix::from_rows(rows.map(|row| row.map(PrimeField::new)))
+    }
+
+    fn f5_vector<const N: usize>(components: [u64; N]) -> Vector<PrimeField<5>, N> {
+        Vector::new(components.map(PrimeField::new))
+    }
+
+    #[test]
+    fn underdetermined_system_over_f5() {
+        // The third row is the sum of the first two, so the rank is two and two of the four
+        // unknowns are free.
+        let a = f5_matrix([[1, 2, 0, 3], [0, 1, 4, 1], [1, 3, 4, 4]]);
+        let b = f5_vector([2, 3, 0]);
+        let (particular, basis) = a.solve_general(&b).unwrap();
+        assert_eq!(basis.len(), 2);
+        assert_eq!(apply(&a, &particular), b);
+        for v in &basis {
+            assert!(!v.is_zero());
+            assert!(apply(&a, v).is_zero());
+        }
+        let shifted = particular + basis[0].scale(&PrimeField::new(3)) + basis[1];
+        assert_eq!(apply(&a, &shifted), b);
+    }
+
+    #[test]
+    fn inconsistent_system_has_no_solution() {
+        let a = f5_matrix([[1, 2, 0, 3], [0, 1, 4, 1], [1, 3, 4, 4]]);
+        assert_eq!(a.solve_general(&f5_vector([2, 3, 1])), None);
+    }
+
+    #[test]
+    fn invertible_system_has_a_unique_solution() {
+        let a = f5_matrix([[2, 1, 0], [1, 3, 1], [0, 4, 2]]);
+        let x = f5_vector([4, 0, 3]);
+        let (particular, basis) = a.solve_general(&apply(&a, &x)).unwrap();
+        assert_eq!(particular, x);
+        assert!(basis.is_empty());
+    }
+
+    #[test]
+    fn zero_matrix_leaves_every_unknown_free() {
+        let a = Matrix::<PrimeField<5>, 2, 3>::zero();
+        let (particular, basis) = a.solve_general(&Vector::zero()).unwrap();
+        assert!(particular.is_zero());
+        assert_eq!(basis.len(), 3);
+        assert_eq!(a.solve_general(&f5_vector([0, 1])), None);
+    }
+
+    fn standard_basis<const N: usize>() -> [Vector<f64, N>; N] {
+        std::array::from_fn(|i| {
+            Vector::new(std::array::from_fn(|j| if i == j { 1.0 } else { 0.0 }))
+        })
+    }
+
+    #[test]
+    fn change_of_basis_to_itself_is_the_identity() {
+        let standard = standard_basis::<3>();
+        assert_eq!(change_of_basis(&standard, &standard), Some(Matrix::one()));
+        let skewed = [
+            Vector::new([1.0, 1.0, 0.0]),
+            Vector::new([0.0, 2.0, 1.0]),
+            Vector::new([1.0, 0.0, 3.0]),
+        ];
+        let p = change_of_basis(&skewed, &skewed).unwrap();
+        assert!(p.approx_eq(&Matrix::one(), &1e-12));
+        // From any basis to the standard basis, P has the basis vectors as columns.
+        let p = change_of_basis(&skewed, &standard).unwrap();
+        assert_eq!(p.transpose().rows().map(Vector::new), skewed);
+    }
+
+    #[test]
+    fn change_of_basis_round_trips_coordinates() {
+        let from = [
+            Vector::new([2.0, 0.0, 1.0]),
+            Vector::new([1.0, -1.0, 0.0]),
+            Vector::new([0.0, 3.0, 1.0]),
+        ];
+        let to = [
+            Vector::new([1.0, 1.0, 1.0]),
+            Vector::new([0.0, 1.0, -2.0]),
+            Vector::new([4.0, 0.0, 1.0]),
+        ];
+        let forward = change_of_basis(&from, &to).unwrap();
+        let backward = change_of_basis(&to, &from).unwrap();
+        let coordinates = Vector::new([0.5, -2.0, 3.0]);
+        let converted = apply(&forward, &coordinates);
+        assert!(apply(&backward, &converted).approx_eq(&coordinates, &1e-12));
+        // Both coordinate vectors describe the same point.
+        let point = |basis: &[Vector<f64, 3>; 3], c: &Vector<f64, 3>| {
+            (0..3).fold(Vector::zero(), |sum, i| sum + basis[i].scale(&c[i]))
+        };
+        assert!(point(&to, &converted).approx_eq(&point(&from, &coordinates), &1e-12));
+    }
+
+    #[test]
+    fn dependent_families_are_not_bases() {
+        let dependent = [
+            Vector::new([1.0, 2.0, 0.0]),
+            Vector::new([0.0, 1.0, 1.0]),
+            Vector::new([1.0, 4.0, 2.0]),
+        ];
+        let standard = standard_basis::<3>();
+        assert_eq!(change_of_basis(&dependent, &standard), None);
+        assert_eq!(change_of_basis(&standard, &dependent), None);
+    }
+
+    #[test]
+    fn row_echelon_form_over_f5() {
+        let a = f5_matrix([[0, 1, 2, 0, 1], [0, 2, 4, 1, 3], [0, 3, 1, 4, 0]]);
+        let (rref, pivots) = a.rref_with_pivots();
+        // Column 0 is zero and column 2 is twice column 1, so neither holds a pivot.
+        assert_eq!(pivots, [1, 3, 4]);
+        assert_eq!(
+            rref,
+            f5_matrix([[0, 1, 2, 0, 0], [0, 0, 0, 1, 0], [0, 0, 0, 0, 1]])
+        );
+        let (again, same) = rref.rref_with_pivots();
+        assert_eq!((again, same), (rref, pivots));
+    }
+
+    #[test]
+    fn pivot_count_is_the_rank() {
+        // The third row is the sum of the first two, so the rank is two.
+        let a = f5_matrix([[1, 2, 0, 3], [0, 1, 4, 1], [1, 3, 4, 4]]);
+        let (rref, pivots) = a.rref_with_pivots();
+        assert_eq!(pivots, [0, 1]);
+        assert!(rref.rows()[2].iter().all(Zero::is_zero));
+        assert_eq!(
+            Matrix::<PrimeField<5>, 3, 3>::one().rref_with_pivots().1,
+            [0, 1, 2]
+        );
+        assert!(Matrix::<PrimeField<5>, 2, 3>::zero()
+            .rref_with_pivots()
+            .1
+            .is_empty());
+    }
+
+    #[test]
+    fn free_columns_index_the_kernel_basis() {
+        let a = f5_matrix([[1, 2, 0, 3, 1], [2, 4, 1, 1, 0], [3, 1, 1, 4, 1]]);
+        let (_, pivots) = a.rref_with_pivots();
+        let free: Vec<usize> = (0..5).filter(|j| !pivots.contains(j)).collect();
+        let (_, kernel) = a.solve_general(&Vector::zero()).unwrap();
+        assert_eq!(kernel.len(), free.len());
+        for (v, &j) in kernel.iter().zip(&free) {
+            assert!(apply(&a, v).is_zero());
+            // Each basis vector sets its own free variable to one and the others to zero.
+            for &k in &free {
+                assert_eq!(
+                    v[k],
+                    if k == j {
+                        PrimeField::one()
+                    } else {
+                        PrimeField::zero()
+                    }
+                );
+            }
+        }
+    }
+
+    /// Asserts that a is in row echelon form and returns the pivot column of each non-zero row.
+    fn echelon_pivots<const R: usize, const C: usize>(a: &Matrix<i64, R, C>) -> Vec<usize> {
+        let mut pivots: Vec<usize> = Vec::new();
+        for i in 0..R {
+            if let Some(j) = (0..C).find(|&j| a[(i, j)] != 0) {
+                assert_eq!(pivots.len(), i, "zero row above row {i} in {a:?}");
+                assert!(pivots.last().map_or(true, |&p| p < j), "{a:?}");
+                pivots.push(j);
+            }
+        }
+        pivots
+    }
+
+    fn to_rationals<const R: usize, const C: usize>(
+        a: &Matrix<i64, R, C>,
+    ) -> Matrix<Ratio<i64>, R, C> {
+        Matrix::from_rows(std::array::from_fn(|i| {
+            std::array::from_fn(|j| Ratio::from(a[(i, j)]))
+        }))
+    }
+
+    #[test]
+    fn echelon_over_the_integers_of_a_known_matrix() {
+        // The third row is the sum of the first two, so the rank is two.
+        let a = Matrix::from_rows([[2i64, 4, 6, 8], [3, 6, 10, 1], [5, 10, 16, 9]]);
+        let (echelon, rank) = a.echelon_over_pid();
+        assert_eq!(rank, 2);
+        assert_eq!(echelon_pivots(&echelon), [0, 2]);
+        // The first pivot is gcd(2, 3, 5) = 1 up to sign.
+        assert_eq!(echelon[(0, 0)].abs(), 1);
+        let b = Matrix::from_rows([[0i64, 0, 4], [0, 6, 3], [0, 9, 0]]);
+        let (echelon, rank) = b.echelon_over_pid();
+        assert_eq!(rank, 2);
+        assert_eq!(echelon_pivots(&echelon), [1, 2]);
+        assert_eq!(echelon[(0, 1)].abs(), 3);
+        assert_eq!(Matrix::<i64, 2, 3>::zero().echelon_over_pid().1, 0);
+    }
+
+    #[test]
+    fn echelon_over_the_integers_is_upper_triangular() {
+        let mut rng = SplitMix64::new(284);
+        for _ in 0..200 {
+            let a = Matrix::<i64, 4, 4>::from_rows(std::array::from_fn(|_| {
+                std::array::from_fn(|_| rng.range_i64(-9, 9))
+            }));
+            let (echelon, rank) = a.echelon_over_pid();
+            for i in 0..4 {
+                for j in 0..i {
+                    assert_eq!(echelon[(i, j)], 0, "{echelon:?}");
+                }
+            }
+            assert_eq!(echelon_pivots(&echelon).len(), rank);
+            // Unimodular row operations preserve the determinant up to sign.
+            assert_eq!(echelon.determinant().abs(), a.determinant().abs());
+        }
+    }
+
+    #[test]
+    fn echelon_rank_agrees_with_the_rank_over_the_rationals() {
+        let mut rng = SplitMix64::new(2840);
+        for _ in 0..200 {
+            // Small entries and a low-rank product make rank deficiency common.
+            let left = Matrix::<i64, 4, 2>::from_rows(std::array::from_fn(|_| {
+                std::array::from_fn(|_| rng.range_i64(-3, 3))
+            }));
+            let right = Matrix::<i64, 2, 5>::from_rows(std::array::from_fn(|_| {
+                std::array::from_fn(|_| rng.range_i64(-3, 3))
+            }));
+            let noise = Matrix::<i64, 4, 5>::from_rows(std::array::from_fn(|_| {
+                std::array::from_fn(|_| if rng.range_i64(0, 3) == 0 { 1 } else { 0 })
+            }));
+            for a in [left * right, left * right + noise] {
+                let (_, rank) = a.echelon_over_pid();
+                let (_, pivots) = to_rationals(&a).rref_with_pivots();
+                assert_eq!(rank, pivots.len(), "{a:?}");
+            }
+        }
+    }
+
+    fn f7_matrix<const N: usize>(rows: [[u64; N]; N]) -> Matrix<PrimeField<7>, N, N> {
+        Matrix::from_rows(rows.map(|row| row.map(PrimeField::new)))
+    }
+
+    #[test]
+    fn diagonal_matrices_are_diagonalizable() {
+        let a = f7_matrix([[2, 0, 0], [0, 5, 0], [0, 0, 2]]);
+        assert!(a.is_diagonalizable());
+        assert_eq!(a.eigenvalues(), [PrimeField::new(2), PrimeField::new(5)]);
+        let unit = |i: usize| {
+            Vector::new(std::array::from_fn(|j| {
+                if i == j {
+                    PrimeField::one()
+                } else {
+                    PrimeField::zero()
+                }
+            }))
+        };
+        let eigenvectors = a.eigenvectors();
+        assert_eq!(eigenvectors[0].1, [unit(0), unit(2)]);
+        assert_eq!(eigenvectors[1].1, [unit(1)]);
+    }
+
+    #[test]
+    fn jordan_blocks_are_not_diagonalizable() {
+        let a = f7_matrix([[3, 1, 0], [0, 3, 1], [0, 0, 3]]);
+        assert!(!a.is_diagonalizable());
+        let eigenvectors = a.eigenvectors();
+        assert_eq!(eigenvectors.len(), 1);
+        let (eigenvalue, basis) = &eigenvectors[0];
+        assert_eq!(*eigenvalue, PrimeField::new(3));
+        assert_eq!(basis.len(), 1);
+        // A block of size two next to a distinct eigenvalue is still deficient.
+        assert!(!f7_matrix([[4, 1, 0], [0, 4, 0], [0, 0, 6]]).is_diagonalizable());
+    }
+
+    #[test]
+    fn eigenvalues_outside_the_field_are_not_found() {
+        // x² + 1 is irreducible over 𝔽₇ since 7 ≡ 3 (mod 4).
+        let rotation = f7_matrix([[0, 6], [1, 0]]);
+        assert!(rotation.eigenvalues().is_empty());
+        assert!(!rotation.is_diagonalizable());
+        // Over 𝔽₅ the same matrix has the eigenvalues ±2, as 2² = −1.
+        let rotation = f5_matrix([[0, 4], [1, 0]]);
+        assert_eq!(
+            rotation.eigenvalues(),
+            [PrimeField::new(2), PrimeField::new(3)]
+        );
+        assert!(rotation.is_diagonalizable());
+    }
+
+    #[test]
+    fn eigenvectors_are_scaled_by_their_eigenvalue() {
+        let mut rng = SplitMix64::new(227);
+        for _ in 0..50 {
+            let a = f7_matrix::<4>(std::array::from_fn(|_| {
+                std::array::from_fn(|_| rng.range_i64(0, 6) as u64)
+            }));
+            let mut dimension = 0;
+            for (eigenvalue, basis) in a.eigenvectors() {
+                dimension += basis.len();
+                for v in basis {
+                    assert!(!v.is_zero());
+                    assert_eq!(apply(&a, &v), v.scale(&eigenvalue));
+                }
+            }
+            assert!(dimension <= 4);
+            assert_eq!(a.is_diagonalizable(), dimension == 4);
+        }
+    }
+
+    #[test]
+    fn determinant_sign_of_known_matrices() {
+        assert_eq!(Matrix::<f64, 3, 3>::one().determinant_sign(), 1);
+        assert_eq!(
+            Matrix::from_rows([[2.0, 1.0], [1.0, 3.0]]).determinant_sign(),
+            1
+        );
+        // det = −5, reached through a pivot exchange.
+        assert_eq!(
+            Matrix::from_rows([[1.0, 3.0], [2.0, 1.0]]).determinant_sign(),
+            -1
+        );
+        assert_eq!(
+            Matrix::from_rows([[0.0, 1.0], [-1.0, 0.0]]).determinant_sign(),
+            1
+        );
+        assert_eq!(Matrix::<f64, 0, 0>::zero().determinant_sign(), 1);
+    }
+
+    #[test]
+    fn determinant_sign_of_reflections() {
+        assert_eq!(
+            Matrix::from_rows([[1.0, 0.0], [0.0, -1.0]]).determinant_sign(),
+            -1
+        );
+        // The Householder reflection I − 2 v vᵀ / ‖v‖² through the plane orthogonal to
+        // v = (1, 2, 2).
+        let v = Vector::new([1.0, 2.0, 2.0]);
+        let reflection = Matrix::one() - v.outer(&v).scale(&(2.0 / 9.0));
+        assert_eq!(reflection.determinant_sign(), -1);
+        assert_eq!((reflection * reflection).determinant_sign(), 1);
+    }
+
+    #[test]
+    fn determinant_sign_of_singular_matrices() {
+        assert_eq!(
+            Matrix::from_rows([[1.0, 2.0], [2.0, 4.0]]).determinant_sign(),
+            0
+        );
+        let a = Matrix::from_rows([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+        assert_eq!(a.determinant_sign(), 0);
+        assert_eq!(Matrix::<f64, 3, 3>::zero().determinant_sign(), 0);
+    }
+
+    #[test]
+    fn determinant_sign_ignores_magnitude() {
+        // The determinants ±10⁶⁰⁰ and 10⁻⁶⁰⁰ overflow and underflow f64.
+        let diagonal =
+            |d: [f64; 3]| Matrix::from_rows([[d[0], 0.0, 0.0], [0.0, d[1], 0.0], [0.0, 0.0, d[2]]]);
+        assert_eq!(diagonal([1e200, 1e200, -1e200]).determinant_sign(), -1);
+        assert_eq!(diagonal([1e200, -1e200, -1e200]).determinant_sign(), 1);
+        assert_eq!(diagonal([1e-200, 1e-200, 1e-200]).determinant_sign(), 1);
+    }
+
+    #[test]
+    fn determinant_sign_agrees_with_the_determinant() {
+        let mut rng = SplitMix64::new(266);
+        for _ in 0..500 {
+            let exact = Matrix::<i64, 4, 4>::from_rows(std::array::from_fn(|_| {
+                std::array::from_fn(|_| rng.range_i64(-9, 9))
+            }));
+            let determinant = exact.determinant();
+            if determinant == 0 {
+                continue;
+            }
+            let a = Matrix::<f64, 4, 4>::from_rows(std::array::from_fn(|i| {
+                std::array::from_fn(|j| exact[(i, j)] as f64)
+            }));
+            assert_eq!(
+                a.determinant_sign() as i64,
+                determinant.signum(),
+                "{exact:?}"
+            );
+            // Scaling by a power of two changes the magnitude but not the sign.
+            assert_eq!(
+                a.scale(&2f64.powi(-900)).determinant_sign(),
+                a.determinant_sign()
+            );
+        }
+    }
+
+    type F97 = PrimeField<97>;
+
+    fn random_f97<const N: usize>(rng: &mut SplitMix64) -> Matrix<F97, N, N> {
+        Matrix::from_rows(std::array::from_fn(|_| {
+            std::array::from_fn(|_| F97::new(rng.range_i64(0, 96) as u64))
+        }))
+    }
+
+    fn random_small_f64<const N: usize>(rng: &mut SplitMix64) -> Matrix<f64, N, N> {
+        Matrix::from_rows(std::array::from_fn(|_| {
+            std::array::from_fn(|_| rng.range_i64(-9, 9) as f64)
+        }))
+    }
+
+    /// The triple loop, independent of the threshold used by `Mul`.
+    fn schoolbook<T: Ring, const N: usize>(
+        a: &Matrix<T, N, N>,
+        b: &Matrix<T, N, N>,
+    ) -> Matrix<T, N, N> {
+        a.map(|i, j| {
+            (0..N).fold(T::zero(), |sum, k| {
+                sum + a[(i, k)].clone() * b[(k, j)].clone()
+            })
+        })
+    }
+
+    fn assert_strassen_matches<const N: usize>(rng: &mut SplitMix64) {
+        let (a, b) = (random_f97::<N>(rng), random_f97::<N>(rng));
+        let (x, y) = (random_small_f64::<N>(rng), random_small_f64::<N>(rng));
+        for threshold in [0, 1, 2, 3, N / 2, N] {
+            assert_eq!(a.strassen_mul(&b, threshold), schoolbook(&a, &b), "N = {N}");
+            // Small integers stay exact in f64, so the regrouped sums agree exactly.
+            assert_eq!(x.strassen_mul(&y, threshold), schoolbook(&x, &y), "N = {N}");
+        }
+    }
+
+    #[test]
+    fn strassen_matches_the_schoolbook_product() {
+        let mut rng = SplitMix64::new(265);
+        assert_strassen_matches::<1>(&mut rng);
+        assert_strassen_matches::<2>(&mut rng);
+        assert_strassen_matches::<3>(&mut rng);
+        assert_strassen_matches::<4>(&mut rng);
+        assert_strassen_matches::<5>(&mut rng);
+        assert_strassen_matches::<7>(&mut rng);
+        assert_strassen_matches::<8>(&mut rng);
+        assert_strassen_matches::<13>(&mut rng);
+        assert_strassen_matches::<16>(&mut rng);
+    }
+
+    #[test]
+    fn strassen_over_real_entries_agrees_up_to_rounding() {
+        let mut rng = SplitMix64::new(2650);
+        for _ in 0..20 {
+            let a = Matrix::<f64, 9, 9>::from_rows(std::array::from_fn(|_| {
+                std::array::from_fn(|_| rng.range_f64(-1.0, 1.0))
+            }));
+            let b = Matrix::<f64, 9, 9>::from_rows(std::array::from_fn(|_| {
+                std::array::from_fn(|_| rng.range_f64(-1.0, 1.0))
+            }));
+            assert!(a.strassen_mul(&b, 2).approx_eq(&schoolbook(&a, &b), &1e-12));
+        }
+    }
+
+    #[test]
+    fn multiplication_switches_to_strassen_above_the_threshold() {
+        let mut rng = SplitMix64::new(2651);
+        // At the threshold `Mul` is the schoolbook product; one above, the odd size forces a
+        // padded first split into blocks of size 33, which are multiplied directly.
+        let (a, b) = (
+            random_f97::<STRASSEN_THRESHOLD>(&mut rng),
+            random_f97::<STRASSEN_THRESHOLD>(&mut rng),
+        );
+        assert_eq!(a * b, schoolbook(&a, &b));
+        const ABOVE: usize = STRASSEN_THRESHOLD + 1;
+        let (a, b) = (random_f97::<ABOVE>(&mut rng), random_f97::<ABOVE>(&mut rng));
+        assert_eq!(a * b, schoolbook(&a, &b));
+        assert_eq!(a * b, a.strassen_mul(&b, STRASSEN_THRESHOLD));
+        let (x, y) = (
+            random_small_f64::<ABOVE>(&mut rng),
+            random_small_f64::<ABOVE>(&mut rng),
+        );
+        assert_eq!(x * y, schoolbook(&x, &y));
+        // Rectangular operands are padded to a square of the largest dimension.
+        let a = Matrix::<F97, ABOVE, 66>::from_rows(std::array::from_fn(|_| {
+            std::array::from_fn(|_| F97::new(rng.range_i64(0, 96) as u64))
+        }));
+        let b = Matrix::<F97, 66, 67>::from_rows(std::array::from_fn(|_| {
+            std::array::from_fn(|_| F97::new(rng.range_i64(0, 96) as u64))
+        }));
+        let product = a * b;
+        for (i, j) in [(0, 0), (64, 66), (31, 40), (64, 0)] {
+            let expected = (0..66).fold(F97::zero(), |sum, k| sum + a[(i, k)] * b[(k, j)]);
+            assert_eq!(product[(i, j)], expected);
+        }
+    }
+}
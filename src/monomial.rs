@@ -0,0 +1,200 @@
+use crate::{AssociativeMultiplication, CommutativeMultiplication};
+use num_traits::One;
+use std::cmp::Ordering;
+use std::ops::{Mul, MulAssign};
+
+/// Represents a monomial x₁^e₁ · x₂^e₂ · ... · xₙ^eₙ in a fixed number of variables.
+///
+/// # Mathematical Definition
+/// A monomial is identified with its exponent vector (e₁, ..., eₙ) ∈ ℕⁿ. Monomials form a
+/// commutative monoid under multiplication, which adds exponent vectors, with identity 1 = x⁰.
+///
+/// # Properties
+/// - m₁ | m₂ iff every exponent of m₁ is at most the corresponding exponent of m₂
+/// - lcm(m₁, m₂) takes the componentwise maximum of the exponents
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Monomial<const VARS: usize> {
+    exponents: [u32; VARS],
+}
+
+impl<const VARS: usize> Monomial<VARS> {
+    /// Creates the monomial with the given exponent vector.
+    pub const fn new(exponents: [u32; VARS]) -> Self {
+        Self { exponents }
+    }
+
+    /// Returns the monomial consisting of the single variable x_var.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `var` is not less than `VARS`.
+    pub fn variable(var: usize) -> Self {
+        let mut exponents = [0; VARS];
+        exponents[var] = 1;
+        Self { exponents }
+    }
+
+    /// Returns the exponent vector.
+    pub fn exponents(&self) -> &[u32; VARS] {
+        &self.exponents
+    }
+
+    /// Returns the total degree e₁ + ... + eₙ.
+    pub fn total_degree(&self) -> u32 {
+        self.exponents.iter().sum()
+    }
+
+    /// Returns whether self divides other.
+    pub fn divides(&self, other: &Self) -> bool {
+        self.exponents
+            .iter()
+            .zip(other.exponents.iter())
+            .all(|(a, b)| a <= b)
+    }
+
+    /// Returns the least common multiple of self and other.
+    pub fn lcm(&self, other: &Self) -> Self {
+        let mut exponents = self.exponents;
+        for (e, &f) in exponents.iter_mut().zip(other.exponents.iter()) {
+            *e = (*e).max(f);
+        }
+        Self { exponents }
+    }
+}
+
+impl<const VARS: usize> Mul for Monomial<VARS> {
+    type Output = Self;
+
+    /// Multiplies monomials by adding their exponent vectors.
+    fn mul(mut self, other: Self) -> Self {
+        self *= other;
+        self
+    }
+}
+
+impl<const VARS: usize> MulAssign for Monomial<VARS> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn mul_assign(&mut self, rhs: Self) {
+        for (e, f) in self.exponents.iter_mut().zip(rhs.exponents.iter()) {
+            *e += f;
+        }
+    }
+}
+
+impl<const VARS: usize> One for Monomial<VARS> {
+    fn one() -> Self {
+        Self {
+            exponents: [0; VARS],
+        }
+    }
+}
+
+impl<const VARS: usize> CommutativeMultiplication for Monomial<VARS> {}
+impl<const VARS: usize> AssociativeMultiplication for Monomial<VARS> {}
+
+/// Represents an admissible monomial order.
+///
+/// # Mathematical Definition
+/// A monomial order is a total order < on monomials such that:
+/// - 1 ≤ m for every monomial m
+/// - m₁ < m₂ implies m₁ · m < m₂ · m for every monomial m
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MonomialOrder {
+    /// Lexicographic order: compare exponents of x₁, then x₂, and so on.
+    Lex,
+    /// Graded lexicographic order: compare total degree, then break ties lexicographically.
+    GrLex,
+    /// Graded reverse lexicographic order: compare total degree, then the monomial with the
+    /// smaller exponent in the last differing variable is larger.
+    GrevLex,
+}
+
+impl MonomialOrder {
+    /// Compares two monomials under this order.
+    pub fn compare<const VARS: usize>(&self, a: &Monomial<VARS>, b: &Monomial<VARS>) -> Ordering {
+        let lex = || a.exponents.cmp(&b.exponents);
+        match self {
+            MonomialOrder::Lex => lex(),
+            MonomialOrder::GrLex => a.total_degree().cmp(&b.total_degree()).then_with(lex),
+            MonomialOrder::GrevLex => a.total_degree().cmp(&b.total_degree()).then_with(|| {
+                a.exponents
+                    .iter()
+                    .zip(b.exponents.iter())
+                    .rev()
+                    .find(|(e, f)| e != f)
+                    .map_or(Ordering::Equal, |(e, f)| f.cmp(e))
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn m(exponents: [u32; 3]) -> Monomial<3> {
+        Monomial::new(exponents)
+    }
+
+    fn leading(order: MonomialOrder, terms: &[Monomial<3>]) -> Monomial<3> {
+        *terms
+            .iter()
+            .max_by(|a, b| order.compare(a, b))
+            .expect("at least one term")
+    }
+
+    #[test]
+    fn multiplication_adds_exponents() {
+        assert_eq!(m([1, 0, 2]) * m([3, 1, 0]), m([4, 1, 2]));
+        assert_eq!(m([1, 0, 2]) * Monomial::one(), m([1, 0, 2]));
+        assert_eq!(Monomial::<3>::variable(1), m([0, 1, 0]));
+        assert_eq!(m([2, 5, 1]).total_degree(), 8);
+    }
+
+    #[test]
+    fn divides_and_lcm() {
+        assert!(m([1, 0, 2]).divides(&m([1, 3, 2])));
+        assert!(!m([1, 0, 2]).divides(&m([0, 3, 2])));
+        assert!(Monomial::one().divides(&m([0, 3, 2])));
+        let (a, b) = (m([2, 0, 1]), m([1, 3, 0]));
+        let lcm = a.lcm(&b);
+        assert_eq!(lcm, m([2, 3, 1]));
+        assert!(a.divides(&lcm) && b.divides(&lcm));
+    }
+
+    #[test]
+    fn leading_monomial_depends_on_the_order() {
+        // x³ against x·y³: Lex prefers the higher power of x, GrLex the higher degree
+        let terms = [m([3, 0, 0]), m([1, 3, 0]), m([0, 0, 1])];
+        assert_eq!(leading(MonomialOrder::Lex, &terms), m([3, 0, 0]));
+        assert_eq!(leading(MonomialOrder::GrLex, &terms), m([1, 3, 0]));
+        assert_eq!(leading(MonomialOrder::GrevLex, &terms), m([1, 3, 0]));
+    }
+
+    #[test]
+    fn grlex_and_grevlex_break_ties_differently() {
+        // x·z² against y³ at degree 3
+        let (a, b) = (m([1, 0, 2]), m([0, 3, 0]));
+        assert_eq!(MonomialOrder::GrLex.compare(&a, &b), Ordering::Greater);
+        assert_eq!(MonomialOrder::GrevLex.compare(&a, &b), Ordering::Less);
+    }
+
+    #[test]
+    fn orders_are_compatible_with_multiplication() {
+        let samples = [m([0, 0, 0]), m([1, 2, 0]), m([0, 1, 3]), m([2, 0, 1])];
+        for order in [
+            MonomialOrder::Lex,
+            MonomialOrder::GrLex,
+            MonomialOrder::GrevLex,
+        ] {
+            for a in &samples {
+                assert_ne!(order.compare(&Monomial::one(), a), Ordering::Greater);
+                for b in &samples {
+                    for c in &samples {
+                        assert_eq!(order.compare(&(*a * *c), &(*b * *c)), order.compare(a, b));
+                    }
+                }
+            }
+        }
+    }
+}
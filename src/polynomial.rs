@@ -0,0 +1,2484 @@
+use crate::arithmetic::pow;
+use crate::euclidean::{continued_fraction, extended_gcd};
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CanonicalAssociate, CommutativeAddition,
+    CommutativeMultiplication, Complex, Distributive, Dual, Field, GcdDomain, OrderedField,
+    Polynomial, Ring, Semiring,
+};
+use num_traits::{Euclid, One, Zero};
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::ops::{Add, AddAssign, Div, Index, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Evaluates a polynomial at x from a stream of coefficients, highest degree first.
+///
+/// For coefficients aₙ, aₙ₋₁, ..., a₀ this applies Horner's rule
+/// (...((aₙ · x + aₙ₋₁) · x + aₙ₋₂) ...) · x + a₀ without collecting the stream, so the
+/// polynomial never has to be materialized. The empty stream is the zero polynomial.
+pub fn evaluate_stream<R: Semiring, I: Iterator<Item = R>>(coeffs_high_to_low: I, x: R) -> R {
+    coeffs_high_to_low.fold(R::zero(), |acc, c| acc * x.clone() + c)
+}
+
+/// Evaluates a polynomial at x from a stream of coefficients, constant term first.
+///
+/// For coefficients a₀, a₁, ..., aₙ this accumulates Σ aᵢ · xⁱ while keeping a running power
+/// of x. It costs one more multiplication per term than `evaluate_stream`, but suits streams
+/// that only produce low-order coefficients first, such as truncated power series.
+pub fn evaluate_stream_low_to_high<R: Semiring, I: Iterator<Item = R>>(
+    coeffs_low_to_high: I,
+    x: R,
+) -> R {
+    let mut power = R::one();
+    let mut sum = R::zero();
+    for c in coeffs_low_to_high {
+        sum += c * power.clone();
+        power *= x.clone();
+    }
+    sum
+}
+
+/// Refines a real root of p by Newton's method, xₖ₊₁ = xₖ − p(xₖ) / p′(xₖ), from `initial`.
+///
+/// Returns the first iterate whose Newton step is at most `tol` in absolute value, after at
+/// most `iterations` steps. Returns `None` if the derivative vanishes at an iterate, if an
+/// iterate stops being finite, or if the steps have not shrunk to `tol` within the cap.
+pub fn newton_root(
+    p: &DensePolynomial<f64>,
+    initial: f64,
+    iterations: usize,
+    tol: f64,
+) -> Option<f64> {
+    let mut x = initial;
+    for _ in 0..iterations {
+        let (value, derivative) = p.eval_with_derivative(&x);
+        if derivative == 0.0 {
+            return None;
+        }
+        let step = value / derivative;
+        x -= step;
+        if !x.is_finite() {
+            return None;
+        }
+        if step.abs() <= tol {
+            return Some(x);
+        }
+    }
+    None
+}
+
+/// Represents a univariate polynomial over a field by its full coefficient vector.
+///
+/// # Mathematical Definition
+/// A polynomial a₀ + a₁ · x + ... + aₙ · xⁿ is stored as [a₀, a₁, ..., aₙ], lowest degree
+/// first. The vector is kept trimmed so that aₙ ≠ 0, which makes the representation canonical.
+///
+/// # Properties
+/// - The zero polynomial has no coefficients; its degree is reported as 0
+/// - F\[x\] is a Euclidean domain with the degree as Euclidean function, so `Div` and `Rem` give
+///   the unique quotient and remainder with deg(r) < deg(divisor)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DensePolynomial<F: Field> {
+    coefficients: Vec<F>,
+}
+
+impl<F: Field> DensePolynomial<F> {
+    /// Creates a polynomial from coefficients ordered from the constant term upwards.
+    pub fn new(coefficients: Vec<F>) -> Self {
+        let mut poly = Self { coefficients };
+        poly.trim();
+        poly
+    }
+
+    /// Creates the monic polynomial ∏ᵢ (x − rᵢ) whose roots are `roots`, with multiplicity.
+    ///
+    /// The linear factors are multiplied in one at a time, each in place in O(deg) operations.
+    /// The empty product is the constant 1.
+    pub fn from_roots(roots: &[F]) -> Self {
+        let mut coefficients = Vec::with_capacity(roots.len() + 1);
+        coefficients.push(F::one());
+        for root in roots {
+            // Multiplying by x − r maps aᵢ to aᵢ₋₁ − r · aᵢ.
+            coefficients.push(F::zero());
+            for i in (0..coefficients.len()).rev() {
+                let shifted = if i > 0 {
+                    coefficients[i - 1].clone()
+                } else {
+                    F::zero()
+                };
+                coefficients[i] = shifted - root.clone() * coefficients[i].clone();
+            }
+        }
+        Self::new(coefficients)
+    }
+
+    /// Returns the coefficients from the constant term upwards, without trailing zeros.
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    /// Returns the coefficient of xⁱ, which is zero beyond the degree.
+    pub fn coefficient(&self, i: usize) -> F {
+        self.coefficients.get(i).cloned().unwrap_or_else(F::zero)
+    }
+
+    /// Returns the leading coefficient, or `None` for the zero polynomial.
+    pub fn leading_coefficient(&self) -> Option<&F> {
+        self.coefficients.last()
+    }
+
+    /// Evaluates the polynomial at x by Horner's rule.
+    pub fn evaluate(&self, x: &F) -> F {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(F::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    /// Evaluates the polynomial at an element of any ring containing F, such as a square matrix.
+    ///
+    /// Horner's rule runs in M, with each coefficient c entering as `M::from(c)`, which for
+    /// matrices is c · I. This takes deg(p) multiplications in M, so p(A) for an N × N matrix
+    /// costs deg(p) matrix products.
+    pub fn evaluate_at<M: Ring + From<F>>(&self, x: M) -> M {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(M::zero(), |acc, c| acc * x.clone() + M::from(c.clone()))
+    }
+
+    /// Returns (p(x), p′(x)) in a single Horner pass, without forming the derivative.
+    ///
+    /// Horner's rule for p runs alongside the recurrence d ← d · x + p for the derivative,
+    /// where p is the running value before the next coefficient is added.
+    pub fn eval_with_derivative(&self, x: &F) -> (F, F) {
+        let mut value = F::zero();
+        let mut derivative = F::zero();
+        for c in self.coefficients.iter().rev() {
+            derivative = derivative * x.clone() + value.clone();
+            value = value * x.clone() + c.clone();
+        }
+        (value, derivative)
+    }
+
+    /// Returns p′(x), the ε part of p(x + ε) evaluated over the dual numbers.
+    ///
+    /// This is forward-mode differentiation: Horner's rule runs in `Dual<F>`, where
+    /// (a + bε)(c + dε) = ac + (ad + bc)ε carries the product rule, so no derivative polynomial
+    /// is built. The result equals `derivative().evaluate(x)` in every characteristic. In
+    /// characteristic p both give the formal derivative, in which (x + ε)ᵖ = xᵖ + p · xᵖ⁻¹ε = xᵖ
+    /// makes the terms with exponent divisible by p vanish. That is a property of the
+    /// polynomial, not of the function it induces: over 𝔽ₚ, xᵖ + x and 2x agree at every point
+    /// but have derivatives 1 and 2.
+    pub fn gradient_at(&self, x: F) -> F {
+        self.evaluate_at(Dual::variable(x)).eps().clone()
+    }
+
+    /// Returns the formal derivative Σ i · aᵢ · xⁱ⁻¹.
+    ///
+    /// In characteristic p the terms whose exponent is a multiple of p vanish.
+    pub fn derivative(&self) -> Self {
+        let mut factor = F::zero();
+        let coefficients = self
+            .coefficients
+            .iter()
+            .skip(1)
+            .map(|c| {
+                factor += F::one();
+                factor.clone() * c.clone()
+            })
+            .collect();
+        Self::new(coefficients)
+    }
+
+    /// Returns the squarefree part p / gcd(p, p′), which has the same roots as p, each simple.
+    ///
+    /// The result is only determined up to a unit factor. In characteristic p, where p′ can
+    /// vanish for a non-constant p such as xᵖ, repeated factors of that form are kept.
+    pub fn squarefree_part(&self) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+        let g = self.gcd(&self.derivative());
+        self.div_rem(&g).0
+    }
+
+    /// Returns the quotient and remainder of division by `divisor`.
+    ///
+    /// Long division is used unless both the divisor degree and the quotient length exceed
+    /// `KARATSUBA_THRESHOLD`, in which case the quotient comes from a Newton inversion of the
+    /// reversed divisor, so that the division costs a few multiplications.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let lead_inverse = divisor
+            .leading_coefficient()
+            .expect("Division by the zero polynomial")
+            .clone()
+            .inv();
+        let divisor_degree = divisor.coefficients.len() - 1;
+        let mut remainder = self.coefficients.clone();
+        if remainder.len() <= divisor_degree {
+            return (Self::zero(), self.clone());
+        }
+        let quotient_len = remainder.len() - divisor_degree;
+        if divisor_degree.min(quotient_len) > KARATSUBA_THRESHOLD {
+            return self.newton_div_rem(divisor, quotient_len);
+        }
+        let mut quotient = vec![F::zero(); remainder.len() - divisor_degree];
+        for shift in (0..quotient.len()).rev() {
+            let factor = remainder[shift + divisor_degree].clone() * lead_inverse.clone();
+            for (i, c) in divisor.coefficients.iter().enumerate() {
+                remainder[shift + i] -= factor.clone() * c.clone();
+            }
+            quotient[shift] = factor;
+        }
+        remainder.truncate(divisor_degree);
+        (Self::new(quotient), Self::new(remainder))
+    }
+
+    /// Returns the product with `other` by Karatsuba's algorithm, recursing while both factors
+    /// have more than `threshold` coefficients and using the schoolbook product below it.
+    ///
+    /// Each level splits the factors as a₀ + xʰ a₁ and b₀ + xʰ b₁ and forms the product from the
+    /// three half-size products a₀b₀, a₁b₁ and (a₀ + a₁)(b₀ + b₁). The `Mul` impl takes this
+    /// path with `KARATSUBA_THRESHOLD` for long factors; this method exposes the threshold. A
+    /// threshold of zero is treated as one.
+    ///
+    /// Over an exact field the result equals the schoolbook product. Over floating point the
+    /// additions are grouped differently, so the two agree only up to rounding.
+    ///
+    /// # Complexity
+    /// O(n^log₂3) ≈ O(n^1.585) field operations for two factors of length n.
+    pub fn karatsuba_mul(&self, other: &Self, threshold: usize) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        Self::new(karatsuba(
+            &self.coefficients,
+            &other.coefficients,
+            threshold.max(1),
+        ))
+    }
+
+    /// Returns the power series inverse of self modulo x^precision, or `None` if the constant
+    /// term is zero and there is no inverse.
+    ///
+    /// Newton iteration doubles the number of correct terms at each step: if g is the inverse
+    /// modulo xᵏ, then g · (2 − self · g) is the inverse modulo x²ᵏ.
+    ///
+    /// # Complexity
+    /// O(M(n)) for precision n, where M(n) is the cost of a product of length n, since the
+    /// products at the final step dominate the geometric sum.
+    pub fn inverse_series(&self, precision: usize) -> Option<Self> {
+        let constant = self.coefficient(0);
+        if constant.is_zero() {
+            return None;
+        }
+        let mut inverse = Self::new(vec![F::one() / constant]);
+        let mut correct = 1;
+        while correct < precision {
+            correct = (2 * correct).min(precision);
+            let error = (self.truncated(correct) * inverse.clone()).truncated(correct);
+            let two = F::one() + F::one();
+            inverse = (inverse.clone() * (Self::new(vec![two]) - error)).truncated(correct);
+        }
+        Some(inverse.truncated(precision))
+    }
+
+    /// Returns self mod xⁿ, the terms of degree below n.
+    fn truncated(&self, n: usize) -> Self {
+        Self::new(self.coefficients.iter().take(n).cloned().collect())
+    }
+
+    /// Divides by reversal: with rev(p) = x^deg p · p(1/x), the quotient of a by b satisfies
+    /// rev(q) = rev(a) / rev(b) mod x^(deg a − deg b + 1), and rev(b) is invertible as a power
+    /// series because its constant term is the leading coefficient of b.
+    fn newton_div_rem(&self, divisor: &Self, quotient_len: usize) -> (Self, Self) {
+        let reversed = |p: &Self| Self::new(p.coefficients.iter().rev().cloned().collect());
+        let inverse = reversed(divisor)
+            .inverse_series(quotient_len)
+            .expect("the reversed divisor has a non-zero constant term");
+        let mut quotient = (reversed(self).truncated(quotient_len) * inverse)
+            .truncated(quotient_len)
+            .coefficients;
+        quotient.resize(quotient_len, F::zero());
+        quotient.reverse();
+        let quotient = Self::new(quotient);
+        let remainder = self.clone() - quotient.clone() * divisor.clone();
+        (quotient, remainder)
+    }
+
+    /// Returns the composition f(g(x)), where f is self and g is `inner`.
+    ///
+    /// Horner's rule is applied in the polynomial ring: f(g) = (...(aₙ · g + aₙ₋₁) · g ...) + a₀.
+    pub fn compose(&self, inner: &Self) -> Self {
+        self.coefficients.iter().rev().fold(Self::zero(), |acc, c| {
+            acc * inner.clone() + Self::new(vec![c.clone()])
+        })
+    }
+
+    /// Returns a decomposition self = f(g(x)) with deg f, deg g ≥ 2, if one is found.
+    ///
+    /// For each factorization deg self = r · s, the Kozen–Landau method takes g as the
+    /// approximate r-th root of self: the unique g of degree s, monic with g(0) = 0, for which
+    /// self / lc(self) − gʳ has degree below r · s − s. The coefficients of f are then the digits
+    /// of self in base g, which must all be constants. Inner factors are tried by increasing
+    /// degree s and the first decomposition is returned.
+    ///
+    /// Decompositions are not unique, since f ∘ g = (f ∘ h⁻¹) ∘ (h ∘ g) for any linear h, which is
+    /// why g is normalized as above. Only the tame case, where r is non-zero in
+    /// F, is searched, so in characteristic p a decomposition with p | deg f may be missed.
+    /// Polynomials of prime degree, or of degree below 4, are indecomposable and give `None`.
+    pub fn decompose(&self) -> Option<(Self, Self)> {
+        let n = self.degree();
+        let lead = self.leading_coefficient()?.clone();
+        let monic = self.scaled(&lead.clone().inv());
+        (2..n).filter(|s| n % s == 0).find_map(|s| {
+            let r = n / s;
+            let r_in_field = (0..r).fold(F::zero(), |acc, _| acc + F::one());
+            if r_in_field.is_zero() {
+                return None;
+            }
+            let mut x_to_s = vec![F::zero(); s + 1];
+            x_to_s[s] = F::one();
+            let mut inner = Self::new(x_to_s);
+            for k in 1..s {
+                let excess =
+                    monic.coefficient(n - k) - pow(inner.clone(), r as u64).coefficient(n - k);
+                inner.coefficients[s - k] = excess / r_in_field.clone();
+            }
+            let mut digits = Vec::with_capacity(r + 1);
+            let mut rest = monic.clone();
+            while !rest.is_zero() {
+                let (quotient, digit) = rest.div_rem(&inner);
+                if digit.degree() > 0 {
+                    return None;
+                }
+                digits.push(digit.coefficient(0));
+                rest = quotient;
+            }
+            Some((Self::new(digits).scaled(&lead), inner))
+        })
+    }
+
+    /// Returns the inverse of self in the quotient ring F\[x\]/(modulus), if it exists.
+    ///
+    /// The extended Euclidean algorithm gives a · self + b · modulus = g with g = gcd(self,
+    /// modulus), so self is invertible exactly when g is a non-zero constant, and then the
+    /// inverse is a / g reduced mod `modulus`. When the modulus is irreducible every non-zero
+    /// residue is invertible and the quotient ring is a field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is the zero polynomial.
+    pub fn inverse_mod(&self, modulus: &Self) -> Option<Self> {
+        let residue = self.div_rem(modulus).1;
+        let (g, a, _) = extended_gcd(residue, modulus.clone());
+        match g.coefficients.as_slice() {
+            [unit] if !unit.is_zero() => Some(a.scaled(&unit.clone().inv()).div_rem(modulus).1),
+            _ => None,
+        }
+    }
+
+    /// Returns the partial quotients [q₀; q₁, q₂, ...] of the continued fraction of
+    /// self / other, the quotients of the Euclidean algorithm on (self, other).
+    ///
+    /// The convergents hₖ / kₖ, with hₖ = qₖ · hₖ₋₁ + hₖ₋₂ and kₖ = qₖ · kₖ₋₁ + kₖ₋₂, end at
+    /// self / other reduced to lowest terms. The expansion of self / 0 is empty.
+    pub fn continued_fraction(&self, other: &Self) -> Vec<Self> {
+        continued_fraction(self, other)
+    }
+
+    /// Returns the Padé approximant p / q of the power series whose first coefficients are
+    /// those of self, with deg p ≤ m and deg q ≤ n for m = `numerator_degree` and
+    /// n = `denominator_degree`, normalized so that q(0) = 1.
+    ///
+    /// The approximant agrees with the series up to x^(m + n), that is q · self ≡ p modulo
+    /// x^(m + n + 1), so self must hold the coefficients up to that degree; later ones are
+    /// ignored. It is found by running the extended Euclidean algorithm on x^(m + n + 1) and
+    /// the truncated series, stopping at the first remainder of degree at most m, which is the
+    /// same computation as rational reconstruction over the integers. Returns `None` if the
+    /// resulting denominator vanishes at zero or exceeds degree n, which happens when the
+    /// approximant of that type does not exist.
+    pub fn pade_approximant(
+        &self,
+        numerator_degree: usize,
+        denominator_degree: usize,
+    ) -> Option<(Self, Self)> {
+        let length = numerator_degree + denominator_degree + 1;
+        let mut modulus = vec![F::zero(); length];
+        modulus.push(F::one());
+        let series: Vec<F> = self.coefficients.iter().take(length).cloned().collect();
+        let (mut r0, mut r1) = (Self::new(modulus), Self::new(series));
+        let (mut t0, mut t1) = (Self::zero(), Self::one());
+        while !r1.is_zero() && r1.degree() > numerator_degree {
+            let (q, r) = r0.div_rem(&r1);
+            r0 = std::mem::replace(&mut r1, r);
+            let t = t0 - q * t1.clone();
+            t0 = std::mem::replace(&mut t1, t);
+        }
+        let constant = t1.coefficient(0);
+        if constant.is_zero() || t1.degree() > denominator_degree {
+            return None;
+        }
+        let scale = constant.inv();
+        Some((r1.scaled(&scale), t1.scaled(&scale)))
+    }
+
+    /// Returns f(g(x)) mod `modulus`, where f is self.
+    ///
+    /// Uses the Brent–Kung baby-step giant-step method. With m = ⌈√(deg f + 1)⌉, the baby steps
+    /// are gⁱ mod `modulus` for i < m, and f is split into blocks f = Σⱼ fⱼ(x) · xᵐʲ with
+    /// deg fⱼ < m. Each block is a linear combination of baby steps, and the blocks are joined
+    /// by Horner's rule in the giant step gᵐ. This takes about 2√n modular multiplications
+    /// instead of the n of plain Horner evaluation. A constant modulus gives zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is the zero polynomial.
+    pub fn modular_compose(&self, g: &Self, modulus: &Self) -> Self {
+        let g = g.div_rem(modulus).1;
+        if self.is_zero() || modulus.coefficients.len() == 1 {
+            return Self::zero();
+        }
+        let mut m = 1;
+        while m * m < self.coefficients.len() {
+            m += 1;
+        }
+        let mut baby_steps = Vec::with_capacity(m + 1);
+        baby_steps.push(Self::one());
+        for i in 1..=m {
+            let next = (baby_steps[i - 1].clone() * g.clone()).div_rem(modulus).1;
+            baby_steps.push(next);
+        }
+        let giant_step = baby_steps.pop().expect("m ≥ 1 baby steps");
+        let mut result = Self::zero();
+        for block in self.coefficients.chunks(m).rev() {
+            result = (result * giant_step.clone()).div_rem(modulus).1;
+            for (c, power) in block.iter().zip(&baby_steps) {
+                result += power.scaled(c);
+            }
+        }
+        result
+    }
+
+    /// Returns the polynomial with every coefficient multiplied by c.
+    fn scaled(&self, c: &F) -> Self {
+        Self::new(
+            self.coefficients
+                .iter()
+                .map(|a| a.clone() * c.clone())
+                .collect(),
+        )
+    }
+
+    /// Removes trailing zero coefficients.
+    fn trim(&mut self) {
+        while self.coefficients.last().map_or(false, |c| c.is_zero()) {
+            self.coefficients.pop();
+        }
+    }
+}
+
+impl<F: OrderedField> DensePolynomial<F> {
+    /// Returns the Sturm sequence p₀ = p, p₁ = p′, pₖ₊₁ = −(pₖ₋₁ mod pₖ), up to the last
+    /// non-zero remainder.
+    ///
+    /// The zero polynomial has an empty sequence.
+    pub fn sturm_sequence(&self) -> Vec<Self> {
+        let mut sequence = Vec::new();
+        if self.is_zero() {
+            return sequence;
+        }
+        sequence.push(self.clone());
+        let mut next = self.derivative();
+        while !next.is_zero() {
+            let remainder = sequence[sequence.len() - 1].div_rem(&next).1;
+            sequence.push(next);
+            next = -remainder;
+        }
+        sequence
+    }
+
+    /// Returns the number of distinct real roots in the closed interval \[a, b\].
+    ///
+    /// Repeated roots are first removed by passing to the squarefree part p / gcd(p, p′). By
+    /// Sturm's theorem the difference V(a) − V(b) in the number of sign changes along its Sturm
+    /// sequence counts the roots in (a, b\], and a root at a is added separately. An empty
+    /// interval, with a > b, has no roots. Over floating-point fields the count is only as
+    /// reliable as the signs of the computed remainders.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is the zero polynomial, which vanishes everywhere.
+    pub fn count_real_roots(&self, a: F, b: F) -> usize {
+        assert!(!self.is_zero(), "Root count of the zero polynomial");
+        if a > b {
+            return 0;
+        }
+        let squarefree = self.squarefree_part();
+        let at_a = squarefree.evaluate(&a);
+        let sequence = squarefree.sturm_sequence();
+        let changes_a = sign_changes(sequence.iter().map(|p| p.evaluate(&a)));
+        let changes_b = sign_changes(sequence.iter().map(|p| p.evaluate(&b)));
+        changes_a.saturating_sub(changes_b) + usize::from(at_a.is_zero())
+    }
+}
+
+/// Counts the sign changes in a sequence, skipping zeros.
+fn sign_changes<F: OrderedField>(values: impl Iterator<Item = F>) -> usize {
+    let mut changes = 0;
+    let mut previous: Option<bool> = None;
+    for value in values {
+        if value.is_zero() {
+            continue;
+        }
+        let positive = value > F::zero();
+        if previous.map_or(false, |p| p != positive) {
+            changes += 1;
+        }
+        previous = Some(positive);
+    }
+    changes
+}
+
+impl DensePolynomial<f64> {
+    /// Returns the complex roots, repeated according to multiplicity, in no particular order.
+    ///
+    /// See the `Complex<f64>` version, to which this delegates.
+    pub fn complex_roots(&self) -> Vec<Complex<f64>> {
+        DensePolynomial::new(
+            self.coefficients
+                .iter()
+                .map(|&c| Complex::from(c))
+                .collect(),
+        )
+        .complex_roots()
+    }
+}
+
+impl DensePolynomial<Complex<f64>> {
+    /// Returns the complex roots, repeated according to multiplicity, in no particular order.
+    ///
+    /// The roots are the eigenvalues of the companion matrix of the monic polynomial, found by
+    /// shifted QR iteration. The companion matrix is already upper Hessenberg, so each step is
+    /// a sweep of Givens rotations, and Wilkinson shifts give fast convergence. Factors of x
+    /// are split off first, so zero roots are exact. A polynomial of degree n has n roots; a
+    /// root of multiplicity m is only resolved to about ε^(1/m), so its copies come back as a
+    /// cluster. Constants, including zero, have no roots.
+    pub fn complex_roots(&self) -> Vec<Complex<f64>> {
+        let zeros = self.coefficients.iter().take_while(|c| c.is_zero()).count();
+        let mut roots = vec![Complex::zero(); zeros];
+        let coefficients = match self.coefficients.get(zeros..) {
+            Some(rest) if rest.len() > 1 => rest,
+            _ => return roots,
+        };
+        let n = coefficients.len() - 1;
+        let leading = coefficients[n];
+        // The companion matrix has −aₙ₋₁₋ⱼ / aₙ across its first row and ones below the diagonal.
+        let mut h = vec![vec![Complex::zero(); n]; n];
+        for (j, entry) in h[0].iter_mut().enumerate() {
+            *entry = -coefficients[n - 1 - j] / leading;
+        }
+        for (i, row) in h.iter_mut().enumerate().skip(1) {
+            row[i - 1] = Complex::one();
+        }
+        roots.extend(hessenberg_eigenvalues(h));
+        roots
+    }
+}
+
+/// Returns the eigenvalues of an upper Hessenberg matrix by shifted QR iteration.
+fn hessenberg_eigenvalues(mut h: Vec<Vec<Complex<f64>>>) -> Vec<Complex<f64>> {
+    let n = h.len();
+    let scale = h.iter().flatten().map(modulus).fold(0.0, f64::max);
+    let mut eigenvalues = Vec::with_capacity(n);
+    let mut hi = n;
+    let mut iterations = 0;
+    while hi > 0 {
+        let last = hi - 1;
+        // Find the start of the unreduced block ending at the last active row.
+        let mut lo = last;
+        while lo > 0 {
+            let diagonal = modulus(&h[lo][lo]) + modulus(&h[lo - 1][lo - 1]);
+            let reference = if diagonal > 0.0 { diagonal } else { scale };
+            if modulus(&h[lo][lo - 1]) <= f64::EPSILON * reference {
+                h[lo][lo - 1] = Complex::zero();
+                break;
+            }
+            lo -= 1;
+        }
+        if lo == last || iterations >= 100 * n {
+            eigenvalues.push(h[last][last]);
+            hi = last;
+            iterations = 0;
+            continue;
+        }
+        iterations += 1;
+        let shift = if iterations % 10 == 0 {
+            // An exceptional shift breaks the rare cycles of the Wilkinson shift.
+            h[last][last] + Complex::new(0.75, 0.5) * Complex::from(modulus(&h[last][last - 1]))
+        } else {
+            wilkinson_shift(
+                h[last - 1][last - 1],
+                h[last - 1][last],
+                h[last][last - 1],
+                h[last][last],
+            )
+        };
+        qr_step(&mut h, lo, last, shift);
+    }
+    eigenvalues
+}
+
+/// Replaces the block h\[lo..=hi\]\[lo..=hi\] of an upper Hessenberg matrix by R · Q + μ, where
+/// Q · R = h − μ, using a Givens rotation for each subdiagonal entry.
+fn qr_step(h: &mut [Vec<Complex<f64>>], lo: usize, hi: usize, shift: Complex<f64>) {
+    for (k, row) in h.iter_mut().enumerate().take(hi + 1).skip(lo) {
+        row[k] -= shift;
+    }
+    let mut rotations = Vec::with_capacity(hi - lo);
+    for k in lo..hi {
+        let (x, y) = (h[k][k], h[k + 1][k]);
+        let r = modulus(&x).hypot(modulus(&y));
+        let (c, s) = if r == 0.0 {
+            (Complex::one(), Complex::zero())
+        } else {
+            (x / Complex::from(r), y / Complex::from(r))
+        };
+        // [c̄ s̄; −s c] maps (x, y) to (r, 0).
+        let (upper, lower) = h.split_at_mut(k + 1);
+        for (a, b) in upper[k][k..=hi].iter_mut().zip(&mut lower[0][k..=hi]) {
+            let (top, bottom) = (*a, *b);
+            *a = c.conjugate() * top + s.conjugate() * bottom;
+            *b = c * bottom - s * top;
+        }
+        rotations.push((c, s));
+    }
+    for (k, (c, s)) in (lo..hi).zip(rotations) {
+        for row in h.iter_mut().take((k + 2).min(hi) + 1).skip(lo) {
+            let (left, right) = (row[k], row[k + 1]);
+            row[k] = c * left + s * right;
+            row[k + 1] = c.conjugate() * right - s.conjugate() * left;
+        }
+    }
+    for (k, row) in h.iter_mut().enumerate().take(hi + 1).skip(lo) {
+        row[k] += shift;
+    }
+}
+
+/// Returns the eigenvalue of \[a b; c d\] closer to d.
+fn wilkinson_shift(
+    a: Complex<f64>,
+    b: Complex<f64>,
+    c: Complex<f64>,
+    d: Complex<f64>,
+) -> Complex<f64> {
+    let half = Complex::from(0.5);
+    let mean = (a + d) * half;
+    let root = complex_sqrt(((a - d) * half) * ((a - d) * half) + b * c);
+    let (first, second) = (mean + root, mean - root);
+    if modulus(&(first - d)) <= modulus(&(second - d)) {
+        first
+    } else {
+        second
+    }
+}
+
+/// Returns the principal square root, the one with non-negative real part.
+fn complex_sqrt(z: Complex<f64>) -> Complex<f64> {
+    let r = modulus(&z);
+    let re = ((r + z.re()) / 2.0).sqrt();
+    let im = ((r - z.re()) / 2.0).sqrt();
+    Complex::new(re, if *z.im() < 0.0 { -im } else { im })
+}
+
+fn modulus(z: &Complex<f64>) -> f64 {
+    z.re().hypot(*z.im())
+}
+
+impl<F: Field> Index<usize> for DensePolynomial<F> {
+    type Output = F;
+
+    /// Returns the stored coefficient of xⁱ.
+    ///
+    /// # Panics
+    ///
+    /// Panics if i exceeds the degree; use `coefficient` to read zeros beyond it.
+    fn index(&self, i: usize) -> &F {
+        &self.coefficients[i]
+    }
+}
+
+impl<F: Field> Add for DensePolynomial<F> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<F: Field> AddAssign for DensePolynomial<F> {
+    fn add_assign(&mut self, other: Self) {
+        if self.coefficients.len() < other.coefficients.len() {
+            self.coefficients
+                .resize(other.coefficients.len(), F::zero());
+        }
+        for (a, b) in self.coefficients.iter_mut().zip(other.coefficients) {
+            *a += b;
+        }
+        self.trim();
+    }
+}
+
+impl<F: Field> Sub for DensePolynomial<F> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<F: Field> SubAssign for DensePolynomial<F> {
+    fn sub_assign(&mut self, other: Self) {
+        *self += -other;
+    }
+}
+
+impl<F: Field> Neg for DensePolynomial<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            coefficients: self.coefficients.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+/// Polynomials whose lengths both exceed this are multiplied by Karatsuba's algorithm and
+/// divided by Newton inversion, and it is the length below which the Karatsuba recursion
+/// switches to the schoolbook product.
+pub const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Returns the product of two non-empty coefficient slices, of length a.len() + b.len() − 1.
+fn karatsuba<F: Field>(a: &[F], b: &[F], threshold: usize) -> Vec<F> {
+    if a.len().min(b.len()) <= threshold {
+        return schoolbook(a, b);
+    }
+    let half = a.len().min(b.len()) / 2;
+    let (a0, a1) = a.split_at(half);
+    let (b0, b1) = b.split_at(half);
+    let low = karatsuba(a0, b0, threshold);
+    let high = karatsuba(a1, b1, threshold);
+    let mut middle = karatsuba(
+        &add_coefficients(a0, a1),
+        &add_coefficients(b0, b1),
+        threshold,
+    );
+    for (i, c) in low.iter().enumerate() {
+        middle[i] -= c.clone();
+    }
+    for (i, c) in high.iter().enumerate() {
+        middle[i] -= c.clone();
+    }
+    let mut product = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, c) in low.into_iter().enumerate() {
+        product[i] += c;
+    }
+    for (i, c) in middle.into_iter().enumerate() {
+        product[half + i] += c;
+    }
+    for (i, c) in high.into_iter().enumerate() {
+        product[2 * half + i] += c;
+    }
+    product
+}
+
+/// Returns the schoolbook convolution of two non-empty coefficient slices.
+fn schoolbook<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut product = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] += x.clone() * y.clone();
+        }
+    }
+    product
+}
+
+/// Returns the coefficient-wise sum of two slices, as long as the longer one.
+fn add_coefficients<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = long.to_vec();
+    for (s, c) in sum.iter_mut().zip(short) {
+        *s += c.clone();
+    }
+    sum
+}
+
+impl<F: Field> Mul for DensePolynomial<F> {
+    type Output = Self;
+
+    /// Multiplies by the schoolbook convolution of the coefficient vectors, or by Karatsuba's
+    /// algorithm when both factors are longer than `KARATSUBA_THRESHOLD`.
+    fn mul(self, other: Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        Self::new(karatsuba(
+            &self.coefficients,
+            &other.coefficients,
+            KARATSUBA_THRESHOLD,
+        ))
+    }
+}
+
+impl<F: Field> MulAssign for DensePolynomial<F> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = std::mem::take(self) * other;
+    }
+}
+
+impl<F: Field> Div for DensePolynomial<F> {
+    type Output = Self;
+
+    /// Returns the quotient of polynomial long division.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is the zero polynomial.
+    fn div(self, other: Self) -> Self {
+        self.div_rem(&other).0
+    }
+}
+
+impl<F: Field> Rem for DensePolynomial<F> {
+    type Output = Self;
+
+    /// Returns the remainder of polynomial long division.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is the zero polynomial.
+    fn rem(self, other: Self) -> Self {
+        self.div_rem(&other).1
+    }
+}
+
+impl<F: Field> Euclid for DensePolynomial<F> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        self.div_rem(v).0
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        self.div_rem(v).1
+    }
+}
+
+impl<F: Field> Default for DensePolynomial<F> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: Field> Zero for DensePolynomial<F> {
+    fn zero() -> Self {
+        Self {
+            coefficients: Vec::new(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+}
+
+impl<F: Field> One for DensePolynomial<F> {
+    fn one() -> Self {
+        Self {
+            coefficients: vec![F::one()],
+        }
+    }
+}
+
+impl<F: Field> CommutativeAddition for DensePolynomial<F> {}
+impl<F: Field> AssociativeAddition for DensePolynomial<F> {}
+impl<F: Field> CommutativeMultiplication for DensePolynomial<F> {}
+impl<F: Field> AssociativeMultiplication for DensePolynomial<F> {}
+impl<F: Field> Distributive for DensePolynomial<F> {}
+
+impl<F: Field> CanonicalAssociate for DensePolynomial<F> {
+    /// Returns the inverse of the leading coefficient, so the normal form is monic.
+    fn normalizing_unit(&self) -> Self {
+        match self.leading_coefficient() {
+            Some(lead) => Self::new(vec![F::one() / lead.clone()]),
+            None => Self::one(),
+        }
+    }
+}
+
+impl<F: Field> Polynomial for DensePolynomial<F> {
+    type Coefficient = F;
+
+    fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    fn coefficient(&self, degree: usize) -> F {
+        DensePolynomial::coefficient(self, degree)
+    }
+}
+
+/// Represents a univariate polynomial over a field by its non-zero terms only.
+///
+/// # Mathematical Definition
+/// A polynomial Σ aᵢ · xⁱ is stored as the finite map i ↦ aᵢ restricted to aᵢ ≠ 0. Memory is
+/// proportional to the number of terms rather than the degree, so x¹⁰⁰⁰⁰⁰ + 1 costs two
+/// entries.
+///
+/// # Properties
+/// - The zero polynomial has no stored terms; its degree is reported as 0
+/// - F\[x\] is a Euclidean domain with the degree as Euclidean function, so `Div` and `Rem` give
+///   the unique quotient and remainder with deg(r) < deg(divisor)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparsePolynomial<F: Field> {
+    terms: BTreeMap<usize, F>,
+}
+
+impl<F: Field> SparsePolynomial<F> {
+    /// Creates a polynomial from (exponent, coefficient) pairs, summing repeated exponents.
+    pub fn new(terms: impl IntoIterator<Item = (usize, F)>) -> Self {
+        let mut poly = Self::zero();
+        for (exponent, coefficient) in terms {
+            poly.add_term(exponent, coefficient);
+        }
+        poly
+    }
+
+    /// Creates the monomial c · xⁿ.
+    pub fn monomial(coefficient: F, exponent: usize) -> Self {
+        Self::new([(exponent, coefficient)])
+    }
+
+    /// Returns the non-zero terms as (exponent, coefficient) pairs in increasing exponent order.
+    pub fn terms(&self) -> impl Iterator<Item = (usize, &F)> {
+        self.terms
+            .iter()
+            .map(|(&exponent, coefficient)| (exponent, coefficient))
+    }
+
+    /// Returns the number of non-zero terms.
+    pub fn term_count(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns the leading term as (exponent, coefficient), or `None` for the zero polynomial.
+    pub fn leading_term(&self) -> Option<(usize, &F)> {
+        self.terms
+            .iter()
+            .next_back()
+            .map(|(&exponent, coefficient)| (exponent, coefficient))
+    }
+
+    /// Evaluates the polynomial at x.
+    ///
+    /// Applies Horner's rule across the gaps between exponents, raising x to each gap by
+    /// square-and-multiply, so the cost depends on the number of terms and the log of the degree.
+    pub fn evaluate(&self, x: &F) -> F {
+        let mut result = F::zero();
+        let mut previous = match self.leading_term() {
+            Some((exponent, _)) => exponent,
+            None => return result,
+        };
+        for (&exponent, coefficient) in self.terms.iter().rev() {
+            result = result * pow(x.clone(), (previous - exponent) as u64) + coefficient.clone();
+            previous = exponent;
+        }
+        result * pow(x.clone(), previous as u64)
+    }
+
+    /// Returns the quotient and remainder of division by `divisor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let (divisor_degree, divisor_lead) = divisor
+            .leading_term()
+            .expect("Division by the zero polynomial");
+        let lead_inverse = divisor_lead.clone().inv();
+        let mut quotient = Self::zero();
+        let mut remainder = self.clone();
+        while let Some((degree, lead)) = remainder.leading_term() {
+            if degree < divisor_degree {
+                break;
+            }
+            let shift = degree - divisor_degree;
+            let factor = lead.clone() * lead_inverse.clone();
+            // The leading terms cancel by construction, but in floating point the computed
+            // difference may not be exactly zero, so the term is removed rather than subtracted.
+            remainder.terms.remove(&degree);
+            for (&exponent, coefficient) in divisor.terms.range(..divisor_degree) {
+                remainder.add_term(exponent + shift, -(factor.clone() * coefficient.clone()));
+            }
+            quotient.add_term(shift, factor);
+        }
+        (quotient, remainder)
+    }
+
+    /// Adds c · xⁿ in place, dropping the term if it cancels.
+    fn add_term(&mut self, exponent: usize, coefficient: F) {
+        if coefficient.is_zero() {
+            return;
+        }
+        match self.terms.entry(exponent) {
+            Entry::Vacant(entry) => {
+                entry.insert(coefficient);
+            }
+            Entry::Occupied(mut entry) => {
+                let sum = entry.get().clone() + coefficient;
+                if sum.is_zero() {
+                    entry.remove();
+                } else {
+                    *entry.get_mut() = sum;
+                }
+            }
+        }
+    }
+}
+
+impl<F: Field> From<DensePolynomial<F>> for SparsePolynomial<F> {
+    /// Keeps only the non-zero coefficients.
+    fn from(poly: DensePolynomial<F>) -> Self {
+        Self::new(poly.coefficients.into_iter().enumerate())
+    }
+}
+
+impl<F: Field> From<SparsePolynomial<F>> for DensePolynomial<F> {
+    /// Fills the gaps between terms with zeros, allocating one coefficient per degree.
+    fn from(poly: SparsePolynomial<F>) -> Self {
+        let length = poly.leading_term().map_or(0, |(exponent, _)| exponent + 1);
+        let mut coefficients = vec![F::zero(); length];
+        for (exponent, coefficient) in poly.terms {
+            coefficients[exponent] = coefficient;
+        }
+        Self::new(coefficients)
+    }
+}
+
+impl<F: Field> Add for SparsePolynomial<F> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<F: Field> AddAssign for SparsePolynomial<F> {
+    fn add_assign(&mut self, other: Self) {
+        for (exponent, coefficient) in other.terms {
+            self.add_term(exponent, coefficient);
+        }
+    }
+}
+
+impl<F: Field> Sub for SparsePolynomial<F> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<F: Field> SubAssign for SparsePolynomial<F> {
+    fn sub_assign(&mut self, other: Self) {
+        for (exponent, coefficient) in other.terms {
+            self.add_term(exponent, -coefficient);
+        }
+    }
+}
+
+impl<F: Field> Neg for SparsePolynomial<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            terms: self
+                .terms
+                .into_iter()
+                .map(|(exponent, coefficient)| (exponent, -coefficient))
+                .collect(),
+        }
+    }
+}
+
+impl<F: Field> Mul for SparsePolynomial<F> {
+    type Output = Self;
+
+    /// Multiplies term by term, so the cost is proportional to the product of the term counts.
+    fn mul(self, other: Self) -> Self {
+        let mut product = Self::zero();
+        for (&i, a) in &self.terms {
+            for (&j, b) in &other.terms {
+                product.add_term(i + j, a.clone() * b.clone());
+            }
+        }
+        product
+    }
+}
+
+impl<F: Field> MulAssign for SparsePolynomial<F> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = std::mem::take(self) * other;
+    }
+}
+
+impl<F: Field> Div for SparsePolynomial<F> {
+    type Output = Self;
+
+    /// Returns the quotient of polynomial long division.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is the zero polynomial.
+    fn div(self, other: Self) -> Self {
+        self.div_rem(&other).0
+    }
+}
+
+impl<F: Field> Rem for SparsePolynomial<F> {
+    type Output = Self;
+
+    /// Returns the remainder of polynomial long division.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is the zero polynomial.
+    fn rem(self, other: Self) -> Self {
+        self.div_rem(&other).1
+    }
+}
+
+impl<F: Field> Euclid for SparsePolynomial<F> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        self.div_rem(v).0
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        self.div_rem(v).1
+    }
+}
+
+impl<F: Field> Default for SparsePolynomial<F> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: Field> Zero for SparsePolynomial<F> {
+    fn zero() -> Self {
+        Self {
+            terms: BTreeMap::new(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<F: Field> One for SparsePolynomial<F> {
+    fn one() -> Self {
+        Self::monomial(F::one(), 0)
+    }
+}
+
+impl<F: Field> CommutativeAddition for SparsePolynomial<F> {}
+impl<F: Field> AssociativeAddition for SparsePolynomial<F> {}
+impl<F: Field> CommutativeMultiplication for SparsePolynomial<F> {}
+impl<F: Field> AssociativeMultiplication for SparsePolynomial<F> {}
+impl<F: Field> Distributive for SparsePolynomial<F> {}
+
+impl<F: Field> CanonicalAssociate for SparsePolynomial<F> {
+    /// Returns the inverse of the leading coefficient, so the normal form is monic.
+    fn normalizing_unit(&self) -> Self {
+        match self.leading_term() {
+            Some((_, lead)) => Self::new([(0, F::one() / lead.clone())]),
+            None => Self::one(),
+        }
+    }
+}
+
+impl<F: Field> Polynomial for SparsePolynomial<F> {
+    type Coefficient = F;
+
+    fn degree(&self) -> usize {
+        self.leading_term().map_or(0, |(exponent, _)| exponent)
+    }
+
+    fn coefficient(&self, degree: usize) -> F {
+        self.terms.get(&degree).cloned().unwrap_or_else(F::zero)
+    }
+}
+
+/// Precomputed barycentric weights for interpolating on a fixed set of distinct nodes.
+///
+/// # Mathematical Definition
+/// For nodes x₀, ..., xₙ the weights are wⱼ = 1 / ∏ₖ≠ⱼ (xⱼ − xₖ). The interpolating polynomial
+/// of values y₀, ..., yₙ is then evaluated by the second barycentric formula
+/// p(x) = (Σ wⱼ · yⱼ / (x − xⱼ)) / (Σ wⱼ / (x − xⱼ)) for x not a node, and p(xⱼ) = yⱼ.
+///
+/// # Complexity
+/// Construction costs O(n²), after which each evaluation costs O(n) for any value vector.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BarycentricInterpolator<F: Field> {
+    nodes: Vec<F>,
+    weights: Vec<F>,
+}
+
+impl<F: Field> BarycentricInterpolator<F> {
+    /// Precomputes the weights for the given nodes, returning `None` if two nodes coincide.
+    pub fn new(nodes: Vec<F>) -> Option<Self> {
+        let mut weights = Vec::with_capacity(nodes.len());
+        for (j, xj) in nodes.iter().enumerate() {
+            let mut product = F::one();
+            for (k, xk) in nodes.iter().enumerate() {
+                if k != j {
+                    product *= xj.clone() - xk.clone();
+                }
+            }
+            if product.is_zero() {
+                return None;
+            }
+            weights.push(product.inv());
+        }
+        Some(Self { nodes, weights })
+    }
+
+    /// Returns the interpolation nodes.
+    pub fn nodes(&self) -> &[F] {
+        &self.nodes
+    }
+
+    /// Returns the barycentric weights.
+    pub fn weights(&self) -> &[F] {
+        &self.weights
+    }
+
+    /// Evaluates at x the polynomial of least degree taking `values[j]` at node j.
+    ///
+    /// Returns `values[j]` directly when x is node j. With no nodes the interpolant is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` does not have one entry per node.
+    pub fn evaluate(&self, values: &[F], x: &F) -> F {
+        assert_eq!(
+            values.len(),
+            self.nodes.len(),
+            "expected one value per interpolation node"
+        );
+        let mut numerator = F::zero();
+        let mut denominator = F::zero();
+        for ((node, weight), value) in self.nodes.iter().zip(&self.weights).zip(values) {
+            let difference = x.clone() - node.clone();
+            if difference.is_zero() {
+                return value.clone();
+            }
+            let term = weight.clone() / difference;
+            numerator += term.clone() * value.clone();
+            denominator += term;
+        }
+        if denominator.is_zero() {
+            return F::zero();
+        }
+        numerator / denominator
+    }
+}
+
+/// A subproduct tree over a fixed set of distinct points, for evaluating polynomials at all of
+/// them and interpolating values on them.
+///
+/// # Mathematical Definition
+/// Level 0 holds the linear factors x − xᵢ, and each node of level l + 1 is the product of two
+/// adjacent nodes of level l, an unpaired last node being carried up unchanged. The root is
+/// M = ∏ᵢ (x − xᵢ). Evaluation reduces p modulo each node from the root down, so that the
+/// leaves hold p mod (x − xᵢ) = p(xᵢ). Interpolation combines yᵢ / M′(xᵢ) from the leaves up,
+/// a node with children (A, B) and partial sums (a, b) receiving a · B + b · A, so that the
+/// root receives the Lagrange form Σᵢ yᵢ · M / ((x − xᵢ) M′(xᵢ)).
+///
+/// # Complexity
+/// The tree has O(log n) levels, and each level costs multiplications and divisions of total
+/// degree n, so building, evaluating and interpolating cost O(M(n) log n), where M(n) is the
+/// cost of a product of degree n. Above `KARATSUBA_THRESHOLD`, `DensePolynomial` multiplies by
+/// Karatsuba's algorithm and divides by Newton inversion, so M(n) = O(n^log₂3) ≈ O(n^1.585):
+/// subquadratic, though not quasi-linear as FFT-based products would be. The tree and the
+/// weights 1 / M′(xᵢ) are computed once and shared by every call.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FastPolyOps<F: Field> {
+    points: Vec<F>,
+    tree: Vec<Vec<DensePolynomial<F>>>,
+    weights: Vec<F>,
+}
+
+impl<F: Field> FastPolyOps<F> {
+    /// Builds the subproduct tree over the points, returning `None` if two points coincide.
+    pub fn new(points: Vec<F>) -> Option<Self> {
+        let mut level: Vec<DensePolynomial<F>> = points
+            .iter()
+            .map(|x| DensePolynomial::new(vec![-x.clone(), F::one()]))
+            .collect();
+        let mut tree = Vec::new();
+        while level.len() > 1 {
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => a.clone() * b.clone(),
+                    _ => pair[0].clone(),
+                })
+                .collect();
+            tree.push(std::mem::replace(&mut level, next));
+        }
+        tree.push(level);
+        let mut ops = Self {
+            points,
+            tree,
+            weights: Vec::new(),
+        };
+        let root = ops.tree[ops.tree.len() - 1].first().cloned();
+        let derivatives = match root {
+            Some(root) => ops.evaluate(&root.derivative()),
+            None => Vec::new(),
+        };
+        if derivatives.iter().any(|d| d.is_zero()) {
+            return None;
+        }
+        ops.weights = derivatives.into_iter().map(|d| F::one() / d).collect();
+        Some(ops)
+    }
+
+    /// Returns the points.
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    /// Returns the values of p at every point, in order.
+    pub fn evaluate(&self, p: &DensePolynomial<F>) -> Vec<F> {
+        let mut levels = self.tree.iter().rev();
+        let mut remainders: Vec<DensePolynomial<F>> = match levels.next() {
+            Some(root) => root.iter().map(|m| p.clone() % m.clone()).collect(),
+            None => return Vec::new(),
+        };
+        for level in levels {
+            remainders = level
+                .iter()
+                .enumerate()
+                .map(|(j, m)| remainders[j / 2].clone() % m.clone())
+                .collect();
+        }
+        remainders.iter().map(|r| r.coefficient(0)).collect()
+    }
+
+    /// Returns the polynomial of degree less than n taking `values[i]` at the i-th point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` does not have one entry per point.
+    pub fn interpolate(&self, values: &[F]) -> DensePolynomial<F> {
+        assert_eq!(
+            values.len(),
+            self.points.len(),
+            "expected one value per point"
+        );
+        let mut sums: Vec<DensePolynomial<F>> = values
+            .iter()
+            .zip(&self.weights)
+            .map(|(y, w)| DensePolynomial::new(vec![y.clone() * w.clone()]))
+            .collect();
+        for level in &self.tree[..self.tree.len() - 1] {
+            sums = sums
+                .chunks(2)
+                .zip(level.chunks(2))
+                .map(|pair| match pair {
+                    ([a, b], [m_a, m_b]) => a.clone() * m_b.clone() + b.clone() * m_a.clone(),
+                    _ => pair.0[0].clone(),
+                })
+                .collect();
+        }
+        sums.pop().unwrap_or_else(DensePolynomial::zero)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SplitMix64;
+    use crate::{Matrix, PrimeField, Ratio};
+
+    type Q = Ratio<i64>;
+    type F7 = PrimeField<7>;
+
+    fn q(n: i64) -> Q {
+        Q::from(n)
+    }
+
+    fn poly_q(coefficients: &[i64]) -> DensePolynomial<Q> {
+        DensePolynomial::new(coefficients.iter().map(|&c| q(c)).collect())
+    }
+
+    fn poly_f7(coefficients: &[u64]) -> DensePolynomial<F7> {
+        DensePolynomial::new(coefficients.iter().map(|&c| F7::new(c)).collect())
+    }
+
+    fn poly_f2(coefficients: &[u64]) -> DensePolynomial<PrimeField<2>> {
+        DensePolynomial::new(coefficients.iter().map(|&c| PrimeField::new(c)).collect())
+    }
+
+    #[test]
+    fn inverses_modulo_an_irreducible_cubic() {
+        // x³ + x + 1 is irreducible over 𝔽₂, so 𝔽₂[x]/(x³ + x + 1) is the field with 8 elements
+        let modulus = poly_f2(&[1, 1, 0, 1]);
+        let x = poly_f2(&[0, 1]);
+        assert_eq!(x.inverse_mod(&modulus), Some(poly_f2(&[1, 0, 1])));
+        for bits in 1..8u64 {
+            let a = poly_f2(&[bits & 1, (bits >> 1) & 1, bits >> 2]);
+            let inverse = a
+                .inverse_mod(&modulus)
+                .expect("every non-zero residue is a unit");
+            assert!(inverse.degree() < 3);
+            assert_eq!((a * inverse).div_rem(&modulus).1, DensePolynomial::one());
+        }
+        assert_eq!(DensePolynomial::zero().inverse_mod(&modulus), None);
+        // Inputs are reduced first: x⁴ ≡ x² + x
+        let x4 = poly_f2(&[0, 0, 0, 0, 1]);
+        assert_eq!(
+            x4.inverse_mod(&modulus),
+            poly_f2(&[0, 1, 1]).inverse_mod(&modulus)
+        );
+    }
+
+    #[test]
+    fn non_units_modulo_a_reducible_modulus() {
+        // x³ + 1 = (x + 1)(x² + x + 1) over 𝔽₂
+        let modulus = poly_f2(&[1, 0, 0, 1]);
+        assert_eq!(poly_f2(&[1, 1]).inverse_mod(&modulus), None);
+        assert_eq!(poly_f2(&[1, 1, 1]).inverse_mod(&modulus), None);
+        assert_eq!(
+            poly_f2(&[0, 1]).inverse_mod(&modulus),
+            Some(poly_f2(&[0, 0, 1]))
+        );
+    }
+
+    #[test]
+    fn inverse_of_a_non_monic_gcd_is_normalized() {
+        // Over 𝔽₇ the gcd found by the Euclidean algorithm need not be monic
+        let modulus = poly_f7(&[1, 0, 3]);
+        let a = poly_f7(&[2, 5]);
+        let inverse = a.inverse_mod(&modulus).unwrap();
+        assert_eq!((a * inverse).div_rem(&modulus).1, DensePolynomial::one());
+    }
+
+    #[test]
+    fn difference_of_squares_divides_exactly() {
+        let (quotient, remainder) = poly_q(&[-1, 0, 1]).div_rem(&poly_q(&[-1, 1]));
+        assert_eq!(quotient, poly_q(&[1, 1]));
+        assert!(remainder.is_zero());
+        assert_eq!(poly_q(&[-1, 0, 1]) / poly_q(&[-1, 1]), poly_q(&[1, 1]));
+    }
+
+    #[test]
+    fn remainder_has_lower_degree_than_divisor() {
+        let a = poly_q(&[5, -3, 0, 2, 7]);
+        let b = poly_q(&[1, 0, 3]);
+        let (quotient, remainder) = a.div_rem(&b);
+        assert!(remainder.degree() < b.degree());
+        assert_eq!(quotient * b.clone() + remainder, a);
+        // A dividend of lower degree is its own remainder
+        let (quotient, remainder) = b.div_rem(&a);
+        assert!(quotient.is_zero());
+        assert_eq!(remainder, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by the zero polynomial")]
+    fn division_by_zero_panics() {
+        let _ = poly_q(&[1, 1]).div_rem(&DensePolynomial::zero());
+    }
+
+    #[test]
+    fn canonical_form_trims_trailing_zeros() {
+        let p = poly_q(&[1, 2, 0, 0]);
+        assert_eq!(p.coefficients().len(), 2);
+        assert_eq!(p.degree(), 1);
+        assert_eq!(p.leading_coefficient(), Some(&q(2)));
+        assert_eq!(poly_q(&[0, 0]), DensePolynomial::zero());
+        assert_eq!(DensePolynomial::<Q>::zero().leading_coefficient(), None);
+    }
+
+    #[test]
+    fn horner_evaluation() {
+        // 2x³ − x + 5 at x = 3 and at x = 2 in 𝔽₇
+        assert_eq!(poly_q(&[5, -1, 0, 2]).evaluate(&q(3)), q(56));
+        assert_eq!(poly_f7(&[5, 6, 0, 2]).evaluate(&F7::new(2)), F7::new(5));
+    }
+
+    fn random_poly_f7(rng: &mut SplitMix64, degree: usize) -> DensePolynomial<F7> {
+        DensePolynomial::new((0..=degree).map(|_| F7::new(rng.next_u64())).collect())
+    }
+
+    #[test]
+    fn composition_substitutes_the_inner_polynomial() {
+        let square = poly_q(&[0, 0, 1]);
+        assert_eq!(square.compose(&poly_q(&[1, 1])), poly_q(&[1, 2, 1]));
+        assert_eq!(poly_q(&[1, 1]).compose(&square), poly_q(&[1, 0, 1]));
+        assert_eq!(square.compose(&poly_q(&[0, 1])), square);
+        assert_eq!(square.compose(&poly_q(&[3])), poly_q(&[9]));
+        assert!(DensePolynomial::<Q>::zero().compose(&square).is_zero());
+    }
+
+    #[test]
+    fn composition_is_associative() {
+        let mut rng = SplitMix64::new(238);
+        for _ in 0..50 {
+            let degrees = [0, 1, 2].map(|_| rng.range_i64(0, 4) as usize);
+            let [f, g, h] = degrees.map(|degree| random_poly_f7(&mut rng, degree));
+            assert_eq!(f.compose(&g).compose(&h), f.compose(&g.compose(&h)));
+        }
+        let x = Ratio::new(2, 3);
+        let [f, g] = [poly_q(&[1, -2, 0, 3]), poly_q(&[-1, 0, 5])];
+        assert_eq!(f.compose(&g).evaluate(&x), f.evaluate(&g.evaluate(&x)));
+    }
+
+    #[test]
+    fn decomposition_recovers_the_factors() {
+        // (x² + 1)² is recovered with the inner x² + 1 shifted to x², so f = (x + 1)²
+        let p = poly_q(&[1, 0, 2, 0, 1]);
+        let (f, g) = p.decompose().unwrap();
+        assert_eq!(
+            (f.clone(), g.clone()),
+            (poly_q(&[1, 2, 1]), poly_q(&[0, 0, 1]))
+        );
+        assert_eq!(f.compose(&g), p);
+        // A non-monic composite of degree 6 = 3 · 2
+        let p = poly_q(&[2, 1, 0, -1]).compose(&poly_q(&[4, 3, 2]));
+        let (f, g) = p.decompose().unwrap();
+        assert_eq!((f.degree(), g.degree()), (3, 2));
+        assert_eq!(f.compose(&g), p);
+    }
+
+    #[test]
+    fn indecomposable_polynomials() {
+        assert_eq!(poly_q(&[1, 1, 0, 1]).decompose(), None);
+        assert_eq!(poly_q(&[0, 1, 0, 0, 1]).decompose(), None);
+        assert_eq!(poly_q(&[0, 0, 1]).decompose(), None);
+        assert_eq!(DensePolynomial::<Q>::zero().decompose(), None);
+        // x⁴ + x² = (x² + x) ∘ x² is tame over 𝔽₇ but wild over 𝔽₂, where 2 = 0
+        assert!(poly_f7(&[0, 0, 1, 0, 1]).decompose().is_some());
+        assert_eq!(poly_f2(&[0, 0, 1, 0, 1]).decompose(), None);
+    }
+
+    #[test]
+    fn modular_composition_matches_substitution() {
+        let mut rng = SplitMix64::new(205);
+        for _ in 0..200 {
+            let degrees = [0, 0, 0].map(|_| rng.range_i64(0, 12) as usize);
+            let f = random_poly_f7(&mut rng, degrees[0]);
+            let g = random_poly_f7(&mut rng, degrees[1] / 2);
+            let mut h = random_poly_f7(&mut rng, 1 + degrees[2] / 2);
+            if h.is_zero() {
+                h = poly_f7(&[1, 1]);
+            }
+            assert_eq!(f.modular_compose(&g, &h), f.compose(&g) % h);
+        }
+    }
+
+    #[test]
+    fn composing_with_x_reduces() {
+        let mut rng = SplitMix64::new(2050);
+        let x = poly_f7(&[0, 1]);
+        let h = poly_f7(&[3, 0, 2, 0, 0, 1]);
+        for _ in 0..50 {
+            let f = random_poly_f7(&mut rng, 9);
+            assert_eq!(f.modular_compose(&x, &h), f.clone() % h.clone());
+        }
+        let f = random_poly_f7(&mut rng, 4);
+        assert_eq!(f.modular_compose(&x, &h), f);
+    }
+
+    #[test]
+    fn modular_composition_edge_cases() {
+        let h = poly_f7(&[1, 0, 1]);
+        let g = poly_f7(&[2, 5]);
+        assert!(DensePolynomial::zero().modular_compose(&g, &h).is_zero());
+        assert_eq!(poly_f7(&[4]).modular_compose(&g, &h), poly_f7(&[4]));
+        assert!(poly_f7(&[1, 2, 3])
+            .modular_compose(&g, &poly_f7(&[3]))
+            .is_zero());
+    }
+
+    #[test]
+    fn coefficient_beyond_the_degree_is_zero() {
+        let p = poly_q(&[3, 0, -2]);
+        assert_eq!(p.coefficient(0), q(3));
+        assert_eq!(p.coefficient(1), q(0));
+        assert_eq!(p.coefficient(2), q(-2));
+        assert_eq!(p.coefficient(3), Q::zero());
+        assert_eq!(p.coefficient(100), Q::zero());
+        assert_eq!(DensePolynomial::<Q>::zero().coefficient(0), Q::zero());
+    }
+
+    #[test]
+    fn indexing_matches_stored_coefficients() {
+        let p = poly_f7(&[1, 5, 0, 3, 0, 0]);
+        assert_eq!(p.coefficients().len(), p.degree() + 1);
+        for (i, c) in p.coefficients().iter().enumerate() {
+            assert_eq!(&p[i], c);
+            assert_eq!(p.coefficient(i), *c);
+        }
+        assert_eq!(p[3], F7::new(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn indexing_beyond_the_degree_panics() {
+        let _ = poly_f7(&[1, 2])[2];
+    }
+
+    fn poly_f64(coefficients: &[f64]) -> DensePolynomial<f64> {
+        DensePolynomial::new(coefficients.to_vec())
+    }
+
+    #[test]
+    fn value_and_derivative_match_separate_evaluation() {
+        let p = poly_f64(&[1.5, -2.0, 0.0, 4.0, 0.25]);
+        let dp = p.derivative();
+        assert_eq!(dp, poly_f64(&[-2.0, 0.0, 12.0, 1.0]));
+        for x in [-2.0, -0.5, 0.0, 0.75, 3.0] {
+            let (value, derivative) = p.eval_with_derivative(&x);
+            assert!((value - p.evaluate(&x)).abs() < 1e-12);
+            assert!((derivative - dp.evaluate(&x)).abs() < 1e-12);
+        }
+        // At zero the pair is the constant and linear coefficients
+        assert_eq!(p.eval_with_derivative(&0.0), (1.5, -2.0));
+    }
+
+    #[test]
+    fn constant_polynomial_has_zero_derivative() {
+        let p = poly_f64(&[7.0]);
+        assert_eq!(p.eval_with_derivative(&3.0), (7.0, 0.0));
+        assert!(p.derivative().is_zero());
+        assert_eq!(
+            DensePolynomial::<f64>::zero().eval_with_derivative(&1.0),
+            (0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn gradient_matches_the_derivative_polynomial() {
+        let polynomials = [
+            DensePolynomial::new(vec![1.0, -3.0, 0.5, 2.0]),
+            DensePolynomial::new(vec![0.0, 0.0, 0.0, 0.0, 0.0, -1.25]),
+            DensePolynomial::new(vec![4.0]),
+            DensePolynomial::zero(),
+        ];
+        for p in &polynomials {
+            for x in [-2.0f64, -0.5, 0.0, 1.0, 3.75] {
+                let expected = p.derivative().evaluate(&x);
+                assert!((p.gradient_at(x) - expected).abs() < 1e-12, "{p:?} at {x}");
+            }
+        }
+        // 3x² − 2x + 1 has derivative 6x − 2
+        assert_eq!(
+            DensePolynomial::new(vec![1.0, -2.0, 3.0]).gradient_at(2.0),
+            10.0
+        );
+    }
+
+    #[test]
+    fn gradient_in_positive_characteristic() {
+        type F5 = PrimeField<5>;
+        // x⁵ + x and 2x induce the same function on 𝔽₅, but the x⁵ term has derivative 5x⁴ = 0
+        let p = DensePolynomial::new(vec![0, 1, 0, 0, 0, 1].into_iter().map(F5::new).collect());
+        let q = DensePolynomial::new(vec![F5::new(0), F5::new(2)]);
+        for x in (0..5).map(F5::new) {
+            assert_eq!(p.evaluate(&x), q.evaluate(&x));
+            assert_eq!(p.gradient_at(x), F5::new(1));
+            assert_eq!(p.gradient_at(x), p.derivative().evaluate(&x));
+            assert_eq!(q.gradient_at(x), F5::new(2));
+        }
+        // 3x⁷ keeps its term, since 7 is prime to 5: the derivative is 21x⁶ = x⁶
+        let mut coefficients = vec![F5::new(0); 8];
+        coefficients[7] = F5::new(3);
+        let r = DensePolynomial::new(coefficients);
+        for x in (0..5).map(F5::new) {
+            assert_eq!(r.gradient_at(x), pow(x, 6));
+            assert_eq!(r.gradient_at(x), r.derivative().evaluate(&x));
+        }
+    }
+
+    #[test]
+    fn derivative_in_positive_characteristic() {
+        // d/dx x⁷ = 7x⁶ = 0 in 𝔽₇
+        assert!(poly_f7(&[0, 0, 0, 0, 0, 0, 0, 1]).derivative().is_zero());
+        assert_eq!(poly_f7(&[1, 1, 1]).derivative(), poly_f7(&[1, 2]));
+    }
+
+    #[test]
+    fn newton_converges_to_square_root_of_two() {
+        let p = poly_f64(&[-2.0, 0.0, 1.0]);
+        let root = newton_root(&p, 1.0, 50, 1e-12).unwrap();
+        assert!((root - std::f64::consts::SQRT_2).abs() < 1e-12);
+        let root = newton_root(&p, -3.0, 50, 1e-12).unwrap();
+        assert!((root + std::f64::consts::SQRT_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn newton_fails_at_a_critical_point() {
+        // p′(0) = 0 for x² − 2
+        assert_eq!(
+            newton_root(&poly_f64(&[-2.0, 0.0, 1.0]), 0.0, 50, 1e-12),
+            None
+        );
+    }
+
+    #[test]
+    fn newton_respects_the_iteration_cap() {
+        let p = poly_f64(&[-2.0, 0.0, 1.0]);
+        assert_eq!(newton_root(&p, 100.0, 3, 1e-12), None);
+        assert_eq!(newton_root(&p, 1.0, 0, 1e-12), None);
+        // x² + 1 has no real root, so the iterates never settle
+        assert_eq!(
+            newton_root(&poly_f64(&[1.0, 0.0, 1.0]), 0.5, 200, 1e-12),
+            None
+        );
+    }
+
+    #[test]
+    fn sturm_counts_roots_of_x_squared_minus_one() {
+        let p = poly_f64(&[-1.0, 0.0, 1.0]);
+        assert_eq!(p.count_real_roots(-2.0, 2.0), 2);
+        assert_eq!(p.count_real_roots(0.0, 2.0), 1);
+        assert_eq!(p.count_real_roots(1.0, 2.0), 1);
+        assert_eq!(p.count_real_roots(-1.0, 1.0), 2);
+        assert_eq!(p.count_real_roots(2.0, 3.0), 0);
+        assert_eq!(p.count_real_roots(2.0, -2.0), 0);
+    }
+
+    #[test]
+    fn sturm_reports_no_roots_without_real_roots() {
+        let p = poly_f64(&[1.0, 0.0, 1.0]);
+        assert_eq!(p.count_real_roots(-100.0, 100.0), 0);
+        assert_eq!(poly_f64(&[3.0]).count_real_roots(-1.0, 1.0), 0);
+    }
+
+    #[test]
+    fn sturm_counts_repeated_roots_once() {
+        // (x − 1)²(x + 2) = x³ − 3x + 2
+        let p = poly_f64(&[2.0, -3.0, 0.0, 1.0]);
+        assert_eq!(p.squarefree_part().degree(), 2);
+        assert_eq!(p.count_real_roots(-3.0, 3.0), 2);
+        assert_eq!(p.count_real_roots(0.0, 3.0), 1);
+        // Exactly over ℚ: (x − 1)³(x − 2)²(x + 3) has three distinct roots
+        let linear = |r: i64| poly_q(&[-r, 1]);
+        let p = linear(1) * linear(1) * linear(1) * linear(2) * linear(2) * linear(-3);
+        assert_eq!(p.count_real_roots(q(-10), q(10)), 3);
+        assert_eq!(p.count_real_roots(q(1), q(2)), 2);
+        assert_eq!(p.count_real_roots(Q::new(3, 2), q(10)), 1);
+    }
+
+    #[test]
+    fn sturm_sequence_of_x_squared_minus_one() {
+        let sequence = poly_q(&[-1, 0, 1]).sturm_sequence();
+        assert_eq!(
+            sequence,
+            [poly_q(&[-1, 0, 1]), poly_q(&[0, 2]), poly_q(&[1])]
+        );
+        assert!(DensePolynomial::<Q>::zero().sturm_sequence().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "zero polynomial")]
+    fn counting_roots_of_zero_panics() {
+        let _ = DensePolynomial::<f64>::zero().count_real_roots(0.0, 1.0);
+    }
+
+    #[test]
+    fn ring_operations() {
+        let a = poly_q(&[1, 1]);
+        let b = poly_q(&[-1, 1]);
+        assert_eq!(a.clone() * b.clone(), poly_q(&[-1, 0, 1]));
+        assert_eq!(a.clone() + b.clone(), poly_q(&[0, 2]));
+        assert_eq!(a.clone() - a.clone(), DensePolynomial::zero());
+        assert_eq!(-b, poly_q(&[1, -1]));
+        assert_eq!(a.clone() * DensePolynomial::one(), a);
+    }
+
+    #[test]
+    fn streaming_evaluation_matches_dense_evaluation() {
+        let mut rng = SplitMix64::new(259);
+        for _ in 0..100 {
+            let coefficients: Vec<u64> =
+                (0..rng.range_i64(0, 12)).map(|_| rng.next_u64()).collect();
+            let p = poly_f7(&coefficients);
+            let x = F7::new(rng.next_u64());
+            let expected = p.evaluate(&x);
+            let low_to_high = coefficients.iter().map(|&c| F7::new(c));
+            assert_eq!(evaluate_stream(low_to_high.clone().rev(), x), expected);
+            assert_eq!(evaluate_stream_low_to_high(low_to_high, x), expected);
+        }
+    }
+
+    #[test]
+    fn empty_stream_evaluates_to_zero() {
+        assert_eq!(evaluate_stream(std::iter::empty(), q(3)), q(0));
+        assert_eq!(evaluate_stream_low_to_high(std::iter::empty(), q(3)), q(0));
+    }
+
+    #[test]
+    fn truncated_infinite_stream() {
+        // 1 + x + x² + ... + x⁹ at x = 2 is 2¹⁰ − 1.
+        let ones = || std::iter::repeat(1i64).take(10);
+        assert_eq!(evaluate_stream(ones(), 2), 1023);
+        assert_eq!(evaluate_stream_low_to_high(ones(), 2), 1023);
+        // The partial sums of Σ xⁿ / n! at x = 1 approach e.
+        let mut factorial = q(1);
+        let exp_terms = (0..).map(|n| {
+            if n > 0 {
+                factorial = factorial.clone() * q(n);
+            }
+            q(1) / factorial.clone()
+        });
+        let e = evaluate_stream_low_to_high(exp_terms.take(12), q(1));
+        assert!((*e.numer() as f64 / *e.denom() as f64 - std::f64::consts::E).abs() < 1e-8);
+    }
+
+    fn sparse_q(terms: &[(usize, i64)]) -> SparsePolynomial<Q> {
+        SparsePolynomial::new(terms.iter().map(|&(exponent, c)| (exponent, q(c))))
+    }
+
+    #[test]
+    fn sparse_product_of_high_degree_binomials() {
+        let product = sparse_q(&[(100, 1), (0, 1)]) * sparse_q(&[(100, 1), (0, -1)]);
+        assert_eq!(product, sparse_q(&[(200, 1), (0, -1)]));
+        assert_eq!(product.term_count(), 2);
+        assert_eq!(product.degree(), 200);
+
+        let dense = DensePolynomial::from(sparse_q(&[(100, 1), (0, 1)]))
+            * DensePolynomial::from(sparse_q(&[(100, 1), (0, -1)]));
+        assert_eq!(DensePolynomial::from(product.clone()), dense);
+        assert_eq!(SparsePolynomial::from(dense), product);
+    }
+
+    #[test]
+    fn sparse_zero_has_no_terms() {
+        let zero = SparsePolynomial::<Q>::zero();
+        assert_eq!(zero.term_count(), 0);
+        assert_eq!(zero.leading_term(), None);
+        assert_eq!(sparse_q(&[(3, 2), (3, -2)]), zero);
+        let p = sparse_q(&[(7, 1), (2, -3)]);
+        assert_eq!((p.clone() - p).term_count(), 0);
+        assert_eq!(SparsePolynomial::from(DensePolynomial::<Q>::zero()), zero);
+        assert!(DensePolynomial::from(zero).is_zero());
+    }
+
+    #[test]
+    fn sparse_and_dense_conversions_round_trip() {
+        let mut rng = SplitMix64::new(260);
+        for _ in 0..50 {
+            let coefficients: Vec<i64> = (0..rng.range_i64(0, 10))
+                .map(|_| {
+                    if rng.range_i64(0, 2) == 0 {
+                        0
+                    } else {
+                        rng.range_i64(-9, 9)
+                    }
+                })
+                .collect();
+            let dense = poly_q(&coefficients);
+            let sparse = SparsePolynomial::from(dense.clone());
+            assert_eq!(
+                sparse.term_count(),
+                coefficients.iter().filter(|&&c| c != 0).count()
+            );
+            assert_eq!(sparse.evaluate(&q(3)), dense.evaluate(&q(3)));
+            assert_eq!(DensePolynomial::from(sparse), dense);
+        }
+    }
+
+    #[test]
+    fn sparse_division_agrees_with_dense_division() {
+        let a = sparse_q(&[(9, 3), (4, -1), (1, 5), (0, 2)]);
+        let b = sparse_q(&[(3, 2), (0, -7)]);
+        let (quotient, remainder) = a.div_rem(&b);
+        assert!(remainder.degree() < 3);
+        assert_eq!(quotient.clone() * b.clone() + remainder.clone(), a);
+        let (dense_quotient, dense_remainder) =
+            DensePolynomial::from(a).div_rem(&DensePolynomial::from(b));
+        assert_eq!(DensePolynomial::from(quotient), dense_quotient);
+        assert_eq!(DensePolynomial::from(remainder), dense_remainder);
+    }
+
+    #[test]
+    fn sparse_division_terminates_over_floats() {
+        // 1 − (1/49) · 49 is not exactly zero in f64, so subtracting the leading term would
+        // leave it in place and loop forever.
+        let dividend = SparsePolynomial::monomial(1.0, 2);
+        let divisor = SparsePolynomial::new([(1, 49.0), (0, 3.0)]);
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+        assert_eq!(quotient.degree(), 1);
+        assert_eq!(remainder.degree(), 0);
+        for x in [-2.0f64, 0.5, 3.0] {
+            let reconstructed =
+                quotient.evaluate(&x) * divisor.evaluate(&x) + remainder.evaluate(&x);
+            assert!((reconstructed - x * x).abs() < 1e-12);
+        }
+    }
+
+    /// Folds partial quotients into the last convergent (h, k) ≈ a / b.
+    fn convergent<F: Field>(
+        quotients: &[DensePolynomial<F>],
+    ) -> (DensePolynomial<F>, DensePolynomial<F>) {
+        let (mut h, mut h_previous) = (DensePolynomial::one(), DensePolynomial::zero());
+        let (mut k, mut k_previous) = (DensePolynomial::zero(), DensePolynomial::one());
+        for q in quotients {
+            let next_h = q.clone() * h.clone() + h_previous;
+            let next_k = q.clone() * k.clone() + k_previous;
+            (h_previous, h) = (h, next_h);
+            (k_previous, k) = (k, next_k);
+        }
+        (h, k)
+    }
+
+    fn assert_close(a: &DensePolynomial<f64>, b: &DensePolynomial<f64>, tol: f64) {
+        let degree = a.degree().max(b.degree());
+        for i in 0..=degree {
+            assert!(
+                (a.coefficient(i) - b.coefficient(i)).abs() < tol,
+                "{a:?} != {b:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn partial_quotients_reconstruct_the_ratio() {
+        let a = poly_f64(&[1.0, -2.0, 0.5, 3.0, 1.0]);
+        let b = poly_f64(&[2.0, 1.0, -1.0]);
+        let quotients = a.continued_fraction(&b);
+        assert_eq!(quotients.len(), 3);
+        assert_eq!(quotients[0].degree(), 2);
+        let (h, k) = convergent(&quotients);
+        // a and b are coprime, so h / k is a / b up to a common constant.
+        assert_close(&(h * b), &(k * a), 1e-9);
+    }
+
+    #[test]
+    fn partial_quotients_reduce_to_lowest_terms() {
+        let common = poly_q(&[1, 1]);
+        let a = poly_q(&[3, 0, 1]) * common.clone();
+        let b = poly_q(&[-1, 2]) * common;
+        let (h, k) = convergent(&a.continued_fraction(&b));
+        assert_eq!(h.degree(), 2);
+        assert_eq!(k.degree(), 1);
+        assert_eq!(h * b, k * a.clone());
+        assert!(a.continued_fraction(&DensePolynomial::zero()).is_empty());
+    }
+
+    fn exp_series(terms: usize) -> DensePolynomial<f64> {
+        let mut coefficients = vec![1.0];
+        for n in 1..terms {
+            coefficients.push(coefficients[n - 1] / n as f64);
+        }
+        DensePolynomial::new(coefficients)
+    }
+
+    #[test]
+    fn pade_table_of_the_exponential() {
+        let exp = exp_series(8);
+        let table = [
+            ((1, 0), poly_f64(&[1.0, 1.0]), poly_f64(&[1.0])),
+            ((0, 1), poly_f64(&[1.0]), poly_f64(&[1.0, -1.0])),
+            ((1, 1), poly_f64(&[1.0, 0.5]), poly_f64(&[1.0, -0.5])),
+            (
+                (2, 1),
+                poly_f64(&[1.0, 2.0 / 3.0, 1.0 / 6.0]),
+                poly_f64(&[1.0, -1.0 / 3.0]),
+            ),
+            (
+                (1, 2),
+                poly_f64(&[1.0, 1.0 / 3.0]),
+                poly_f64(&[1.0, -2.0 / 3.0, 1.0 / 6.0]),
+            ),
+            (
+                (2, 2),
+                poly_f64(&[1.0, 0.5, 1.0 / 12.0]),
+                poly_f64(&[1.0, -0.5, 1.0 / 12.0]),
+            ),
+        ];
+        for ((m, n), numerator, denominator) in table {
+            let (p, q) = exp.pade_approximant(m, n).unwrap();
+            assert_close(&p, &numerator, 1e-12);
+            assert_close(&q, &denominator, 1e-12);
+        }
+    }
+
+    #[test]
+    fn pade_approximant_matches_the_series() {
+        // The [3/3] approximant of exp over ℚ agrees with the series through x⁶.
+        let mut coefficients = vec![q(1)];
+        for n in 1..7 {
+            coefficients.push(coefficients[n - 1].clone() / q(n as i64));
+        }
+        let exp = DensePolynomial::new(coefficients);
+        let (p, d) = exp.pade_approximant(3, 3).unwrap();
+        assert_eq!(d.coefficient(0), q(1));
+        assert_eq!(p, poly_q(&[120, 60, 12, 1]).scaled(&Q::new(1, 120)));
+        let agreement = (d * exp - p)
+            .coefficients()
+            .iter()
+            .take(7)
+            .all(Zero::is_zero);
+        assert!(agreement);
+    }
+
+    #[test]
+    fn pade_approximant_of_a_rational_function_is_exact() {
+        // 1 / (1 − x) = 1 + x + x² + ...
+        let geometric = poly_q(&[1, 1, 1, 1, 1]);
+        let (p, d) = geometric.pade_approximant(0, 1).unwrap();
+        assert_eq!((p, d), (poly_q(&[1]), poly_q(&[1, -1])));
+        // x has no [0/1] approximant with a denominator non-zero at the origin.
+        assert_eq!(poly_q(&[0, 1]).pade_approximant(0, 1), None);
+    }
+
+    #[test]
+    fn polynomial_from_roots() {
+        assert_eq!(
+            DensePolynomial::from_roots(&[1.0, 2.0]),
+            poly_f64(&[2.0, -3.0, 1.0])
+        );
+        assert_eq!(
+            DensePolynomial::<f64>::from_roots(&[]),
+            DensePolynomial::one()
+        );
+        let roots = [0.5f64, -3.0, 4.0, 1.25];
+        let p = DensePolynomial::from_roots(&roots);
+        assert_eq!(p.degree(), 4);
+        assert_eq!(p.leading_coefficient(), Some(&1.0));
+        for root in roots {
+            assert!(p.evaluate(&root).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn repeated_roots_give_repeated_factors() {
+        let p = DensePolynomial::from_roots(&[2.0, 2.0, -1.0]);
+        let linear = |r: f64| poly_f64(&[-r, 1.0]);
+        assert_eq!(p, linear(2.0) * linear(2.0) * linear(-1.0));
+        let (quotient, remainder) = p.div_rem(&(linear(2.0) * linear(2.0)));
+        assert!(remainder.is_zero());
+        assert_eq!(quotient, linear(-1.0));
+        // A double root is also a root of the derivative.
+        assert_eq!(p.derivative().evaluate(&2.0), 0.0);
+        assert_ne!(p.derivative().evaluate(&-1.0), 0.0);
+    }
+
+    #[test]
+    fn roots_over_a_prime_field() {
+        // Every element of 𝔽₇ is a root of x⁷ − x.
+        let all: Vec<F7> = (0..7).map(F7::new).collect();
+        assert_eq!(
+            DensePolynomial::from_roots(&all),
+            poly_f7(&[0, 6, 0, 0, 0, 0, 0, 1])
+        );
+    }
+
+    /// Returns x³ − tr(A) x² + c₁ x − det(A), the characteristic polynomial of a 3 × 3 matrix,
+    /// where c₁ is the sum of the principal 2 × 2 minors.
+    fn characteristic_polynomial(a: &Matrix<f64, 3, 3>) -> DensePolynomial<f64> {
+        let minor = |i: usize, j: usize| a[(i, i)] * a[(j, j)] - a[(i, j)] * a[(j, i)];
+        let trace = a[(0, 0)] + a[(1, 1)] + a[(2, 2)];
+        let determinant = a[(0, 0)] * minor(1, 2)
+            - a[(0, 1)] * (a[(1, 0)] * a[(2, 2)] - a[(1, 2)] * a[(2, 0)])
+            + a[(0, 2)] * (a[(1, 0)] * a[(2, 1)] - a[(1, 1)] * a[(2, 0)]);
+        poly_f64(&[
+            -determinant,
+            minor(0, 1) + minor(0, 2) + minor(1, 2),
+            -trace,
+            1.0,
+        ])
+    }
+
+    #[test]
+    fn matrices_satisfy_their_characteristic_polynomial() {
+        let matrices = [
+            Matrix::from_rows([[2.0, -1.0, 0.5], [3.0, 0.0, 1.0], [-4.0, 2.5, 1.0]]),
+            Matrix::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]]),
+            Matrix::from_rows([[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]),
+        ];
+        for a in matrices {
+            let p = characteristic_polynomial(&a);
+            assert!(p.evaluate_at(a).approx_eq(&Matrix::zero(), &1e-9), "{a:?}");
+        }
+    }
+
+    #[test]
+    fn evaluation_at_a_scalar_matrix() {
+        let p = poly_f64(&[1.0, 0.0, 1.0]);
+        for x in [-2.0, 0.0, 0.5, 3.0] {
+            assert_eq!(p.evaluate_at(x), x * x + 1.0);
+            assert_eq!(p.evaluate_at(x), p.evaluate(&x));
+            let scalar = Matrix::<f64, 2, 2>::from(x);
+            assert_eq!(p.evaluate_at(scalar), Matrix::from(x * x + 1.0));
+        }
+        // x² + 1 vanishes at the rotation by a quarter turn, which squares to −I.
+        let rotation = Matrix::from_rows([[0.0, -1.0], [1.0, 0.0]]);
+        assert_eq!(p.evaluate_at(rotation), Matrix::zero());
+    }
+
+    /// Builds the Lagrange form Σⱼ yⱼ · Πₖ≠ⱼ (x − xₖ) / (xⱼ − xₖ) as a coefficient polynomial.
+    fn lagrange_polynomial(nodes: &[f64], values: &[f64]) -> DensePolynomial<f64> {
+        let mut sum = DensePolynomial::zero();
+        for (j, (&xj, &yj)) in nodes.iter().zip(values).enumerate() {
+            let others: Vec<f64> = (0..nodes.len())
+                .filter(|&k| k != j)
+                .map(|k| nodes[k])
+                .collect();
+            let scale: f64 = others.iter().map(|&xk| xj - xk).product();
+            sum += DensePolynomial::from_roots(&others) * poly_f64(&[yj / scale]);
+        }
+        sum
+    }
+
+    fn chebyshev_nodes(n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|k| ((2 * k + 1) as f64 * std::f64::consts::PI / (2 * n) as f64).cos())
+            .collect()
+    }
+
+    #[test]
+    fn barycentric_evaluation_matches_the_lagrange_polynomial() {
+        let mut rng = SplitMix64::new(283);
+        let nodes = chebyshev_nodes(9);
+        let interpolator = BarycentricInterpolator::new(nodes.clone()).unwrap();
+        let values: Vec<f64> = (0..9).map(|_| rng.range_f64(-5.0, 5.0)).collect();
+        let lagrange = lagrange_polynomial(&nodes, &values);
+        for _ in 0..200 {
+            let x = rng.range_f64(-1.5, 1.5);
+            let expected = lagrange.evaluate(&x);
+            let actual = interpolator.evaluate(&values, &x);
+            assert!(
+                (actual - expected).abs() < 1e-8 * (1.0 + expected.abs()),
+                "x = {x}: {actual} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn barycentric_evaluation_reproduces_polynomials_exactly() {
+        type F = PrimeField<101>;
+        let p = DensePolynomial::new(vec![F::new(3), F::new(0), F::new(7), F::new(1)]);
+        let nodes: Vec<F> = [2, 5, 11, 40, 77].iter().map(|&x| F::new(x)).collect();
+        let values: Vec<F> = nodes.iter().map(|x| p.evaluate(x)).collect();
+        let interpolator = BarycentricInterpolator::new(nodes).unwrap();
+        for x in 0..101 {
+            let x = F::new(x);
+            assert_eq!(interpolator.evaluate(&values, &x), p.evaluate(&x));
+        }
+    }
+
+    #[test]
+    fn barycentric_evaluation_at_the_nodes_returns_the_values() {
+        let nodes = vec![-1.0, 0.0, 0.5, 2.0];
+        let values = [4.0, -3.0, 0.125, 9.5];
+        let interpolator = BarycentricInterpolator::new(nodes.clone()).unwrap();
+        for (x, y) in nodes.iter().zip(values) {
+            assert_eq!(interpolator.evaluate(&values, x), y);
+        }
+    }
+
+    #[test]
+    fn barycentric_interpolator_is_reusable_across_value_vectors() {
+        let nodes = chebyshev_nodes(6);
+        let interpolator = BarycentricInterpolator::new(nodes.clone()).unwrap();
+        let weights = interpolator.weights().to_vec();
+        let polynomials = [
+            poly_f64(&[1.0]),
+            poly_f64(&[0.0, 1.0]),
+            poly_f64(&[2.0, -1.0, 0.0, 3.0]),
+            poly_f64(&[0.5, 0.0, -4.0, 0.0, 0.0, 1.0]),
+        ];
+        for p in &polynomials {
+            let values: Vec<f64> = nodes.iter().map(|x| p.evaluate(x)).collect();
+            for x in [-0.9, -0.3, 0.1, 0.65, 1.2] {
+                let expected = p.evaluate(&x);
+                assert!((interpolator.evaluate(&values, &x) - expected).abs() < 1e-9);
+            }
+        }
+        assert_eq!(interpolator.weights(), weights.as_slice());
+    }
+
+    #[test]
+    fn barycentric_interpolator_rejects_repeated_nodes() {
+        assert!(BarycentricInterpolator::new(vec![1.0, 2.0, 1.0]).is_none());
+        let empty = BarycentricInterpolator::<f64>::new(Vec::new()).unwrap();
+        assert_eq!(empty.evaluate(&[], &3.0), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "one value per interpolation node")]
+    fn barycentric_evaluation_with_mismatched_values_panics() {
+        let interpolator = BarycentricInterpolator::new(vec![0.0, 1.0]).unwrap();
+        interpolator.evaluate(&[1.0], &0.5);
+    }
+
+    /// Asserts that the multiset of roots matches expected within tol, by greedy matching.
+    fn assert_roots(roots: &[Complex<f64>], expected: &[Complex<f64>], tol: f64) {
+        assert_eq!(roots.len(), expected.len(), "{roots:?}");
+        let mut unmatched = expected.to_vec();
+        for root in roots {
+            let position = unmatched
+                .iter()
+                .position(|e| modulus(&(*e - *root)) < tol)
+                .unwrap_or_else(|| panic!("unexpected root {root:?} among {roots:?}"));
+            unmatched.swap_remove(position);
+        }
+    }
+
+    fn c(re: f64, im: f64) -> Complex<f64> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn complex_roots_of_x_squared_plus_one() {
+        let roots = poly_f64(&[1.0, 0.0, 1.0]).complex_roots();
+        assert_roots(&roots, &[c(0.0, 1.0), c(0.0, -1.0)], 1e-12);
+    }
+
+    #[test]
+    fn complex_roots_of_unity() {
+        let roots = poly_f64(&[-1.0, 0.0, 0.0, 1.0]).complex_roots();
+        let half_sqrt_3 = 3f64.sqrt() / 2.0;
+        let expected = [c(1.0, 0.0), c(-0.5, half_sqrt_3), c(-0.5, -half_sqrt_3)];
+        assert_roots(&roots, &expected, 1e-12);
+        for n in [5, 8, 13] {
+            let mut coefficients = vec![0.0; n + 1];
+            coefficients[0] = -1.0;
+            coefficients[n] = 1.0;
+            let roots = poly_f64(&coefficients).complex_roots();
+            let expected: Vec<Complex<f64>> = (0..n)
+                .map(|k| {
+                    let angle = 2.0 * std::f64::consts::PI * k as f64 / n as f64;
+                    c(angle.cos(), angle.sin())
+                })
+                .collect();
+            assert_roots(&roots, &expected, 1e-10);
+        }
+    }
+
+    #[test]
+    fn complex_roots_count_multiplicity() {
+        // (x − 1)² (x + 2)³ (x − 3)
+        let p = DensePolynomial::from_roots(&[1.0, 1.0, -2.0, -2.0, -2.0, 3.0]);
+        let roots = p.complex_roots();
+        assert_eq!(roots.len(), p.degree());
+        // A root of multiplicity m is perturbed by about ε^(1/m).
+        let near = |target: f64, tol: f64| {
+            roots
+                .iter()
+                .filter(|r| modulus(&(**r - c(target, 0.0))) < tol)
+                .count()
+        };
+        assert_eq!(near(1.0, 1e-6), 2);
+        assert_eq!(near(-2.0, 1e-4), 3);
+        assert_eq!(near(3.0, 1e-9), 1);
+        // Factors of x give exact zero roots.
+        let roots = poly_f64(&[0.0, 0.0, 2.0, 1.0]).complex_roots();
+        assert_roots(&roots, &[c(0.0, 0.0), c(0.0, 0.0), c(-2.0, 0.0)], 1e-12);
+        assert_eq!(roots.iter().filter(|r| r.is_zero()).count(), 2);
+    }
+
+    #[test]
+    fn complex_roots_annihilate_random_polynomials() {
+        let mut rng = SplitMix64::new(213);
+        for degree in 1..=12 {
+            let coefficients: Vec<f64> = (0..=degree).map(|_| rng.range_f64(-5.0, 5.0)).collect();
+            let p = poly_f64(&coefficients);
+            let roots = p.complex_roots();
+            assert_eq!(roots.len(), degree);
+            let complex = DensePolynomial::new(coefficients.iter().map(|&a| c(a, 0.0)).collect());
+            for root in &roots {
+                let value = complex.evaluate(root);
+                let size: f64 = coefficients
+                    .iter()
+                    .enumerate()
+                    .map(|(i, a)| a.abs() * modulus(root).powi(i as i32))
+                    .sum();
+                assert!(modulus(&value) < 1e-10 * size, "p({root:?}) = {value:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn complex_roots_of_a_complex_polynomial() {
+        // (x − i)(x − 2 + i)(x + 1)
+        let p = DensePolynomial::from_roots(&[c(0.0, 1.0), c(2.0, -1.0), c(-1.0, 0.0)]);
+        assert_roots(
+            &p.complex_roots(),
+            &[c(0.0, 1.0), c(2.0, -1.0), c(-1.0, 0.0)],
+            1e-12,
+        );
+    }
+
+    #[test]
+    fn constants_have_no_complex_roots() {
+        assert!(poly_f64(&[]).complex_roots().is_empty());
+        assert!(poly_f64(&[4.0]).complex_roots().is_empty());
+        assert_roots(
+            &poly_f64(&[3.0, 2.0]).complex_roots(),
+            &[c(-1.5, 0.0)],
+            1e-15,
+        );
+    }
+
+    type Fp = PrimeField<1_000_000_007>;
+
+    fn random_poly(rng: &mut SplitMix64, len: usize) -> DensePolynomial<Fp> {
+        DensePolynomial::new((0..len).map(|_| Fp::new(rng.next_u64())).collect())
+    }
+
+    fn distinct_points(rng: &mut SplitMix64, n: usize) -> Vec<Fp> {
+        let mut points: Vec<Fp> = Vec::with_capacity(n);
+        while points.len() < n {
+            let x = Fp::new(rng.next_u64());
+            if !points.contains(&x) {
+                points.push(x);
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn fast_evaluation_matches_horner() {
+        let mut rng = SplitMix64::new(268);
+        for n in [1, 2, 3, 37, 64, 100] {
+            let ops = FastPolyOps::new(distinct_points(&mut rng, n)).unwrap();
+            for len in [0, 1, n / 2, n, 3 * n] {
+                let p = random_poly(&mut rng, len);
+                let naive: Vec<Fp> = ops.points().iter().map(|x| p.evaluate(x)).collect();
+                assert_eq!(ops.evaluate(&p), naive, "n = {n}, len = {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn fast_interpolation_matches_lagrange() {
+        let mut rng = SplitMix64::new(2680);
+        for n in [1, 5, 37, 64] {
+            let points = distinct_points(&mut rng, n);
+            let ops = FastPolyOps::new(points.clone()).unwrap();
+            let values: Vec<Fp> = (0..n).map(|_| Fp::new(rng.next_u64())).collect();
+            let p = ops.interpolate(&values);
+            assert!(p.degree() < n);
+            // The Lagrange form Σᵢ yᵢ ∏ⱼ≠ᵢ (x − xⱼ) / (xᵢ − xⱼ), built term by term.
+            let mut lagrange = DensePolynomial::zero();
+            for (i, (xi, yi)) in points.iter().zip(&values).enumerate() {
+                let others: Vec<Fp> = (0..n).filter(|&j| j != i).map(|j| points[j]).collect();
+                let basis = DensePolynomial::from_roots(&others);
+                let scale = *yi / basis.evaluate(xi);
+                lagrange += basis * DensePolynomial::new(vec![scale]);
+            }
+            assert_eq!(p, lagrange);
+        }
+    }
+
+    #[test]
+    fn fast_interpolation_inverts_evaluation() {
+        let mut rng = SplitMix64::new(2681);
+        let ops = FastPolyOps::new(distinct_points(&mut rng, 50)).unwrap();
+        // One tree serves every polynomial and value vector.
+        for len in 0..=50 {
+            let p = random_poly(&mut rng, len);
+            assert_eq!(ops.interpolate(&ops.evaluate(&p)), p);
+        }
+        for _ in 0..10 {
+            let values: Vec<Fp> = (0..50).map(|_| Fp::new(rng.next_u64())).collect();
+            assert_eq!(ops.evaluate(&ops.interpolate(&values)), values);
+        }
+    }
+
+    #[test]
+    fn karatsuba_matches_the_schoolbook_product() {
+        let mut rng = SplitMix64::new(2682);
+        for (m, n) in [
+            (1, 1),
+            (2, 3),
+            (7, 7),
+            (33, 33),
+            (40, 97),
+            (100, 64),
+            (129, 5),
+        ] {
+            let (a, b) = (random_poly(&mut rng, m), random_poly(&mut rng, n));
+            let naive = DensePolynomial::new(schoolbook(a.coefficients(), b.coefficients()));
+            for threshold in [0, 1, 2, 5, KARATSUBA_THRESHOLD] {
+                assert_eq!(
+                    a.karatsuba_mul(&b, threshold),
+                    naive,
+                    "{m} × {n}, {threshold}"
+                );
+            }
+            assert_eq!(a * b, naive);
+        }
+        let p = random_poly(&mut rng, 10);
+        assert!(p.karatsuba_mul(&DensePolynomial::zero(), 1).is_zero());
+    }
+
+    #[test]
+    fn newton_division_matches_long_division() {
+        let mut rng = SplitMix64::new(2683);
+        for (m, n) in [(80, 40), (200, 34), (300, 150), (65, 64)] {
+            let (a, b) = (random_poly(&mut rng, m), random_poly(&mut rng, n));
+            let (q, r) = a.div_rem(&b);
+            assert!(r.degree() < b.degree());
+            assert_eq!(q.clone() * b.clone() + r.clone(), a);
+            assert_eq!(a.newton_div_rem(&b, m - n + 1), (q, r));
+        }
+        let f = random_poly(&mut rng, 50) + DensePolynomial::one();
+        let inverse = f.inverse_series(70).unwrap();
+        assert_eq!((f * inverse).truncated(70), DensePolynomial::one());
+        assert!(poly_f7(&[0, 1]).inverse_series(4).is_none());
+    }
+
+    #[test]
+    fn fast_poly_ops_scale_to_thousands_of_points() {
+        let mut rng = SplitMix64::new(2684);
+        let n = 2048;
+        let ops = FastPolyOps::new(distinct_points(&mut rng, n)).unwrap();
+        let p = random_poly(&mut rng, n);
+        let values = ops.evaluate(&p);
+        for (x, y) in ops.points().iter().zip(&values).step_by(97) {
+            assert_eq!(p.evaluate(x), *y);
+        }
+        assert_eq!(ops.interpolate(&values), p);
+    }
+
+    #[test]
+    fn fast_poly_ops_on_degenerate_point_sets() {
+        assert!(FastPolyOps::new(vec![Fp::new(3), Fp::new(5), Fp::new(3)]).is_none());
+        let empty = FastPolyOps::<Fp>::new(Vec::new()).unwrap();
+        assert!(empty
+            .evaluate(&DensePolynomial::new(vec![Fp::new(2)]))
+            .is_empty());
+        assert!(empty.interpolate(&[]).is_zero());
+        let single = FastPolyOps::new(vec![Fp::new(7)]).unwrap();
+        assert_eq!(
+            single.interpolate(&[Fp::new(4)]),
+            DensePolynomial::new(vec![Fp::new(4)])
+        );
+    }
+}
@@ -0,0 +1,505 @@
+use crate::discrete_log::is_generator;
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CanonicalAssociate, CommutativeAddition,
+    CommutativeMultiplication, Distributive, FiniteField, NaiveReduction, ReductionStrategy,
+};
+use num_traits::{Euclid, Inv, One, Zero};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Represents the prime field 𝔽ₚ = ℤ/pℤ of integers modulo a prime P.
+///
+/// # Mathematical Definition
+/// Elements are the residues {0, 1, ..., P − 1}, with addition and multiplication taken
+/// modulo P. Because P is prime every non-zero residue a has an inverse, found by the extended
+/// Euclidean algorithm from the Bézout identity a · x + P · y = 1.
+///
+/// # Properties
+/// - The characteristic and the order are both P
+/// - Values are always stored reduced into [0, P); products are widened to u128 so no
+///   intermediate result overflows for any P < 2⁶⁴
+/// - Primality of P is checked at compile time, when a `PrimeField<P>` is first constructed
+/// - Products are reduced by the strategy R, hardware division by default; every strategy gives
+///   the same field, so the choice only affects speed. `MontgomeryReduction` needs an odd
+///   modulus, so `PrimeField<2, MontgomeryReduction>` fails to compile
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PrimeField<const P: u64, R: ReductionStrategy<P> = NaiveReduction> {
+    value: u64,
+    reduction: PhantomData<R>,
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> PrimeField<P, R> {
+    const ASSERT_PRIME: () = assert!(is_prime(P), "PrimeField modulus must be prime");
+
+    /// Creates the residue of value modulo P.
+    pub fn new(value: u64) -> Self {
+        let () = Self::ASSERT_PRIME;
+        Self::reduced(R::INSTANCE.reduce(value as u128))
+    }
+
+    /// Wraps a value already in [0, P).
+    fn reduced(value: u64) -> Self {
+        Self {
+            value,
+            reduction: PhantomData,
+        }
+    }
+
+    /// Returns the canonical representative in [0, P).
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Returns the center lift, the representative in (−P/2, P/2\].
+    pub fn center_lift(&self) -> i64 {
+        if self.value > P / 2 {
+            -((P - self.value) as i64)
+        } else {
+            self.value as i64
+        }
+    }
+
+    /// Returns the center lift as a float, the inverse of `round_into` for integers of
+    /// magnitude below P/2.
+    ///
+    /// Lifts of magnitude above 2⁵³ are rounded to the nearest float.
+    pub fn to_f64(&self) -> f64 {
+        self.center_lift() as f64
+    }
+}
+
+/// Rounds x to the nearest integer and reduces it modulo P.
+///
+/// Half-way cases round away from zero, as `f64::round` does, so 2.5 becomes 3 and −2.5 becomes
+/// −3 before reduction. Values beyond the range of i128 saturate to its bounds and NaN maps to
+/// zero, following the `as` conversion.
+pub fn round_into<const P: u64>(x: f64) -> PrimeField<P> {
+    let n = (x.round() as i128).rem_euclid(P as i128);
+    PrimeField::new(n as u64)
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> From<u64> for PrimeField<P, R> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> Default for PrimeField<P, R> {
+    /// Returns zero, through `new` so that the primality check also applies here.
+    ///
+    /// ```compile_fail
+    /// let _ = noether::PrimeField::<4>::default();
+    /// ```
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> fmt::Display for PrimeField<P, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> Add for PrimeField<P, R> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let (sum, overflow) = self.value.overflowing_add(other.value);
+        Self::reduced(if overflow || sum >= P {
+            sum.wrapping_sub(P)
+        } else {
+            sum
+        })
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> AddAssign for PrimeField<P, R> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> Neg for PrimeField<P, R> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::reduced(if self.value == 0 { 0 } else { P - self.value })
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> Sub for PrimeField<P, R> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> SubAssign for PrimeField<P, R> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> Mul for PrimeField<P, R> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::reduced(R::INSTANCE.reduce(self.value as u128 * other.value as u128))
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> MulAssign for PrimeField<P, R> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> Inv for PrimeField<P, R> {
+    type Output = Self;
+
+    /// Returns the multiplicative inverse by the extended Euclidean algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is zero.
+    fn inv(self) -> Self {
+        assert!(self.value != 0, "Inverse of zero in a prime field");
+        let (mut r0, mut r1) = (P as i128, self.value as i128);
+        let (mut t0, mut t1) = (0i128, 1i128);
+        while r1 != 0 {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (t0, t1) = (t1, t0 - q * t1);
+        }
+        Self::reduced(t0.rem_euclid(P as i128) as u64)
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> Div for PrimeField<P, R> {
+    type Output = Self;
+
+    /// Multiplies by the inverse of the divisor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.inv()
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> DivAssign for PrimeField<P, R> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> Rem for PrimeField<P, R> {
+    type Output = Self;
+
+    /// Returns zero, as division in a field is exact.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    fn rem(self, other: Self) -> Self {
+        assert!(other.value != 0, "Remainder by zero in a prime field");
+        Self::zero()
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> Euclid for PrimeField<P, R> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        *self / *v
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        *self % *v
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> Zero for PrimeField<P, R> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> One for PrimeField<P, R> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> CommutativeAddition for PrimeField<P, R> {}
+impl<const P: u64, R: ReductionStrategy<P>> AssociativeAddition for PrimeField<P, R> {}
+impl<const P: u64, R: ReductionStrategy<P>> CommutativeMultiplication for PrimeField<P, R> {}
+impl<const P: u64, R: ReductionStrategy<P>> AssociativeMultiplication for PrimeField<P, R> {}
+impl<const P: u64, R: ReductionStrategy<P>> Distributive for PrimeField<P, R> {}
+impl<const P: u64, R: ReductionStrategy<P>> CanonicalAssociate for PrimeField<P, R> {}
+
+impl<const P: u64, R: ReductionStrategy<P>> FiniteField for PrimeField<P, R> {
+    fn characteristic() -> u64 {
+        P
+    }
+
+    fn order() -> u64 {
+        P
+    }
+
+    /// Returns the least primitive root modulo P, testing candidates against the prime factors
+    /// of P − 1, which are found by trial division in O(√P).
+    fn primitive_element() -> Self {
+        (1..P)
+            .map(Self::new)
+            .find(|g| is_generator(g, P - 1))
+            .expect("the multiplicative group of a prime field is cyclic")
+    }
+}
+
+/// Defines a type alias for a prime field, checking at compile time that the modulus is prime.
+///
+/// `field_from_modulus!(Fp, p)` expands to `type Fp = PrimeField<p>;` together with a constant
+/// assertion, so a composite modulus is rejected where the alias is defined rather than where
+/// the first element is constructed. Attributes, a visibility and a reduction strategy may be
+/// given, as in `field_from_modulus!(pub Fp, p, MontgomeryReduction)`.
+///
+/// ```
+/// noether::field_from_modulus!(F7, 7);
+///
+/// assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+/// ```
+///
+/// ```compile_fail
+/// noether::field_from_modulus!(Z9, 9);
+/// ```
+#[macro_export]
+macro_rules! field_from_modulus {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $p:expr) => {
+        $crate::field_from_modulus!($(#[$meta])* $vis $name, $p, $crate::NaiveReduction);
+    };
+    ($(#[$meta:meta])* $vis:vis $name:ident, $p:expr, $reduction:ty) => {
+        $(#[$meta])*
+        $vis type $name = $crate::PrimeField<{ $p }, $reduction>;
+
+        const _: () = assert!(
+            $crate::prime_field::is_prime($p),
+            "field_from_modulus! modulus must be prime"
+        );
+    };
+}
+
+/// Returns whether n is prime, by deterministic Miller–Rabin for all of u64.
+pub const fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    // The first twelve primes are a deterministic witness set for every n < 2⁶⁴.
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        if n % WITNESSES[i] == 0 {
+            return n == WITNESSES[i];
+        }
+        i += 1;
+    }
+    let mut d = n - 1;
+    let mut s = 0;
+    while d % 2 == 0 {
+        d /= 2;
+        s += 1;
+    }
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        let mut x = pow_mod(WITNESSES[i], d, n);
+        if x != 1 && x != n - 1 {
+            let mut r = 1;
+            while r < s && x != n - 1 {
+                x = mul_mod(x, x, n);
+                r += 1;
+            }
+            if x != n - 1 {
+                return false;
+            }
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+const fn pow_mod(base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1;
+    let mut square = base % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, square, m);
+        }
+        square = mul_mod(square, square, m);
+        exp >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::check_field_laws;
+
+    type F7 = PrimeField<7>;
+    crate::field_from_modulus!(Fp7, 7);
+    crate::field_from_modulus!(FpBig, BIG, crate::MontgomeryReduction);
+    const BIG: u64 = 18_446_744_073_709_551_557; // The largest prime below 2⁶⁴
+
+    #[test]
+    fn field_laws_hold_on_every_triple() {
+        for a in 0..7 {
+            for b in 0..7 {
+                for c in 1..7 {
+                    assert!(check_field_laws(F7::new(c), F7::new(a), F7::new(b)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn values_stay_reduced() {
+        assert_eq!(F7::new(23).value(), 2);
+        assert_eq!((F7::new(5) + F7::new(4)).value(), 2);
+        assert_eq!((F7::new(2) - F7::new(5)).value(), 4);
+        assert_eq!((-F7::new(3)).value(), 4);
+        assert_eq!((F7::new(6) * F7::new(6)).value(), 1);
+    }
+
+    #[test]
+    fn inverse_by_extended_euclid() {
+        for a in 1..7 {
+            assert_eq!((F7::new(a) * F7::new(a).inv()).value(), 1);
+        }
+        assert_eq!(F7::new(3).inv().value(), 5);
+        assert_eq!((F7::new(3) / F7::new(5)).value(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Inverse of zero")]
+    fn inverse_of_zero_panics() {
+        let _ = F7::zero().inv();
+    }
+
+    #[test]
+    fn large_modulus_does_not_overflow() {
+        type Big = PrimeField<BIG>;
+        let a = Big::new(BIG - 1);
+        assert_eq!((a + a).value(), BIG - 2);
+        assert_eq!((a * a).value(), 1);
+        assert_eq!((a.inv() * a).value(), 1);
+    }
+
+    #[test]
+    fn default_is_zero() {
+        assert!(F7::default().is_zero());
+        assert_eq!(F7::default(), F7::new(7));
+    }
+
+    #[test]
+    fn rounding_into_the_field() {
+        assert_eq!(round_into::<7>(3.6), F7::new(4));
+        assert_eq!(round_into::<7>(-0.4), F7::new(0));
+        assert_eq!(round_into::<7>(-1.2), F7::new(6));
+        assert_eq!(round_into::<7>(2.5), F7::new(3));
+        assert_eq!(round_into::<7>(-2.5), F7::new(4));
+        assert_eq!(round_into::<7>(1e40), F7::new((i128::MAX % 7) as u64));
+        assert_eq!(round_into::<7>(f64::NAN), F7::new(0));
+    }
+
+    #[test]
+    fn center_lift_round_trips_small_integers() {
+        assert_eq!(F7::new(3).center_lift(), 3);
+        assert_eq!(F7::new(4).center_lift(), -3);
+        assert_eq!(PrimeField::<2>::new(1).center_lift(), 1);
+        for n in -3..=3 {
+            assert_eq!(round_into::<7>(n as f64).to_f64(), n as f64);
+        }
+        for n in -1000..=1000 {
+            assert_eq!(round_into::<BIG>(n as f64).to_f64(), n as f64);
+        }
+        assert_eq!(PrimeField::<BIG>::new(BIG - 1).center_lift(), -1);
+        assert_eq!(
+            PrimeField::<BIG>::new(BIG / 2).center_lift(),
+            (BIG / 2) as i64
+        );
+    }
+
+    #[test]
+    fn characteristic_and_order() {
+        assert_eq!(F7::characteristic(), 7);
+        assert_eq!(F7::order(), 7);
+    }
+
+    #[test]
+    fn primality() {
+        let primes: Vec<u64> = (0..30).filter(|&n| is_prime(n)).collect();
+        assert_eq!(primes, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert!(is_prime(BIG));
+        assert!(!is_prime(3_215_031_751)); // A strong pseudoprime to bases 2, 3, 5 and 7
+    }
+
+    #[test]
+    fn field_from_modulus_defines_a_prime_field_alias() {
+        assert_eq!(Fp7::new(3) + Fp7::new(5), F7::new(1));
+        assert_eq!(Fp7::new(3).inv() * Fp7::new(3), Fp7::one());
+        assert_eq!(Fp7::characteristic(), 7);
+        let x = FpBig::new(BIG - 1);
+        assert_eq!(x * x, FpBig::one());
+        assert_eq!(
+            FpBig::new(12345).value(),
+            PrimeField::<BIG>::new(12345).value()
+        );
+    }
+
+    #[test]
+    fn primitive_elements_generate_the_multiplicative_group() {
+        fn order<const P: u64>(g: PrimeField<P>) -> u64 {
+            let mut power = g;
+            (1..)
+                .find(|_| {
+                    let done = power.is_one();
+                    power *= g;
+                    done
+                })
+                .unwrap()
+        }
+        assert_eq!(PrimeField::<2>::primitive_element(), PrimeField::new(1));
+        assert_eq!(F7::primitive_element(), F7::new(3));
+        assert_eq!(order(F7::primitive_element()), 6);
+        assert_eq!(PrimeField::<41>::primitive_element(), PrimeField::new(6));
+        assert_eq!(order(PrimeField::<41>::primitive_element()), 40);
+        assert_eq!(order(PrimeField::<257>::primitive_element()), 256);
+    }
+
+    #[test]
+    fn primality_is_decided_at_compile_time() {
+        const PRIMES: [bool; 6] = [
+            is_prime(0),
+            is_prime(1),
+            is_prime(2),
+            is_prime(91),
+            is_prime(97),
+            is_prime(BIG),
+        ];
+        assert_eq!(PRIMES, [false, false, true, false, true, true]);
+        // 3215031751 = 151 · 751 · 28351 is a strong pseudoprime to the bases 2, 3, 5 and 7.
+        assert!(!is_prime(3_215_031_751));
+        assert!(!is_prime(BIG - 2));
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CanonicalAssociate, ClosedDivEuclid,
+    CommutativeAddition, CommutativeMultiplication, Distributive, RealField,
+};
+use num_traits::Euclid;
+
+// Integers
+// Note: Signed integers are commutative rings and, through the blanket impls, Euclidean domains.
+// Unsigned integers lack additive inverses and are commutative semirings. The laws hold exactly
+// only while results stay in range, as overflow panics in debug builds and wraps in release.
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl CommutativeAddition for $t {}
+            impl AssociativeAddition for $t {}
+            impl CommutativeMultiplication for $t {}
+            impl AssociativeMultiplication for $t {}
+            impl Distributive for $t {}
+
+            impl ClosedDivEuclid for $t {
+                fn div_euclid(self, rhs: Self) -> Self {
+                    Euclid::div_euclid(&self, &rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize);
+impl_integer!(u8, u16, u32, u64, u128, usize);
+
+// Signed integers and floats take the default normalizing unit, which for integers is the sign
+macro_rules! impl_canonical_associate {
+    ($($t:ty),*) => {
+        $(
+            impl CanonicalAssociate for $t {}
+        )*
+    };
+}
+
+impl_canonical_associate!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+// Floating point
+// Note: f32 and f64 are treated as real fields, but the field axioms hold only approximately:
+// addition and multiplication round, so associativity and distributivity can fail in the last
+// bits. The order is total except for NaN, which compares unordered with everything.
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl CommutativeAddition for $t {}
+            impl AssociativeAddition for $t {}
+            impl CommutativeMultiplication for $t {}
+            impl AssociativeMultiplication for $t {}
+            impl Distributive for $t {}
+
+            impl ClosedDivEuclid for $t {
+                fn div_euclid(self, rhs: Self) -> Self {
+                    Euclid::div_euclid(&self, &rhs)
+                }
+            }
+
+            // The bounds come from error-free transformations: the rounding error of a sum is
+            // recovered exactly by TwoSum, and that of a product or quotient by a fused
+            // multiply-add. Its sign says which neighbour of the rounded result to widen to.
+            impl RealField for $t {
+                fn add_bounds(&self, other: &Self) -> (Self, Self) {
+                    let (a, b) = (*self, *other);
+                    let sum = a + b;
+                    if !a.is_finite() || !b.is_finite() {
+                        return (sum, sum);
+                    }
+                    let b_virtual = sum - a;
+                    let error = (a - (sum - b_virtual)) + (b - b_virtual);
+                    sum.enclose(error)
+                }
+
+                fn mul_bounds(&self, other: &Self) -> (Self, Self) {
+                    let (a, b) = (*self, *other);
+                    let product = a * b;
+                    if !a.is_finite() || !b.is_finite() {
+                        return (product, product);
+                    }
+                    if product.abs() < <$t>::MIN_POSITIVE && a != 0.0 && b != 0.0 {
+                        // Below the normal range the error itself may round away
+                        return (product.step_down(), product.step_up());
+                    }
+                    product.enclose(a.mul_add(b, -product))
+                }
+
+                fn div_bounds(&self, other: &Self) -> (Self, Self) {
+                    let (a, b) = (*self, *other);
+                    let quotient = a / b;
+                    if !a.is_finite() || !b.is_finite() || b == 0.0 {
+                        return (quotient, quotient);
+                    }
+                    if quotient.abs() < <$t>::MIN_POSITIVE && a != 0.0 {
+                        return (quotient.step_down(), quotient.step_up());
+                    }
+                    // a − q · b has the sign of a / b − q when b is positive
+                    let residual = (-quotient).mul_add(b, a);
+                    quotient.enclose(if b > 0.0 { residual } else { -residual })
+                }
+            }
+
+            impl DirectedRounding for $t {
+                fn step_up(self) -> Self {
+                    if self.is_nan() || self == <$t>::INFINITY {
+                        self
+                    } else if self == 0.0 {
+                        <$t>::from_bits(1)
+                    } else if self > 0.0 {
+                        <$t>::from_bits(self.to_bits() + 1)
+                    } else {
+                        <$t>::from_bits(self.to_bits() - 1)
+                    }
+                }
+
+                fn step_down(self) -> Self {
+                    -(-self).step_up()
+                }
+
+                fn enclose(self, error: Self) -> (Self, Self) {
+                    if error > 0.0 {
+                        (self, self.step_up())
+                    } else if error < 0.0 {
+                        (self.step_down(), self)
+                    } else if error == 0.0 {
+                        (self, self)
+                    } else {
+                        // The error overflowed, so the exact result is only known to be nearby
+                        (self.step_down(), self.step_up())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+/// Steps between adjacent floating-point values, as `f64::next_up` does on newer toolchains.
+trait DirectedRounding: Sized {
+    /// Returns the least value greater than self.
+    fn step_up(self) -> Self;
+
+    /// Returns the greatest value less than self.
+    fn step_down(self) -> Self;
+
+    /// Returns the bounds of self + error, given the sign of the rounding error.
+    fn enclose(self, error: Self) -> (Self, Self);
+}
+
+impl_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::DirectedRounding;
+    use crate::{CommutativeRing, EuclideanDomain, IntegralDomain, RealField, Semiring};
+
+    fn needs_ring<T: CommutativeRing>() {}
+    fn needs_domain<T: IntegralDomain + EuclideanDomain>() {}
+    fn needs_semiring<T: Semiring>() {}
+
+    #[test]
+    fn signed_integers_are_euclidean_domains() {
+        needs_ring::<i8>();
+        needs_ring::<i16>();
+        needs_ring::<i32>();
+        needs_ring::<i64>();
+        needs_ring::<i128>();
+        needs_ring::<isize>();
+        needs_domain::<i8>();
+        needs_domain::<i16>();
+        needs_domain::<i32>();
+        needs_domain::<i64>();
+        needs_domain::<i128>();
+        needs_domain::<isize>();
+    }
+
+    #[test]
+    fn unsigned_integers_are_semirings() {
+        needs_semiring::<u8>();
+        needs_semiring::<u16>();
+        needs_semiring::<u32>();
+        needs_semiring::<u64>();
+        needs_semiring::<u128>();
+        needs_semiring::<usize>();
+    }
+
+    #[test]
+    fn euclidean_division_delegates_to_std() {
+        use crate::ClosedDivEuclid;
+        assert_eq!(ClosedDivEuclid::div_euclid(-7i64, 2), -4);
+        assert_eq!(ClosedDivEuclid::div_euclid(7u32, 2), 3);
+        assert_eq!(num_traits::Euclid::rem_euclid(&-7i64, &2), 1);
+    }
+
+    fn solve<F: RealField>(a: F, b: F) -> F {
+        // The root of a · x + b = 0
+        -b / a
+    }
+
+    #[test]
+    fn floats_are_real_fields() {
+        assert_eq!(solve(2.0f32, -3.0), 1.5);
+        assert_eq!(solve(4.0f64, 1.0), -0.25);
+    }
+
+    #[test]
+    fn exact_float_operations_have_tight_bounds() {
+        assert_eq!(1.0f64.add_bounds(&2.0), (3.0, 3.0));
+        assert_eq!(1.5f64.mul_bounds(&-4.0), (-6.0, -6.0));
+        assert_eq!(1.0f32.div_bounds(&4.0), (0.25, 0.25));
+    }
+
+    #[test]
+    fn inexact_float_operations_are_enclosed() {
+        let (lo, hi) = 0.1f64.add_bounds(&0.2);
+        assert_eq!(hi, lo.step_up());
+        assert!(lo == 0.1 + 0.2 || hi == 0.1 + 0.2);
+        // 1/3 lies strictly between the bounds: 3 · lo − 1 < 0 < 3 · hi − 1, each fused
+        let (lo, hi) = 1.0f64.div_bounds(&3.0);
+        assert!(lo.mul_add(3.0, -1.0) < 0.0 && hi.mul_add(3.0, -1.0) > 0.0);
+        let (lo, hi) = (-1.0f64).div_bounds(&-3.0);
+        assert!(lo.mul_add(3.0, -1.0) < 0.0 && hi.mul_add(3.0, -1.0) > 0.0);
+        let x = 1.0f64 + f64::EPSILON;
+        let (lo, hi) = x.mul_bounds(&x);
+        assert!(x.mul_add(x, -lo) > 0.0 && x.mul_add(x, -hi) < 0.0);
+    }
+
+    #[test]
+    fn float_bounds_at_the_edges() {
+        assert_eq!(f64::MAX.add_bounds(&f64::MAX), (f64::MAX, f64::INFINITY));
+        assert_eq!(f64::MAX.mul_bounds(&-2.0), (f64::NEG_INFINITY, -f64::MAX));
+        let tiny = f64::MIN_POSITIVE;
+        let (lo, hi) = tiny.mul_bounds(&0.3);
+        assert!(lo < tiny * 0.3 && tiny * 0.3 < hi);
+        assert_eq!(
+            f64::INFINITY.add_bounds(&1.0),
+            (f64::INFINITY, f64::INFINITY)
+        );
+        assert_eq!(0.0f64.step_up(), f64::from_bits(1));
+        assert_eq!((-0.0f64).step_down(), -f64::from_bits(1));
+        assert_eq!(f64::MAX.step_up(), f64::INFINITY);
+    }
+
+    #[test]
+    fn float_order_is_partial_at_nan() {
+        assert_eq!(1.0f64.partial_cmp(&2.0), Some(std::cmp::Ordering::Less));
+        assert_eq!(f64::NAN.partial_cmp(&0.0), None);
+    }
+}
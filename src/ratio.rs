@@ -0,0 +1,655 @@
+use crate::{
+    AbsoluteValue, AssociativeAddition, AssociativeMultiplication, CanonicalAssociate,
+    CommutativeAddition, CommutativeMultiplication, Distributive, EuclideanDomain, OrderedRing,
+};
+use num_traits::{Euclid, Inv, Num, One, Signed, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// Represents a fraction n / d over a Euclidean domain, an element of its field of fractions.
+///
+/// # Mathematical Definition
+/// The field of fractions of an integral domain R consists of pairs (n, d) with d ≠ 0, where
+/// (a, b) ~ (c, d) iff a · d = b · c, and
+/// - a/b + c/d = (a · d + b · c) / (b · d)
+/// - a/b · c/d = (a · c) / (b · d)
+///
+/// # Properties
+/// - Every fraction is kept in lowest terms by dividing out gcd(n, d) after each operation
+/// - The denominator is scaled by the unit −(−1 div d) whenever that quotient is non-zero. Over
+///   ℤ this moves the sign onto the numerator, and over a field it makes the denominator one
+/// - Equality compares the parts of the canonical forms. Where lowest terms are only unique up
+///   to a unit, as for polynomial denominators, it falls back to a · d = b · c once the
+///   denominators are known to be associates, so no product larger than a part is formed
+/// - Over an ordered domain such as ℤ, fractions are ordered by comparing their continued
+///   fraction expansions term by term, which makes `Ratio<i64>` an `OrderedField` whose
+///   comparisons never form a product of two parts
+/// - Over such a domain it also implements `num_traits::Signed`, whose `abs`, `is_positive` and
+///   `is_negative` agree with those of `OrderedRing`; with both traits in scope, call them by
+///   path, as in `OrderedRing::abs(r)`
+#[derive(Clone, Debug)]
+pub struct Ratio<T: EuclideanDomain> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: EuclideanDomain> Ratio<T> {
+    /// Creates the fraction numer / denom in lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if denom is zero.
+    pub fn new(numer: T, denom: T) -> Self {
+        assert!(!denom.is_zero(), "Ratio with a zero denominator");
+        let mut ratio = Self { numer, denom };
+        ratio.reduce();
+        ratio
+    }
+
+    /// Creates numer / denom exactly as given, so tests can reach non-reduced states.
+    #[cfg(test)]
+    fn new_raw(numer: T, denom: T) -> Self {
+        Self { numer, denom }
+    }
+
+    /// Returns the numerator.
+    pub fn numer(&self) -> &T {
+        &self.numer
+    }
+
+    /// Returns the denominator.
+    pub fn denom(&self) -> &T {
+        &self.denom
+    }
+
+    /// Returns the numerator in lowest terms. An alias of `numer`.
+    pub fn numerator(&self) -> &T {
+        self.numer()
+    }
+
+    /// Returns the denominator in lowest terms. An alias of `denom`.
+    pub fn denominator(&self) -> &T {
+        self.denom()
+    }
+
+    /// Returns whether the fraction is in canonical form: gcd(n, d) is a unit and the
+    /// denominator is unchanged by the normalizing unit.
+    ///
+    /// Every public constructor and operation returns reduced fractions.
+    pub fn is_reduced(&self) -> bool {
+        let g = self.numer.gcd(&self.denom);
+        let unit = -Euclid::div_euclid(&-T::one(), &self.denom);
+        Euclid::rem_euclid(&T::one(), &g).is_zero() && (unit.is_zero() || unit.is_one())
+    }
+
+    /// Puts the fraction in canonical form by dividing out gcd(n, d) and scaling the
+    /// denominator by the normalizing unit.
+    pub fn reduce(&mut self) {
+        let g = self.numer.gcd(&self.denom);
+        self.numer = Euclid::div_euclid(&self.numer, &g);
+        self.denom = Euclid::div_euclid(&self.denom, &g);
+        self.normalize_unit();
+    }
+
+    /// Creates numer / denom from coprime parts, only scaling by the normalizing unit.
+    fn from_coprime(numer: T, denom: T) -> Self {
+        let mut ratio = Self { numer, denom };
+        ratio.normalize_unit();
+        ratio
+    }
+
+    /// Scales numerator and denominator by the unit −(−1 div d), when it is non-zero.
+    fn normalize_unit(&mut self) {
+        let unit = -Euclid::div_euclid(&-T::one(), &self.denom);
+        if !unit.is_zero() {
+            self.numer *= unit.clone();
+            self.denom *= unit;
+        }
+    }
+}
+
+impl<T: EuclideanDomain> From<T> for Ratio<T> {
+    fn from(value: T) -> Self {
+        Self {
+            numer: value,
+            denom: T::one(),
+        }
+    }
+}
+
+impl<T: EuclideanDomain + fmt::Display> fmt::Display for Ratio<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numer, self.denom)
+    }
+}
+
+impl<T: EuclideanDomain> PartialEq for Ratio<T> {
+    /// Compares numerators and denominators, then checks a · d = c · b only when b and d are
+    /// associates, where dividing both by gcd(b, d) leaves units.
+    fn eq(&self, other: &Self) -> bool {
+        if self.numer == other.numer && self.denom == other.denom {
+            return true;
+        }
+        let g = self.denom.gcd(&other.denom);
+        let b = Euclid::div_euclid(&self.denom, &g);
+        let d = Euclid::div_euclid(&other.denom, &g);
+        let is_unit = |x: &T| Euclid::rem_euclid(&T::one(), x).is_zero();
+        is_unit(&b) && is_unit(&d) && self.numer.clone() * d == other.numer.clone() * b
+    }
+}
+
+impl<T: EuclideanDomain + PartialOrd> PartialOrd for Ratio<T> {
+    /// Compares a/b with c/d by the floor-division walk: with positive denominators, compare
+    /// q₁ = a div b with q₂ = c div d, and if they agree, compare the remainders r₁/b and r₂/d,
+    /// which is the same as comparing d/r₂ with b/r₁. This is the Euclidean algorithm run on
+    /// both fractions at once, so every intermediate value is bounded by the parts.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let oriented = |r: &Self| {
+            if r.denom < T::zero() {
+                (-r.numer.clone(), -r.denom.clone())
+            } else {
+                (r.numer.clone(), r.denom.clone())
+            }
+        };
+        let ((mut a, mut b), (mut c, mut d)) = (oriented(self), oriented(other));
+        loop {
+            let ordering = Euclid::div_euclid(&a, &b).partial_cmp(&Euclid::div_euclid(&c, &d))?;
+            if ordering != Ordering::Equal {
+                return Some(ordering);
+            }
+            let (r1, r2) = (Euclid::rem_euclid(&a, &b), Euclid::rem_euclid(&c, &d));
+            match (r1.is_zero(), r2.is_zero()) {
+                (true, true) => return Some(Ordering::Equal),
+                (true, false) => return Some(Ordering::Less),
+                (false, true) => return Some(Ordering::Greater),
+                (false, false) => {
+                    (a, b, c, d) = (d, r2, b, r1);
+                }
+            }
+        }
+    }
+}
+
+impl<T: EuclideanDomain> Add for Ratio<T> {
+    type Output = Self;
+
+    /// Adds by Henrici's method, which keeps intermediate values small.
+    ///
+    /// With g = gcd(b, d), a/b + c/d = t / (b · d/g) for t = a · d/g + c · b/g, and any common
+    /// factor of t and that denominator divides g. Dividing out g₂ = gcd(t, g) therefore leaves
+    /// lowest terms, and no product larger than the reduced result's parts times g is formed.
+    /// Over `i64` this means 1/2³³ + 1/2³³ gives 1/2³² instead of overflowing at 2⁶⁶.
+    fn add(self, other: Self) -> Self {
+        let g = self.denom.gcd(&other.denom);
+        let b = Euclid::div_euclid(&self.denom, &g);
+        let d = Euclid::div_euclid(&other.denom, &g);
+        let t = self.numer * d.clone() + other.numer * b.clone();
+        let g2 = t.gcd(&g);
+        Self::from_coprime(
+            Euclid::div_euclid(&t, &g2),
+            b * Euclid::div_euclid(&other.denom, &g2),
+        )
+    }
+}
+
+impl<T: EuclideanDomain> AddAssign for Ratio<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = std::mem::replace(self, Self::zero()) + other;
+    }
+}
+
+impl<T: EuclideanDomain> Neg for Ratio<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl<T: EuclideanDomain> Sub for Ratio<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<T: EuclideanDomain> SubAssign for Ratio<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = std::mem::replace(self, Self::zero()) - other;
+    }
+}
+
+impl<T: EuclideanDomain> Mul for Ratio<T> {
+    type Output = Self;
+
+    /// Multiplies after cancelling across, (a/g₁ · c/g₂) / (b/g₂ · d/g₁) for g₁ = gcd(a, d) and
+    /// g₂ = gcd(c, b), so the result is in lowest terms without a final gcd.
+    fn mul(self, other: Self) -> Self {
+        if self.numer.is_zero() || other.numer.is_zero() {
+            return Self::zero();
+        }
+        let g1 = self.numer.gcd(&other.denom);
+        let g2 = other.numer.gcd(&self.denom);
+        Self::from_coprime(
+            Euclid::div_euclid(&self.numer, &g1) * Euclid::div_euclid(&other.numer, &g2),
+            Euclid::div_euclid(&self.denom, &g2) * Euclid::div_euclid(&other.denom, &g1),
+        )
+    }
+}
+
+impl<T: EuclideanDomain> MulAssign for Ratio<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = std::mem::replace(self, Self::zero()) * other;
+    }
+}
+
+impl<T: EuclideanDomain> Inv for Ratio<T> {
+    type Output = Self;
+
+    /// Swaps numerator and denominator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if self is zero.
+    fn inv(self) -> Self {
+        assert!(!self.numer.is_zero(), "Division by a zero Ratio");
+        Self::new(self.denom, self.numer)
+    }
+}
+
+impl<T: EuclideanDomain> Div for Ratio<T> {
+    type Output = Self;
+
+    /// Multiplies by the inverse of the divisor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.inv()
+    }
+}
+
+impl<T: EuclideanDomain> DivAssign for Ratio<T> {
+    fn div_assign(&mut self, other: Self) {
+        *self = std::mem::replace(self, Self::zero()) / other;
+    }
+}
+
+impl<T: EuclideanDomain> Rem for Ratio<T> {
+    type Output = Self;
+
+    /// Returns zero, as division in a field is exact.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    fn rem(self, other: Self) -> Self {
+        assert!(!other.is_zero(), "Division by a zero Ratio");
+        Self::zero()
+    }
+}
+
+impl<T: EuclideanDomain> Euclid for Ratio<T> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        self.clone() / v.clone()
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        self.clone() % v.clone()
+    }
+}
+
+impl<T: EuclideanDomain> Zero for Ratio<T> {
+    fn zero() -> Self {
+        Self::from(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+}
+
+impl<T: EuclideanDomain> One for Ratio<T> {
+    fn one() -> Self {
+        Self::from(T::one())
+    }
+}
+
+impl<T: EuclideanDomain + Num> Num for Ratio<T> {
+    type FromStrRadixErr = ParseRatioError;
+
+    /// Parses `n` or `n/d`, with both parts in the given radix.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseRatioError> {
+        let parse =
+            |part: &str| T::from_str_radix(part, radix).map_err(|_| ParseRatioError::Invalid);
+        match s.split_once('/') {
+            None => parse(s).map(Self::from),
+            Some((numer, denom)) => {
+                let (numer, denom) = (parse(numer)?, parse(denom)?);
+                if denom.is_zero() {
+                    Err(ParseRatioError::ZeroDenominator)
+                } else {
+                    Ok(Self::new(numer, denom))
+                }
+            }
+        }
+    }
+}
+
+impl<T: EuclideanDomain + Num + PartialOrd> Signed for Ratio<T> {
+    fn abs(&self) -> Self {
+        OrderedRing::abs(self.clone())
+    }
+
+    /// Returns self − other if that is positive, and zero otherwise.
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            Self::zero()
+        } else {
+            self.clone() - other.clone()
+        }
+    }
+
+    fn signum(&self) -> Self {
+        AbsoluteValue::signum(self.clone())
+    }
+
+    fn is_positive(&self) -> bool {
+        OrderedRing::is_positive(self)
+    }
+
+    fn is_negative(&self) -> bool {
+        OrderedRing::is_negative(self)
+    }
+}
+
+/// The error returned when parsing a `Ratio` from a string fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseRatioError {
+    /// The numerator or denominator is not a valid integer.
+    Invalid,
+    /// The denominator is zero.
+    ZeroDenominator,
+}
+
+impl fmt::Display for ParseRatioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid => write!(f, "invalid numerator or denominator"),
+            Self::ZeroDenominator => write!(f, "zero denominator"),
+        }
+    }
+}
+
+impl std::error::Error for ParseRatioError {}
+
+impl<T: EuclideanDomain> CommutativeAddition for Ratio<T> {}
+impl<T: EuclideanDomain> AssociativeAddition for Ratio<T> {}
+impl<T: EuclideanDomain> CommutativeMultiplication for Ratio<T> {}
+impl<T: EuclideanDomain> AssociativeMultiplication for Ratio<T> {}
+impl<T: EuclideanDomain> Distributive for Ratio<T> {}
+impl<T: EuclideanDomain> CanonicalAssociate for Ratio<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::check_field_laws;
+    use crate::test_support::SplitMix64;
+    use crate::PrimeField;
+
+    fn q(n: i64, d: i64) -> Ratio<i64> {
+        Ratio::new(n, d)
+    }
+
+    #[test]
+    fn new_reduces_to_lowest_terms() {
+        let r = q(6, 8);
+        assert_eq!((*r.numer(), *r.denom()), (3, 4));
+        let r = q(0, -5);
+        assert_eq!((*r.numer(), *r.denom()), (0, 1));
+    }
+
+    #[test]
+    fn sign_moves_onto_the_numerator() {
+        let r = q(3, -6);
+        assert_eq!((*r.numer(), *r.denom()), (-1, 2));
+        let r = q(-3, -6);
+        assert_eq!((*r.numer(), *r.denom()), (1, 2));
+    }
+
+    #[test]
+    fn operations_stay_in_lowest_terms() {
+        let sum = q(1, 6) + q(1, 3);
+        assert_eq!((*sum.numer(), *sum.denom()), (1, 2));
+        let product = q(2, 3) * q(9, 4);
+        assert_eq!((*product.numer(), *product.denom()), (3, 2));
+        let quotient = q(2, 3) / q(-4, 9);
+        assert_eq!((*quotient.numer(), *quotient.denom()), (-3, 2));
+        assert_eq!(q(1, 2) - q(1, 2), Ratio::zero());
+    }
+
+    #[test]
+    fn field_laws_hold() {
+        let samples = [q(1, 2), q(-3, 4), q(5, 1), q(7, -9)];
+        for a in &samples {
+            for b in &samples {
+                for c in &samples {
+                    assert!(check_field_laws(a.clone(), b.clone(), c.clone()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by a zero Ratio")]
+    fn division_by_zero_panics() {
+        let _ = q(1, 2) / Ratio::zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "zero denominator")]
+    fn zero_denominator_panics() {
+        let _ = q(1, 0);
+    }
+
+    #[test]
+    fn ordering_sorts_fractions() {
+        assert!(q(1, 3) < q(1, 2));
+        assert!(q(-1, 2) < q(-1, 3));
+        assert!(q(2, 4) <= q(1, 2) && q(2, 4) >= q(1, 2));
+        assert!(q(7, -3) < Ratio::zero());
+        let mut sorted = vec![q(3, 4), q(-2, 1), q(1, 3), q(0, 1), q(5, -6)];
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(sorted, [q(-2, 1), q(-5, 6), q(0, 1), q(1, 3), q(3, 4)]);
+    }
+
+    #[test]
+    fn ordering_near_the_limits_does_not_overflow() {
+        assert!(q(1, 1 << 33) < q(1, 3 << 31));
+        assert!(q(-1, 1 << 33) > q(-1, 3 << 31));
+        let (a, b) = (q(i64::MAX - 1, i64::MAX), q(i64::MAX - 2, i64::MAX - 1));
+        assert_eq!(b.partial_cmp(&a), Some(Ordering::Less));
+        assert!(q(i64::MAX, i64::MAX - 1) < q(i64::MAX - 1, i64::MAX - 2));
+        assert!(q(i64::MIN, 1) < q(i64::MIN + 1, 1));
+        assert!(q(i64::MIN, i64::MAX) < q(-1, 1));
+        assert!(q(i64::MIN + 1, i64::MAX) == q(-1, 1));
+        assert!(q(1, i64::MAX) > Ratio::zero() && q(-1, i64::MAX) < Ratio::zero());
+        assert!(q(1, i64::MAX) < q(1, i64::MAX - 1));
+        let sum = q(1, 1_000_000_000) + q(1, 999_999_999);
+        assert_eq!(sum.partial_cmp(&sum), Some(Ordering::Equal));
+        assert!(sum > q(1, 500_000_000) && sum < q(2, 999_999_999));
+    }
+
+    #[test]
+    fn ordering_matches_cross_products_when_they_fit() {
+        let mut rng = SplitMix64::new(513);
+        for _ in 0..2000 {
+            let (a, b) = (rng.range_i64(-1000, 1000), rng.range_i64(1, 1000));
+            let (c, d) = (rng.range_i64(-1000, 1000), rng.range_i64(1, 1000));
+            assert_eq!(q(a, b).partial_cmp(&q(c, d)), (a * d).partial_cmp(&(c * b)));
+        }
+    }
+
+    #[test]
+    fn ratios_are_ordered_fields() {
+        fn needs_ordered_field<T: crate::OrderedField>() {}
+        needs_ordered_field::<Ratio<i64>>();
+    }
+
+    #[test]
+    fn accessors_return_lowest_terms() {
+        let r = q(-10, -4);
+        assert_eq!((*r.numerator(), *r.denominator()), (5, 2));
+        assert_eq!((r.numerator(), r.denominator()), (r.numer(), r.denom()));
+    }
+
+    #[test]
+    fn constructed_and_computed_fractions_are_reduced() {
+        for (n, d) in [(6, 8), (3, -6), (0, 7), (-9, 3), (1, 1), (12, -18)] {
+            assert!(q(n, d).is_reduced(), "{n}/{d}");
+        }
+        let sum = q(1, 6) + q(1, 3);
+        assert!(sum.is_reduced());
+        assert!((q(2, 3) * q(9, 4)).is_reduced());
+        assert!(Ratio::new(PrimeField::<7>::new(3), PrimeField::new(5)).is_reduced());
+    }
+
+    #[test]
+    fn reduce_fixes_a_non_reduced_state() {
+        let mut r = Ratio::new_raw(6i64, -8);
+        assert!(!r.is_reduced());
+        r.reduce();
+        assert!(r.is_reduced());
+        assert_eq!((*r.numerator(), *r.denominator()), (-3, 4));
+
+        // Coprime but with the sign on the denominator
+        let mut r = Ratio::new_raw(1i64, -2);
+        assert!(!r.is_reduced());
+        r.reduce();
+        assert_eq!((*r.numerator(), *r.denominator()), (-1, 2));
+
+        // Over a field the canonical denominator is one
+        type F7 = PrimeField<7>;
+        let mut r = Ratio::new_raw(F7::new(3), F7::new(5));
+        assert!(!r.is_reduced());
+        r.reduce();
+        assert_eq!((*r.numerator(), *r.denominator()), (F7::new(2), F7::new(1)));
+        assert!(Ratio::new_raw(0i64, 1).is_reduced());
+        assert!(!Ratio::new_raw(0i64, 5).is_reduced());
+    }
+
+    #[test]
+    fn signs_agree_with_comparison_to_zero() {
+        let zero = Ratio::zero();
+        for n in -6..=6 {
+            for d in [-5, -2, 1, 3] {
+                let r = q(n, d);
+                assert_eq!(OrderedRing::is_positive(&r), r > zero);
+                assert_eq!(OrderedRing::is_negative(&r), r < zero);
+                assert_eq!(Signed::is_positive(&r), r > zero);
+                assert_eq!(Signed::is_negative(&r), r < zero);
+                assert!(OrderedRing::abs(r.clone()) >= zero);
+                assert_eq!(Signed::abs(&r), OrderedRing::abs(r.clone()));
+                assert_eq!(Signed::signum(&r), AbsoluteValue::signum(r.clone()));
+            }
+        }
+        for x in [-2.5f64, 0.0, 3.0, f64::NAN] {
+            assert_eq!(OrderedRing::is_positive(&x), x > 0.0);
+            assert_eq!(OrderedRing::is_negative(&x), x < 0.0);
+        }
+    }
+
+    #[test]
+    fn signed_differences() {
+        assert_eq!(q(3, 2).abs_sub(&q(1, 2)), q(1, 1));
+        assert_eq!(q(1, 2).abs_sub(&q(3, 2)), Ratio::zero());
+    }
+
+    #[test]
+    fn parses_fractions() {
+        assert_eq!(Ratio::<i64>::from_str_radix("-6/8", 10), Ok(q(-3, 4)));
+        assert_eq!(Ratio::<i64>::from_str_radix("7", 10), Ok(q(7, 1)));
+        assert_eq!(Ratio::<i64>::from_str_radix("ff/-10", 16), Ok(q(-255, 16)));
+        assert_eq!(
+            Ratio::<i64>::from_str_radix("1/0", 10),
+            Err(ParseRatioError::ZeroDenominator)
+        );
+        assert_eq!(
+            Ratio::<i64>::from_str_radix("1/x", 10),
+            Err(ParseRatioError::Invalid)
+        );
+    }
+
+    /// The textbook formulas, reduced once at the end.
+    fn naive_add(a: &Ratio<i64>, b: &Ratio<i64>) -> Ratio<i64> {
+        Ratio::new(a.numer * b.denom + b.numer * a.denom, a.denom * b.denom)
+    }
+
+    fn naive_mul(a: &Ratio<i64>, b: &Ratio<i64>) -> Ratio<i64> {
+        Ratio::new(a.numer * b.numer, a.denom * b.denom)
+    }
+
+    #[test]
+    fn sums_of_large_denominators() {
+        let sum = q(1, 1_000_000_000) + q(1, 999_999_999);
+        assert_eq!(
+            (*sum.numer(), *sum.denom()),
+            (1_999_999_999, 999_999_999_000_000_000)
+        );
+        assert!(sum.is_reduced());
+        let sum = q(1, 1 << 33) + q(1, 1 << 33);
+        assert_eq!((*sum.numer(), *sum.denom()), (1, 1 << 32));
+        let difference = q(1, 3 << 40) - q(1, 5 << 40);
+        assert_eq!((*difference.numer(), *difference.denom()), (1, 15 << 39));
+    }
+
+    #[test]
+    fn equality_of_large_denominators_does_not_overflow() {
+        let r = q((1 << 40) + 1, 1 << 40);
+        assert_eq!(r, r.clone());
+        assert_ne!(r, q((1 << 40) + 3, 1 << 40));
+        assert_ne!(r, q((1 << 40) + 1, (1 << 40) + 2));
+        let sum = q(1, 1_000_000_000) + q(1, 999_999_999);
+        assert_eq!(sum, sum.clone());
+        assert_eq!(sum, q(1_999_999_999, 999_999_999_000_000_000));
+        assert_ne!(sum, q(1, 999_999_999_000_000_000));
+        assert_eq!(q(i64::MAX, i64::MAX - 1), q(-i64::MAX, 1 - i64::MAX));
+        assert_ne!(q(i64::MIN, i64::MAX), q(i64::MAX, i64::MAX));
+    }
+
+    #[test]
+    fn products_cancel_across() {
+        let product = q(1 << 40, 3) * q(3, 1 << 40);
+        assert_eq!((*product.numer(), *product.denom()), (1, 1));
+        let product = q(-(1 << 31), 5) * q(7, 1 << 32);
+        assert_eq!((*product.numer(), *product.denom()), (-7, 10));
+        assert!((q(0, 1) * q(3, 7)).is_reduced());
+    }
+
+    #[test]
+    fn cross_reduced_arithmetic_matches_the_naive_formulas() {
+        let mut rng = SplitMix64::new(270);
+        for _ in 0..2000 {
+            let a = q(rng.range_i64(-1000, 1000), rng.range_i64(1, 1000));
+            let b = q(rng.range_i64(-1000, 1000), rng.range_i64(-1000, -1));
+            for (x, y) in [(&a, &b), (&b, &a), (&a, &a)] {
+                let sum = x.clone() + y.clone();
+                let product = x.clone() * y.clone();
+                assert!(sum.is_reduced() && product.is_reduced());
+                let (naive_sum, naive_product) = (naive_add(x, y), naive_mul(x, y));
+                assert_eq!((sum.numer, sum.denom), (naive_sum.numer, naive_sum.denom));
+                assert_eq!(
+                    (product.numer, product.denom),
+                    (naive_product.numer, naive_product.denom)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use std::fmt;
+use std::hash::Hash;
+
+/// Represents a strategy for reducing wide integers modulo a fixed 64-bit modulus.
+///
+/// # Mathematical Definition
+/// For a modulus m > 0, reduction maps x ∈ [0, 2¹²⁸) to the unique r ∈ [0, m) with r ≡ x (mod m).
+///
+/// # Properties
+/// - Every strategy computes the same function x ↦ x mod m; they differ only in cost
+/// - Precomputation happens once at construction, so a strategy is built per modulus
+pub trait ModularReduction {
+    /// Returns the modulus m.
+    fn modulus(&self) -> u64;
+
+    /// Returns value mod m.
+    fn reduce(&self, value: u128) -> u64;
+}
+
+/// Represents a reduction strategy fixed at compile time for the modulus P, so that a type such
+/// as `PrimeField<P, R>` can select it without storing it.
+///
+/// `INSTANCE` is evaluated once per modulus during compilation, so the precomputation of each
+/// strategy costs nothing at run time, and a modulus the strategy rejects is a compile error.
+pub trait ReductionStrategy<const P: u64>:
+    ModularReduction + Copy + Eq + Hash + fmt::Debug
+{
+    /// The strategy for the modulus P.
+    const INSTANCE: Self;
+}
+
+impl<const P: u64> ReductionStrategy<P> for NaiveReduction {
+    const INSTANCE: Self = Self::new(P);
+}
+
+impl<const P: u64> ReductionStrategy<P> for BarrettReduction {
+    const INSTANCE: Self = Self::new(P);
+}
+
+impl<const P: u64> ReductionStrategy<P> for MontgomeryReduction {
+    const INSTANCE: Self = Self::new(P);
+}
+
+/// Reduction by hardware division, the reference strategy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NaiveReduction {
+    modulus: u64,
+}
+
+impl NaiveReduction {
+    /// Creates the strategy for the given modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero.
+    pub const fn new(modulus: u64) -> Self {
+        assert!(modulus > 0, "modulus must be positive");
+        Self { modulus }
+    }
+}
+
+impl ModularReduction for NaiveReduction {
+    fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    fn reduce(&self, value: u128) -> u64 {
+        (value % self.modulus as u128) as u64
+    }
+}
+
+/// Barrett reduction, replacing division by a multiplication with a precomputed reciprocal.
+///
+/// With μ = ⌊(2¹²⁸ − 1) / m⌋, the estimate q = ⌊x · μ / 2¹²⁸⌋ undershoots ⌊x / m⌋ by at most
+/// two, so x − q · m is brought into [0, m) by at most two subtractions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BarrettReduction {
+    modulus: u64,
+    mu: u128,
+}
+
+impl BarrettReduction {
+    /// Creates the strategy for the given modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero.
+    pub const fn new(modulus: u64) -> Self {
+        assert!(modulus > 0, "modulus must be positive");
+        Self {
+            modulus,
+            mu: u128::MAX / modulus as u128,
+        }
+    }
+}
+
+impl ModularReduction for BarrettReduction {
+    fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    fn reduce(&self, value: u128) -> u64 {
+        let m = self.modulus as u128;
+        let q = mul_high(value, self.mu);
+        let mut r = value - q * m;
+        while r >= m {
+            r -= m;
+        }
+        r as u64
+    }
+}
+
+/// Montgomery reduction with R = 2⁶⁴, replacing division by multiplications and shifts.
+///
+/// REDC(t) = t · R⁻¹ mod m for t < m · R. A full reduction of x applies REDC twice, scaling by
+/// R² mod m in between to cancel the R⁻¹ factors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MontgomeryReduction {
+    modulus: u64,
+    neg_inv: u64,
+    r_squared: u64,
+}
+
+impl MontgomeryReduction {
+    /// Creates the strategy for the given modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is even, as it must be invertible modulo R = 2⁶⁴.
+    pub const fn new(modulus: u64) -> Self {
+        assert!(
+            modulus % 2 == 1,
+            "Montgomery reduction requires an odd modulus"
+        );
+        // Newton's iteration doubles the number of correct low bits of m⁻¹ mod 2⁶⁴ each step.
+        let mut inv: u64 = 1;
+        let mut step = 0;
+        while step < 6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus.wrapping_mul(inv)));
+            step += 1;
+        }
+        let m = modulus as u128;
+        Self {
+            modulus,
+            neg_inv: inv.wrapping_neg(),
+            r_squared: ((u128::MAX % m + 1) % m) as u64,
+        }
+    }
+
+    /// Returns t · R⁻¹ mod m for t < m · R.
+    fn redc(&self, t: u128) -> u64 {
+        let m = self.modulus as u128;
+        let k = (t as u64).wrapping_mul(self.neg_inv);
+        let (sum, carry) = t.overflowing_add(k as u128 * m);
+        let u = (sum >> 64) | ((carry as u128) << 64);
+        if u >= m {
+            (u - m) as u64
+        } else {
+            u as u64
+        }
+    }
+}
+
+impl ModularReduction for MontgomeryReduction {
+    fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    fn reduce(&self, value: u128) -> u64 {
+        let m = self.modulus as u128;
+        let r_squared = self.r_squared as u128;
+        // REDC needs its input below m · R, so first replace the high word by its residue.
+        let mut value = value;
+        let high = value >> 64;
+        if high >= m {
+            let high = self.redc(self.redc(high) as u128 * r_squared) as u128;
+            value = (high << 64) | (value & u64::MAX as u128);
+        }
+        self.redc(self.redc(value) as u128 * r_squared)
+    }
+}
+
+/// Returns the high 128 bits of the 256-bit product a · b.
+fn mul_high(a: u128, b: u128) -> u128 {
+    const LOW: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & LOW);
+    let (b1, b0) = (b >> 64, b & LOW);
+    let p00 = a0 * b0;
+    let p01 = a0 * b1;
+    let p10 = a1 * b0;
+    let p11 = a1 * b1;
+    let middle = (p00 >> 64) + (p01 & LOW) + (p10 & LOW);
+    p11 + (p01 >> 64) + (p10 >> 64) + (middle >> 64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SplitMix64;
+    use crate::PrimeField;
+
+    const MODULI: [u64; 6] = [
+        3,
+        65_537,
+        1_000_000_007,
+        2_147_483_647,
+        1 << 61,
+        18_446_744_073_709_551_557,
+    ];
+
+    fn wide(rng: &mut SplitMix64) -> u128 {
+        (rng.next_u64() as u128) << 64 | rng.next_u64() as u128
+    }
+
+    #[test]
+    fn strategies_agree_with_remainder() {
+        let mut rng = SplitMix64::new(250);
+        for &m in &MODULI {
+            let naive = NaiveReduction::new(m);
+            let barrett = BarrettReduction::new(m);
+            for _ in 0..2000 {
+                let x = wide(&mut rng);
+                let expected = (x % m as u128) as u64;
+                assert_eq!(naive.reduce(x), expected);
+                assert_eq!(barrett.reduce(x), expected);
+                if m % 2 == 1 {
+                    assert_eq!(MontgomeryReduction::new(m).reduce(x), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn strategies_agree_at_the_edges() {
+        for &m in MODULI.iter().filter(|&&m| m % 2 == 1) {
+            let strategies: [&dyn ModularReduction; 3] = [
+                &NaiveReduction::new(m),
+                &BarrettReduction::new(m),
+                &MontgomeryReduction::new(m),
+            ];
+            let m = m as u128;
+            for x in [0, 1, m - 1, m, m + 1, m * m - 1, u128::MAX - 1, u128::MAX] {
+                for strategy in strategies {
+                    assert_eq!(strategy.reduce(x) as u128, x % m, "x = {}, m = {}", x, m);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn montgomery_constants() {
+        let m = 1_000_000_007u64;
+        let montgomery = MontgomeryReduction::new(m);
+        assert_eq!(m.wrapping_mul(montgomery.neg_inv), u64::MAX);
+        assert_eq!(
+            montgomery.r_squared as u128,
+            (1u128 << 64) % m as u128 * ((1u128 << 64) % m as u128) % m as u128
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "odd modulus")]
+    fn montgomery_rejects_even_modulus() {
+        MontgomeryReduction::new(1 << 61);
+    }
+
+    #[test]
+    fn parameterized_fields_agree() {
+        const P: u64 = 18_446_744_073_709_551_557;
+        let mut rng = SplitMix64::new(2500);
+        for _ in 0..2000 {
+            let (a, b) = (rng.next_u64(), rng.next_u64());
+            let naive = PrimeField::<P>::new(a) * PrimeField::new(b);
+            let barrett = PrimeField::<P, BarrettReduction>::new(a) * PrimeField::new(b);
+            let montgomery = PrimeField::<P, MontgomeryReduction>::new(a) * PrimeField::new(b);
+            let expected = (a as u128 % P as u128 * (b as u128 % P as u128) % P as u128) as u64;
+            assert_eq!(naive.value(), expected);
+            assert_eq!(barrett.value(), expected);
+            assert_eq!(montgomery.value(), expected);
+        }
+    }
+
+    /// Runs the same chain of multiply-accumulate steps through each field and compares the
+    /// checksums.
+    #[test]
+    fn strategies_agree_on_a_long_product_chain() {
+        const P: u64 = 2_147_483_647;
+        const STEPS: usize = 100_000;
+
+        fn run<R: ReductionStrategy<P>>() -> u64 {
+            let step = PrimeField::<P, R>::new(48_271);
+            let mut x = PrimeField::<P, R>::new(1);
+            let mut sum = PrimeField::<P, R>::new(0);
+            for _ in 0..STEPS {
+                x *= step;
+                sum += x * x;
+            }
+            sum.value()
+        }
+
+        let naive = run::<NaiveReduction>();
+        assert_eq!(run::<BarrettReduction>(), naive);
+        assert_eq!(run::<MontgomeryReduction>(), naive);
+    }
+}
@@ -0,0 +1,319 @@
+use crate::{BarycentricInterpolator, DensePolynomial, Field, FiniteField, Polynomial};
+use num_traits::{One, Zero};
+
+/// Represents a Reed–Solomon code of length n and dimension k over a finite field.
+///
+/// # Mathematical Definition
+/// For distinct non-zero evaluation points x₀, ..., xₙ₋₁, the message (m₀, ..., mₖ₋₁) is the
+/// polynomial f = m₀ + m₁ · x + ... + mₖ₋₁ · xᵏ⁻¹ and its codeword is (f(x₀), ..., f(xₙ₋₁)).
+///
+/// # Properties
+/// - Distinct polynomials of degree below k agree on at most k − 1 points, so the minimum
+///   distance is n − k + 1 and up to t = ⌊(n − k) / 2⌋ symbol errors can be corrected
+/// - With the weights wᵢ = 1 / ∏ⱼ (xᵢ − xⱼ), over j ≠ i, every codeword c satisfies the n − k
+///   parity checks Σᵢ wᵢ cᵢ xᵢʲ = 0 for j < n − k, whose values on a received word are its
+///   syndromes
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReedSolomon<F: FiniteField> {
+    points: Vec<F>,
+    weights: Vec<F>,
+    message_len: usize,
+}
+
+impl<F: FiniteField> ReedSolomon<F> {
+    /// Creates the code of dimension `message_len` that evaluates messages at `points`.
+    ///
+    /// Returns `None` if two points coincide, a point is zero, or `message_len` exceeds the
+    /// number of points.
+    pub fn new(points: Vec<F>, message_len: usize) -> Option<Self> {
+        if message_len > points.len() || points.iter().any(|x| x.is_zero()) {
+            return None;
+        }
+        let weights = BarycentricInterpolator::new(points.clone())?
+            .weights()
+            .to_vec();
+        Some(Self {
+            points,
+            weights,
+            message_len,
+        })
+    }
+
+    /// Returns the evaluation points.
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    /// Returns the message length k.
+    pub fn message_len(&self) -> usize {
+        self.message_len
+    }
+
+    /// Returns the number t = ⌊(n − k) / 2⌋ of symbol errors the code corrects.
+    pub fn correctable_errors(&self) -> usize {
+        (self.points.len() - self.message_len) / 2
+    }
+
+    /// Returns the codeword of a message, the evaluations of its polynomial at the points.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message does not have k symbols.
+    pub fn encode(&self, message: &[F]) -> Vec<F> {
+        assert_eq!(
+            message.len(),
+            self.message_len,
+            "expected a message of k symbols"
+        );
+        let f = DensePolynomial::new(message.to_vec());
+        self.points.iter().map(|x| f.evaluate(x)).collect()
+    }
+
+    /// Returns the message whose codeword differs from `received` in at most t symbols, or
+    /// `None` if no such message is found.
+    ///
+    /// The n − k syndromes are the power sums Sⱼ = Σ Yₗ Xₗʲ over the error positions, with
+    /// locators Xₗ = xᵢ and Yₗ = wᵢ eᵢ. Berlekamp–Massey finds the shortest recurrence they
+    /// satisfy, whose connection polynomial Λ(z) = ∏ₗ (1 − Xₗ z) has the inverse locators as
+    /// roots. Forney's formula Yₗ = −Xₗ Ω(Xₗ⁻¹) / Λ′(Xₗ⁻¹), with Ω = S · Λ mod zⁿ⁻ᵏ, then gives
+    /// the error values. Decoding fails when Λ is longer than t, when it does not have as
+    /// many roots among the points as its degree, or when the corrected word still has a
+    /// non-zero syndrome. Beyond t errors the received word may also lie within t of another
+    /// codeword, which is then decoded without any failure being detectable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `received` does not have n symbols.
+    pub fn decode(&self, received: &[F]) -> Option<Vec<F>> {
+        assert_eq!(
+            received.len(),
+            self.points.len(),
+            "expected a received word of n symbols"
+        );
+        let checks = self.points.len() - self.message_len;
+        let syndromes = self.syndromes(received);
+        let mut codeword = received.to_vec();
+        if syndromes.iter().any(|s| !s.is_zero()) {
+            let (locator, errors) = berlekamp_massey(&syndromes);
+            if errors > checks / 2 || locator.degree() != errors {
+                return None;
+            }
+            let product = DensePolynomial::new(syndromes) * locator.clone();
+            let evaluator = DensePolynomial::new(
+                product
+                    .coefficients()
+                    .iter()
+                    .take(checks)
+                    .cloned()
+                    .collect(),
+            );
+            let derivative = locator.derivative();
+            let mut found = 0;
+            for ((x, weight), symbol) in self.points.iter().zip(&self.weights).zip(&mut codeword) {
+                let inverse = F::one() / x.clone();
+                if !locator.evaluate(&inverse).is_zero() {
+                    continue;
+                }
+                let slope = derivative.evaluate(&inverse);
+                if slope.is_zero() {
+                    return None;
+                }
+                let value = -x.clone() * evaluator.evaluate(&inverse) / slope;
+                *symbol -= value / weight.clone();
+                found += 1;
+            }
+            if found != errors || self.syndromes(&codeword).iter().any(|s| !s.is_zero()) {
+                return None;
+            }
+        }
+        Some(self.interpolate(&codeword))
+    }
+
+    /// Returns the n − k syndromes Σᵢ wᵢ rᵢ xᵢʲ of a received word.
+    fn syndromes(&self, received: &[F]) -> Vec<F> {
+        let mut terms: Vec<F> = received
+            .iter()
+            .zip(&self.weights)
+            .map(|(r, w)| r.clone() * w.clone())
+            .collect();
+        let mut syndromes = Vec::with_capacity(self.points.len() - self.message_len);
+        for _ in self.message_len..self.points.len() {
+            syndromes.push(terms.iter().fold(F::zero(), |sum, t| sum + t.clone()));
+            for (t, x) in terms.iter_mut().zip(&self.points) {
+                *t = t.clone() * x.clone();
+            }
+        }
+        syndromes
+    }
+
+    /// Returns the message of a codeword, by Lagrange interpolation through its first k
+    /// symbols.
+    fn interpolate(&self, codeword: &[F]) -> Vec<F> {
+        let nodes = &self.points[..self.message_len];
+        let vanishing = DensePolynomial::from_roots(nodes);
+        let mut f = DensePolynomial::zero();
+        for (x, y) in nodes.iter().zip(codeword) {
+            let basis = vanishing.clone() / DensePolynomial::new(vec![-x.clone(), F::one()]);
+            let scale = y.clone() / basis.evaluate(x);
+            f += basis * DensePolynomial::new(vec![scale]);
+        }
+        (0..self.message_len).map(|i| f.coefficient(i)).collect()
+    }
+}
+
+/// Returns the connection polynomial Λ(z) = 1 + Λ₁ z + ... + Λ_L z^L of the shortest linear
+/// recurrence sₙ = −Σᵢ Λᵢ sₙ₋ᵢ generating the sequence, together with its length L.
+///
+/// The degree of Λ can be less than L when the recurrence needs initial terms that no shorter
+/// one reproduces.
+fn berlekamp_massey<F: Field>(sequence: &[F]) -> (DensePolynomial<F>, usize) {
+    let mut connection = DensePolynomial::one();
+    let mut previous = DensePolynomial::one();
+    let mut previous_discrepancy = F::one();
+    let mut length = 0;
+    let mut shift = 1;
+    for (n, term) in sequence.iter().enumerate() {
+        let discrepancy = (1..=length).fold(term.clone(), |d, i| {
+            d + connection.coefficient(i) * sequence[n - i].clone()
+        });
+        if discrepancy.is_zero() {
+            shift += 1;
+            continue;
+        }
+        let mut correction = vec![F::zero(); shift];
+        correction.push(discrepancy.clone() / previous_discrepancy.clone());
+        let update = connection.clone() - DensePolynomial::new(correction) * previous.clone();
+        if 2 * length <= n {
+            previous = std::mem::replace(&mut connection, update);
+            previous_discrepancy = discrepancy;
+            length = n + 1 - length;
+            shift = 1;
+        } else {
+            connection = update;
+            shift += 1;
+        }
+    }
+    (connection, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SplitMix64;
+    use crate::PrimeField;
+
+    type F29 = PrimeField<29>;
+
+    /// The code of length 16 and dimension 8 at the points 1, ..., 16, correcting 4 errors.
+    fn code() -> ReedSolomon<F29> {
+        ReedSolomon::new((1..=16).map(F29::new).collect(), 8).unwrap()
+    }
+
+    fn random_message(rng: &mut SplitMix64) -> Vec<F29> {
+        (0..8).map(|_| F29::new(rng.next_u64())).collect()
+    }
+
+    /// Adds a non-zero error to `count` distinct random positions.
+    fn corrupt(word: &mut [F29], count: usize, rng: &mut SplitMix64) {
+        let mut positions: Vec<usize> = Vec::new();
+        while positions.len() < count {
+            let i = rng.range_i64(0, word.len() as i64 - 1) as usize;
+            if !positions.contains(&i) {
+                positions.push(i);
+                word[i] += F29::new(rng.range_i64(1, 28) as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn codewords_are_evaluations() {
+        let code = code();
+        let message: Vec<F29> = [3, 1, 4, 1, 5, 9, 2, 6]
+            .iter()
+            .map(|&m| F29::new(m))
+            .collect();
+        let codeword = code.encode(&message);
+        let f = DensePolynomial::new(message);
+        for (x, c) in code.points().iter().zip(&codeword) {
+            assert_eq!(*c, f.evaluate(x));
+        }
+        assert!(code.syndromes(&codeword).iter().all(|s| s.is_zero()));
+        assert_eq!(code.correctable_errors(), 4);
+    }
+
+    #[test]
+    fn clean_codewords_round_trip() {
+        let code = code();
+        let mut rng = SplitMix64::new(235);
+        for _ in 0..100 {
+            let message = random_message(&mut rng);
+            assert_eq!(code.decode(&code.encode(&message)), Some(message));
+        }
+        assert_eq!(code.decode(&[F29::zero(); 16]), Some(vec![F29::zero(); 8]));
+    }
+
+    #[test]
+    fn up_to_t_errors_are_corrected() {
+        let code = code();
+        let mut rng = SplitMix64::new(2350);
+        for errors in 1..=4 {
+            for _ in 0..100 {
+                let message = random_message(&mut rng);
+                let mut received = code.encode(&message);
+                corrupt(&mut received, errors, &mut rng);
+                assert_eq!(code.decode(&received), Some(message), "{errors} errors");
+            }
+        }
+    }
+
+    #[test]
+    fn more_than_t_errors_are_not_corrected() {
+        let code = code();
+        let mut rng = SplitMix64::new(2351);
+        let mut detected = 0;
+        for errors in 5..=8 {
+            for _ in 0..100 {
+                let message = random_message(&mut rng);
+                let mut received = code.encode(&message);
+                corrupt(&mut received, errors, &mut rng);
+                let decoded = code.decode(&received);
+                assert_ne!(decoded, Some(message), "{errors} errors");
+                detected += usize::from(decoded.is_none());
+            }
+        }
+        // Landing within t of another codeword is rare, so most failures are detected.
+        assert!(detected > 300, "only {detected} failures detected");
+    }
+
+    #[test]
+    fn berlekamp_massey_finds_the_fibonacci_recurrence() {
+        // sₙ = sₙ₋₁ + sₙ₋₂ has connection polynomial 1 − z − z².
+        let fibonacci: Vec<F29> = [1, 1, 2, 3, 5, 8, 13, 21]
+            .iter()
+            .map(|&s| F29::new(s))
+            .collect();
+        let (connection, length) = berlekamp_massey(&fibonacci);
+        assert_eq!(length, 2);
+        assert_eq!(
+            connection,
+            DensePolynomial::new(vec![F29::one(), -F29::one(), -F29::one()])
+        );
+        let (connection, length) = berlekamp_massey(&[F29::zero(); 4]);
+        assert_eq!((connection, length), (DensePolynomial::one(), 0));
+    }
+
+    #[test]
+    fn invalid_codes_are_rejected() {
+        let points = |xs: &[u64]| xs.iter().map(|&x| F29::new(x)).collect::<Vec<_>>();
+        assert!(ReedSolomon::new(points(&[1, 2, 1]), 1).is_none());
+        assert!(ReedSolomon::new(points(&[0, 1, 2]), 1).is_none());
+        assert!(ReedSolomon::new(points(&[1, 2, 3]), 4).is_none());
+        assert!(ReedSolomon::new(points(&[1, 2, 3]), 3).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "message of k symbols")]
+    fn messages_of_the_wrong_length_panic() {
+        code().encode(&[F29::one(); 3]);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::Field;
+
+/// Splits `secret` into `shares` points on a random polynomial of degree `threshold − 1`.
+///
+/// The polynomial has constant term `secret` and its remaining coefficients are drawn from
+/// `random`, which must sample uniformly from F for the scheme to be secure. Shares are its
+/// evaluations at x = 1, 2, ..., `shares`, so any `threshold` of them determine the secret
+/// while fewer reveal nothing about it.
+///
+/// # Panics
+///
+/// Panics if `threshold` is zero, if `threshold > shares`, or if `shares` is not less than the
+/// characteristic of F (the evaluation points would then repeat).
+pub fn shamir_split<F: Field>(
+    secret: F,
+    threshold: usize,
+    shares: usize,
+    mut random: impl FnMut() -> F,
+) -> Vec<(F, F)> {
+    assert!(threshold > 0, "threshold must be at least one");
+    assert!(
+        threshold <= shares,
+        "threshold must not exceed the number of shares"
+    );
+
+    let mut coefficients = Vec::with_capacity(threshold);
+    coefficients.push(secret);
+    coefficients.extend((1..threshold).map(|_| random()));
+
+    let mut x = F::zero();
+    (0..shares)
+        .map(|_| {
+            x += F::one();
+            assert!(
+                !x.is_zero(),
+                "shares must be fewer than the field characteristic"
+            );
+            let y = coefficients
+                .iter()
+                .rev()
+                .fold(F::zero(), |acc, c| acc * x.clone() + c.clone());
+            (x.clone(), y)
+        })
+        .collect()
+}
+
+/// Recovers the secret from shares via Lagrange interpolation at x = 0.
+///
+/// At least `threshold` shares from the same split are required for the result to be the
+/// secret; with fewer, the interpolated value is unrelated to it. Returns `None` if the shares
+/// are empty or two shares have the same x-coordinate.
+pub fn shamir_reconstruct<F: Field>(shares: &[(F, F)]) -> Option<F> {
+    if shares.is_empty() {
+        return None;
+    }
+    let mut secret = F::zero();
+    for (i, (xi, yi)) in shares.iter().enumerate() {
+        let mut numerator = F::one();
+        let mut denominator = F::one();
+        for (j, (xj, _)) in shares.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            if xi == xj {
+                return None;
+            }
+            numerator *= xj.clone();
+            denominator *= xj.clone() - xi.clone();
+        }
+        secret += yi.clone() * numerator / denominator;
+    }
+    Some(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SplitMix64;
+    use crate::PrimeField;
+    use num_traits::One;
+
+    type F257 = PrimeField<257>;
+
+    fn split(secret: u64, threshold: usize, shares: usize, seed: u64) -> Vec<(F257, F257)> {
+        let mut rng = SplitMix64::new(seed);
+        shamir_split(F257::new(secret), threshold, shares, || {
+            F257::new(rng.next_u64())
+        })
+    }
+
+    #[test]
+    fn any_threshold_shares_reconstruct_the_secret() {
+        let shares = split(123, 3, 5, 234);
+        for i in 0..5 {
+            for j in i + 1..5 {
+                for k in j + 1..5 {
+                    let subset = [shares[i], shares[j], shares[k]];
+                    assert_eq!(shamir_reconstruct(&subset), Some(F257::new(123)));
+                }
+            }
+        }
+        assert_eq!(shamir_reconstruct(&shares), Some(F257::new(123)));
+    }
+
+    #[test]
+    fn shares_are_evaluations_at_one_to_n() {
+        let shares = split(7, 1, 3, 0);
+        let xs: Vec<u64> = shares.iter().map(|(x, _)| x.value()).collect();
+        assert_eq!(xs, [1, 2, 3]);
+        // A threshold of one stores the secret in every share
+        assert!(shares.iter().all(|&(_, y)| y == F257::new(7)));
+    }
+
+    #[test]
+    fn fewer_shares_do_not_determine_the_secret() {
+        let shares = split(42, 2, 3, 1);
+        let (x, y) = shares[0];
+        assert_eq!(x, F257::new(1));
+        // Every candidate secret s is consistent with the single share (1, y), via the line
+        // s + (y − s) · x
+        for s in 0..257 {
+            let candidate = F257::new(s);
+            let forged = shamir_split(candidate, 2, 3, || y - candidate);
+            assert_eq!(forged[0], (x, y));
+            assert_eq!(shamir_reconstruct(&forged[1..]), Some(candidate));
+        }
+    }
+
+    #[test]
+    fn repeated_or_missing_x_coordinates_are_rejected() {
+        let shares = split(9, 2, 3, 2);
+        assert_eq!(shamir_reconstruct(&[shares[0], shares[1], shares[0]]), None);
+        assert_eq!(shamir_reconstruct::<F257>(&[]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "fewer than the field characteristic")]
+    fn too_many_shares_for_the_field_panics() {
+        let _ = shamir_split(PrimeField::<5>::new(1), 2, 5, PrimeField::one);
+    }
+
+    #[test]
+    #[should_panic(expected = "threshold must not exceed")]
+    fn threshold_above_share_count_panics() {
+        let _ = split(1, 4, 3, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "threshold must be at least one")]
+    fn zero_threshold_panics() {
+        let _ = split(1, 0, 3, 0);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, CommutativeMultiplication,
+    Distributive,
+};
+use num_traits::{Float, One, Zero};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign};
+
+/// Represents an element of the min-plus tropical semiring (ℝ ∪ {+∞}, min, +).
+///
+/// # Mathematical Definition
+/// Tropical addition is a ⊕ b = min(a, b) and tropical multiplication is a ⊗ b = a + b. The
+/// additive identity is +∞ and the multiplicative identity is 0, and +∞ annihilates:
+/// a ⊗ +∞ = +∞.
+///
+/// # Properties
+/// - Addition is idempotent, a ⊕ a = a, so no element other than +∞ has an additive inverse
+/// - Ordered by the usual order of the reals, in which the zero +∞ is the largest element; hence
+///   `positive_part` is always +∞ and `negative_part` is the identity
+/// - Dot products over this semiring are min-plus products, minᵢ(aᵢ + bᵢ), the relaxation step
+///   of shortest-path algorithms
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Tropical<F: Float>(F);
+
+impl<F: Float> Tropical<F> {
+    /// Creates the tropical number with the given real value, or +∞.
+    ///
+    /// # Panics
+    ///
+    /// Panics if value is NaN or −∞, which are not elements of the semiring.
+    pub fn new(value: F) -> Self {
+        assert!(
+            !value.is_nan() && value != F::neg_infinity(),
+            "Tropical numbers are reals or +∞"
+        );
+        Self(value)
+    }
+
+    /// Returns the underlying value, +∞ for the additive identity.
+    pub fn value(&self) -> F {
+        self.0
+    }
+}
+
+impl<F: Float + fmt::Display> fmt::Display for Tropical<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<F: Float> Add for Tropical<F> {
+    type Output = Self;
+
+    /// Returns the minimum.
+    fn add(self, other: Self) -> Self {
+        Self(self.0.min(other.0))
+    }
+}
+
+impl<F: Float> AddAssign for Tropical<F> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<F: Float> Mul for Tropical<F> {
+    type Output = Self;
+
+    /// Returns the real sum.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl<F: Float> MulAssign for Tropical<F> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<F: Float> Zero for Tropical<F> {
+    /// Returns +∞.
+    fn zero() -> Self {
+        Self(F::infinity())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == F::infinity()
+    }
+}
+
+impl<F: Float> One for Tropical<F> {
+    /// Returns the real number 0.
+    fn one() -> Self {
+        Self(F::zero())
+    }
+}
+
+impl<F: Float> CommutativeAddition for Tropical<F> {}
+impl<F: Float> AssociativeAddition for Tropical<F> {}
+impl<F: Float> CommutativeMultiplication for Tropical<F> {}
+impl<F: Float> AssociativeMultiplication for Tropical<F> {}
+impl<F: Float> Distributive for Tropical<F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::{
+        check_additive_identity, check_associative_addition, check_associative_multiplication,
+        check_commutative_addition, check_commutative_multiplication, check_distributive,
+        check_multiplicative_identity,
+    };
+    use crate::OrderedSemiring;
+
+    fn t(value: f64) -> Tropical<f64> {
+        Tropical::new(value)
+    }
+
+    fn samples() -> [Tropical<f64>; 5] {
+        [t(-3.0), t(0.0), t(1.5), t(8.0), Tropical::zero()]
+    }
+
+    #[test]
+    fn operations_are_min_and_plus() {
+        assert_eq!(t(2.0) + t(-1.0), t(-1.0));
+        assert_eq!(t(2.0) * t(-1.0), t(1.0));
+        assert_eq!(t(4.0) + t(4.0), t(4.0));
+        assert_eq!(t(4.0) + Tropical::zero(), t(4.0));
+        assert_eq!(t(4.0) * Tropical::one(), t(4.0));
+        assert!((t(4.0) * Tropical::zero()).is_zero());
+    }
+
+    #[test]
+    fn semiring_laws_hold() {
+        for a in samples() {
+            assert!(check_additive_identity(a));
+            assert!(check_multiplicative_identity(a));
+            for b in samples() {
+                assert!(check_commutative_addition(a, b));
+                assert!(check_commutative_multiplication(a, b));
+                for c in samples() {
+                    assert!(check_associative_addition(a, b, c));
+                    assert!(check_associative_multiplication(a, b, c));
+                    assert!(check_distributive(a, b, c));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn positive_part_of_reals() {
+        assert_eq!((-3.0f64).positive_part(), 0.0);
+        assert_eq!(5.0f64.positive_part(), 5.0);
+        assert_eq!((-3.0f64).negative_part(), -3.0);
+        assert_eq!(5.0f64.negative_part(), 0.0);
+    }
+
+    #[test]
+    fn positive_part_clamps_to_infinity() {
+        for a in samples() {
+            assert!(a.positive_part().is_zero());
+            assert_eq!(a.negative_part(), a);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "reals or +∞")]
+    fn negative_infinity_is_rejected() {
+        let _ = t(f64::NEG_INFINITY);
+    }
+
+    #[test]
+    #[should_panic(expected = "reals or +∞")]
+    fn nan_is_rejected() {
+        let _ = t(f64::NAN);
+    }
+}
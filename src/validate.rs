@@ -0,0 +1,276 @@
+use crate::laws::{
+    check_additive_identity, check_additive_inverse, check_associative_addition,
+    check_associative_multiplication, check_commutative_addition, check_distributive,
+    check_multiplicative_identity,
+};
+use crate::test_support::SplitMix64;
+use crate::{PrimeField, Ratio, ReductionStrategy, Ring};
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+
+// The marker traits are promises the compiler cannot check. With the `validate` feature a type
+// can be tested against them: `validate_laws` draws samples from a fixed-seed generator, so a
+// failure is reproducible, and remembers the verdict per type so repeated calls cost nothing.
+
+/// Number of sample triples checked by `validate_laws`.
+const SAMPLES: usize = 256;
+
+/// Produces sample values for randomized law checks.
+///
+/// Available with the `validate` feature.
+pub trait Arbitrary: Sized {
+    /// Returns the sample determined by 64 random bits. Samples of integer types are kept small
+    /// enough that the laws can be checked without overflow.
+    fn arbitrary(bits: u64) -> Self;
+}
+
+impl<const P: u64, R: ReductionStrategy<P>> Arbitrary for PrimeField<P, R> {
+    fn arbitrary(bits: u64) -> Self {
+        Self::new(bits)
+    }
+}
+
+macro_rules! impl_arbitrary_small_int {
+    ($($t:ty),*) => {
+        $(
+            impl Arbitrary for $t {
+                /// Returns a value in [−1000, 1000].
+                fn arbitrary(bits: u64) -> Self {
+                    (bits % 2001) as $t - 1000
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_small_int!(i32, i64, i128);
+
+impl Arbitrary for Ratio<i64> {
+    /// Returns n / d with |n| ≤ 20 and 1 ≤ d ≤ 20.
+    fn arbitrary(bits: u64) -> Self {
+        let numer = (bits % 41) as i64 - 20;
+        let denom = ((bits >> 32) % 20) as i64 + 1;
+        Ratio::new(numer, denom)
+    }
+}
+
+/// A ring law that failed on some sample.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LawViolation {
+    /// (a + b) + c ≠ a + (b + c)
+    AssociativeAddition,
+    /// a + b ≠ b + a
+    CommutativeAddition,
+    /// a + 0 ≠ a
+    AdditiveIdentity,
+    /// a + (−a) ≠ 0
+    AdditiveInverse,
+    /// (a · b) · c ≠ a · (b · c)
+    AssociativeMultiplication,
+    /// a · 1 ≠ a
+    MultiplicativeIdentity,
+    /// a · (b + c) ≠ a · b + a · c
+    Distributive,
+}
+
+impl fmt::Display for LawViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let law = match self {
+            Self::AssociativeAddition => "addition is not associative",
+            Self::CommutativeAddition => "addition is not commutative",
+            Self::AdditiveIdentity => "zero is not an additive identity",
+            Self::AdditiveInverse => "negation is not an additive inverse",
+            Self::AssociativeMultiplication => "multiplication is not associative",
+            Self::MultiplicativeIdentity => "one is not a multiplicative identity",
+            Self::Distributive => "multiplication does not distribute over addition",
+        };
+        write!(f, "{law}")
+    }
+}
+
+impl std::error::Error for LawViolation {}
+
+/// Checks the ring laws of T on random samples, returning the first law that fails.
+///
+/// Draws `SAMPLES` triples from `Arbitrary` with a fixed seed and checks each with the
+/// functions of `laws`, in the order of `LawViolation`. The verdict is cached per type, so
+/// only the first call for a type runs the checks. Passing is evidence rather than proof: a
+/// law may fail only on values that were never sampled.
+///
+/// Available with the `validate` feature.
+pub fn validate_laws<T: Ring + Arbitrary + 'static>() -> Result<(), LawViolation> {
+    type Verdicts = HashMap<TypeId, Result<(), LawViolation>>;
+    static VERDICTS: Mutex<Option<Verdicts>> = Mutex::new(None);
+    let mut verdicts = VERDICTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *verdicts
+        .get_or_insert_with(HashMap::new)
+        .entry(TypeId::of::<T>())
+        .or_insert_with(check_samples::<T>)
+}
+
+fn check_samples<T: Ring + Arbitrary>() -> Result<(), LawViolation> {
+    let mut rng = SplitMix64::new(0x5EED_1A75);
+    for _ in 0..SAMPLES {
+        let a = T::arbitrary(rng.next_u64());
+        let b = T::arbitrary(rng.next_u64());
+        let c = T::arbitrary(rng.next_u64());
+        if !check_associative_addition(a.clone(), b.clone(), c.clone()) {
+            return Err(LawViolation::AssociativeAddition);
+        }
+        if !check_commutative_addition(a.clone(), b.clone()) {
+            return Err(LawViolation::CommutativeAddition);
+        }
+        if !check_additive_identity(a.clone()) {
+            return Err(LawViolation::AdditiveIdentity);
+        }
+        if !check_additive_inverse(a.clone()) {
+            return Err(LawViolation::AdditiveInverse);
+        }
+        if !check_associative_multiplication(a.clone(), b.clone(), c.clone()) {
+            return Err(LawViolation::AssociativeMultiplication);
+        }
+        if !check_multiplicative_identity(a.clone()) {
+            return Err(LawViolation::MultiplicativeIdentity);
+        }
+        if !check_distributive(a, b, c) {
+            return Err(LawViolation::Distributive);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AssociativeAddition, AssociativeMultiplication, CommutativeAddition, Distributive,
+    };
+    use num_traits::{One, Zero};
+    use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+    type F7 = PrimeField<7>;
+
+    /// 𝔽₇ with multiplication transported along the permutation swapping 2 and 3:
+    /// a ⊙ b = φ(φ(a) · φ(b)). It is associative with identity 1, since φ fixes 1, but φ is not
+    /// additive, so the `Distributive` marker below is a lie.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Swapped(F7);
+
+    fn swap(a: F7) -> F7 {
+        match a.value() {
+            2 => F7::new(3),
+            3 => F7::new(2),
+            _ => a,
+        }
+    }
+
+    impl Add for Swapped {
+        type Output = Self;
+
+        fn add(self, other: Self) -> Self {
+            Swapped(self.0 + other.0)
+        }
+    }
+
+    impl AddAssign for Swapped {
+        fn add_assign(&mut self, other: Self) {
+            *self = *self + other;
+        }
+    }
+
+    impl Neg for Swapped {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            Swapped(-self.0)
+        }
+    }
+
+    impl Sub for Swapped {
+        type Output = Self;
+
+        fn sub(self, other: Self) -> Self {
+            Swapped(self.0 - other.0)
+        }
+    }
+
+    impl SubAssign for Swapped {
+        fn sub_assign(&mut self, other: Self) {
+            *self = *self - other;
+        }
+    }
+
+    impl Mul for Swapped {
+        type Output = Self;
+
+        fn mul(self, other: Self) -> Self {
+            Swapped(swap(swap(self.0) * swap(other.0)))
+        }
+    }
+
+    impl MulAssign for Swapped {
+        fn mul_assign(&mut self, other: Self) {
+            *self = *self * other;
+        }
+    }
+
+    impl Zero for Swapped {
+        fn zero() -> Self {
+            Swapped(F7::zero())
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0.is_zero()
+        }
+    }
+
+    impl One for Swapped {
+        fn one() -> Self {
+            Swapped(F7::one())
+        }
+    }
+
+    impl CommutativeAddition for Swapped {}
+    impl AssociativeAddition for Swapped {}
+    impl AssociativeMultiplication for Swapped {}
+    impl Distributive for Swapped {}
+
+    impl Arbitrary for Swapped {
+        fn arbitrary(bits: u64) -> Self {
+            Swapped(F7::arbitrary(bits))
+        }
+    }
+
+    #[test]
+    fn lawful_rings_validate() {
+        assert_eq!(validate_laws::<F7>(), Ok(()));
+        assert_eq!(validate_laws::<PrimeField<1_000_000_007>>(), Ok(()));
+        assert_eq!(validate_laws::<i64>(), Ok(()));
+        assert_eq!(validate_laws::<Ratio<i64>>(), Ok(()));
+        // The cached verdict is returned on later calls
+        assert_eq!(validate_laws::<F7>(), Ok(()));
+    }
+
+    #[test]
+    fn broken_double_reports_the_violated_law() {
+        assert_eq!(validate_laws::<Swapped>(), Err(LawViolation::Distributive));
+        assert_eq!(
+            LawViolation::Distributive.to_string(),
+            "multiplication does not distribute over addition"
+        );
+        // Every other law holds, exhaustively
+        let elements: Vec<Swapped> = (0..7).map(|a| Swapped(F7::new(a))).collect();
+        for &a in &elements {
+            assert!(check_multiplicative_identity(a));
+            for &b in &elements {
+                for &c in &elements {
+                    assert!(check_associative_multiplication(a, b, c));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,946 @@
+use crate::arithmetic::dot;
+use crate::{
+    AssociativeAddition, CommutativeAddition, CommutativeRing, Field, InnerProductSpace, Matrix,
+    Ratio, RealField, Ring, VectorSpace,
+};
+use num_traits::Zero;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Neg, Sub, SubAssign};
+
+/// Represents a vector in the coordinate space Fᴺ over a field T.
+///
+/// # Mathematical Definition
+/// Fᴺ is the N-dimensional vector space of N-tuples with componentwise addition and scalar
+/// multiplication, and the standard inner product ⟨u, v⟩ = Σᵢ uᵢ vᵢ.
+///
+/// # Properties
+/// - For N = 0 the space is {0} and every inner product is the empty sum, zero
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Vector<T: Ring, const N: usize> {
+    components: [T; N],
+}
+
+impl<T: Ring, const N: usize> Vector<T, N> {
+    /// Creates a vector from its components.
+    pub fn new(components: [T; N]) -> Self {
+        Self { components }
+    }
+
+    /// Returns the components of the vector.
+    pub fn components(&self) -> &[T; N] {
+        &self.components
+    }
+
+    /// Returns the Hadamard product, the componentwise product (u ∘ v)ᵢ = uᵢ vᵢ.
+    pub fn hadamard(&self, other: &Self) -> Self {
+        Self::map(|i| self.components[i].clone() * other.components[i].clone())
+    }
+
+    /// Returns the outer product u ⊗ vᵀ, the N × P matrix with (i, j) entry uᵢ vⱼ.
+    ///
+    /// # Properties
+    /// - Has rank at most one, and exactly one when u and v are non-zero over a field
+    /// - When N = P its trace is the inner product Σᵢ uᵢ vᵢ
+    pub fn outer<const P: usize>(&self, other: &Vector<T, P>) -> Matrix<T, N, P> {
+        Matrix::from_rows(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self.components[i].clone() * other.components[j].clone())
+        }))
+    }
+
+    /// Builds a vector whose i-th component is f(i).
+    fn map(f: impl Fn(usize) -> T) -> Self {
+        Self::new(std::array::from_fn(f))
+    }
+}
+
+impl<T: CommutativeRing> Vector<T, 3> {
+    /// Returns the cross product u × v = (u₂v₃ − u₃v₂, u₃v₁ − u₁v₃, u₁v₂ − u₂v₁).
+    ///
+    /// # Properties
+    /// - Antisymmetric: u × v = −(v × u), so u × u = 0
+    /// - Orthogonal to both operands: ⟨u × v, u⟩ = ⟨u × v, v⟩ = 0
+    pub fn cross(&self, other: &Self) -> Self {
+        let [a1, a2, a3] = self.components.clone();
+        let [b1, b2, b3] = other.components.clone();
+        Self::new([
+            a2.clone() * b3.clone() - a3.clone() * b2.clone(),
+            a3 * b1.clone() - a1.clone() * b3,
+            a1 * b2 - a2 * b1,
+        ])
+    }
+}
+
+impl<T: RealField, const N: usize> Vector<T, N> {
+    /// Returns whether every component of self is within `tol` of the matching one of other.
+    pub fn approx_eq(&self, other: &Self, tol: &T) -> bool {
+        self.components
+            .iter()
+            .zip(&other.components)
+            .all(|(a, b)| a.is_within(b, tol))
+    }
+}
+
+impl<T: Ring + fmt::Display, const N: usize> fmt::Display for Vector<T, N> {
+    /// Writes the components in brackets, separated like the columns of a `Matrix`.
+    ///
+    /// A precision such as `{:.2}` is applied to every component.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, x) in self.components.iter().enumerate() {
+            if i > 0 {
+                write!(f, "  ")?;
+            }
+            match f.precision() {
+                Some(precision) => write!(f, "{x:.precision$}")?,
+                None => write!(f, "{x}")?,
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+impl<T: Ring, const N: usize> From<[T; N]> for Vector<T, N> {
+    fn from(components: [T; N]) -> Self {
+        Self::new(components)
+    }
+}
+
+impl<T: Ring, const N: usize> Index<usize> for Vector<T, N> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        &self.components[i]
+    }
+}
+
+impl<T: Ring, const N: usize> IndexMut<usize> for Vector<T, N> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.components[i]
+    }
+}
+
+impl<T: Ring, const N: usize> Add for Vector<T, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::map(|i| self.components[i].clone() + other.components[i].clone())
+    }
+}
+
+impl<T: Ring, const N: usize> AddAssign for Vector<T, N> {
+    fn add_assign(&mut self, other: Self) {
+        for (x, y) in self.components.iter_mut().zip(other.components) {
+            *x += y;
+        }
+    }
+}
+
+impl<T: Ring, const N: usize> Neg for Vector<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::map(|i| -self.components[i].clone())
+    }
+}
+
+impl<T: Ring, const N: usize> Sub for Vector<T, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::map(|i| self.components[i].clone() - other.components[i].clone())
+    }
+}
+
+impl<T: Ring, const N: usize> SubAssign for Vector<T, N> {
+    fn sub_assign(&mut self, other: Self) {
+        for (x, y) in self.components.iter_mut().zip(other.components) {
+            *x -= y;
+        }
+    }
+}
+
+impl<T: Ring, const N: usize> Zero for Vector<T, N> {
+    fn zero() -> Self {
+        Self::map(|_| T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.components.iter().all(Zero::is_zero)
+    }
+}
+
+impl<T: Field, const N: usize> VectorSpace for Vector<T, N> {
+    type Scalar = T;
+
+    fn scale(&self, scalar: &T) -> Self {
+        Self::map(|i| scalar.clone() * self.components[i].clone())
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(N)
+    }
+}
+
+impl<T: Field, const N: usize> InnerProductSpace for Vector<T, N> {
+    fn inner_product(&self, other: &Self) -> T {
+        dot(&self.components, &other.components)
+    }
+}
+
+impl<T: Ring, const N: usize> CommutativeAddition for Vector<T, N> {}
+impl<T: Ring, const N: usize> AssociativeAddition for Vector<T, N> {}
+
+/// Solves A x = b for a symmetric positive-definite A by the conjugate gradient method.
+///
+/// A is given only through the product x ↦ A x, so it can be sparse or never formed at all.
+/// Starting from x = 0, each step moves along a search direction A-conjugate to all earlier
+/// ones, which in exact arithmetic reaches the solution within N steps. Iteration stops as soon
+/// as the residual ‖b − A x‖ is at most `tol`.
+///
+/// Returns `None` if the residual is still above `tol` after `max_iter` steps, or if a search
+/// direction p has ⟨p, A p⟩ ≤ 0, which shows that A is not positive definite.
+pub fn conjugate_gradient<const N: usize>(
+    a: &impl Fn(&Vector<f64, N>) -> Vector<f64, N>,
+    b: &Vector<f64, N>,
+    tol: f64,
+    max_iter: usize,
+) -> Option<Vector<f64, N>> {
+    let mut x = Vector::zero();
+    let mut residual = *b;
+    let mut direction = residual;
+    let mut norm_squared = residual.inner_product(&residual);
+    if norm_squared.sqrt() <= tol {
+        return Some(x);
+    }
+    for _ in 0..max_iter {
+        let image = a(&direction);
+        let curvature = direction.inner_product(&image);
+        if curvature.partial_cmp(&0.0) != Some(Ordering::Greater) {
+            return None;
+        }
+        let step = norm_squared / curvature;
+        x += direction.scale(&step);
+        residual -= image.scale(&step);
+        let next_norm_squared = residual.inner_product(&residual);
+        if next_norm_squared.sqrt() <= tol {
+            return Some(x);
+        }
+        direction = residual + direction.scale(&(next_norm_squared / norm_squared));
+        norm_squared = next_norm_squared;
+    }
+    None
+}
+
+/// Reduces a basis of an integer lattice in place by the Lenstra–Lenstra–Lovász algorithm.
+///
+/// With Gram–Schmidt vectors bᵢ* and coefficients μᵢⱼ = ⟨bᵢ, bⱼ*⟩ / ‖bⱼ*‖², the result is
+/// size-reduced, |μᵢⱼ| ≤ 1/2, and satisfies the Lovász condition
+/// ‖bₖ*‖² ≥ (δ − μₖ,ₖ₋₁²) ‖bₖ₋₁*‖² for every k. It spans the same lattice as the input, the
+/// two being related by a unimodular transformation, and its first vector is at most
+/// (δ − 1/4)^(−(n−1)/2) times as long as the shortest non-zero lattice vector.
+///
+/// The Gram–Schmidt data is kept as exact fractions, so only the Lovász test itself is made in
+/// floating point.
+///
+/// # Panics
+///
+/// Panics if δ is outside (1/4, 1] or the vectors are linearly dependent. Debug builds also
+/// panic if the Gram–Schmidt fractions overflow `i64`, which limits the reduction to bases of
+/// moderate size and entries.
+pub fn lll_reduce<const N: usize>(basis: &mut [Vector<i64, N>], delta: f64) {
+    assert!(delta > 0.25 && delta <= 1.0, "LLL requires 1/4 < δ ≤ 1");
+    let mut gram_schmidt = GramSchmidt::new(basis);
+    let mut k = 1;
+    while k < basis.len() {
+        for j in (0..k).rev() {
+            let q = round(&gram_schmidt.mu[k][j]);
+            if q == 0 {
+                continue;
+            }
+            let multiple = basis[j];
+            for (x, y) in basis[k].components.iter_mut().zip(multiple.components) {
+                *x -= q * y;
+            }
+            // bₖ − q bⱼ has coefficients μₖᵢ − q μⱼᵢ, with μⱼⱼ = 1.
+            let (head, tail) = gram_schmidt.mu.split_at_mut(k);
+            let row = &mut tail[0];
+            for (m, mj) in row.iter_mut().zip(&head[j]) {
+                *m -= Ratio::from(q) * mj.clone();
+            }
+            row[j] -= Ratio::from(q);
+        }
+        let mu = to_f64(&gram_schmidt.mu[k][k - 1]);
+        let previous = to_f64(&gram_schmidt.norms[k - 1]);
+        if to_f64(&gram_schmidt.norms[k]) >= (delta - mu * mu) * previous {
+            k += 1;
+        } else {
+            basis.swap(k - 1, k);
+            gram_schmidt = GramSchmidt::new(basis);
+            k = (k - 1).max(1);
+        }
+    }
+}
+
+/// Returns a shortest non-zero vector of the lattice spanned by basis.
+///
+/// The basis is LLL-reduced first, and its first vector bounds the search: every integer
+/// combination Σ xᵢ bᵢ no longer than the best vector so far is enumerated depth first, from the
+/// last coefficient down, using the Gram–Schmidt decomposition to bound each coefficient given
+/// the ones already fixed. The cost is exponential in the rank, so the rank is limited to
+/// `MAX_ENUMERATION_RANK`. Ties between vectors of equal length are broken arbitrarily.
+///
+/// # Panics
+///
+/// Panics if basis is empty, its vectors are linearly dependent, or its rank exceeds
+/// `MAX_ENUMERATION_RANK`.
+pub fn shortest_vector<const N: usize>(basis: &[Vector<i64, N>]) -> Vector<i64, N> {
+    assert!(!basis.is_empty(), "the zero lattice has no non-zero vector");
+    Enumeration::new(basis, &Vector::zero(), true).search()
+}
+
+/// Returns a vector of the lattice spanned by basis closest to target.
+///
+/// Babai's nearest-plane rounding on the LLL-reduced basis gives a first candidate, whose
+/// distance then bounds the same depth-first enumeration as in `shortest_vector`. The cost is
+/// exponential in the rank, so the rank is limited to `MAX_ENUMERATION_RANK`. The zero lattice
+/// has the zero vector as its only, and so closest, point.
+///
+/// # Panics
+///
+/// Panics if the vectors of basis are linearly dependent or its rank exceeds
+/// `MAX_ENUMERATION_RANK`.
+pub fn closest_vector<const N: usize>(
+    basis: &[Vector<i64, N>],
+    target: &Vector<i64, N>,
+) -> Vector<i64, N> {
+    Enumeration::new(basis, target, false).search()
+}
+
+/// The largest lattice rank accepted by `shortest_vector` and `closest_vector`.
+pub const MAX_ENUMERATION_RANK: usize = 20;
+
+/// The state of a depth-first search for lattice points close to a target.
+///
+/// With t = Σ τᵢ bᵢ* + t⊥, the squared distance from Σ xᵢ bᵢ to t is
+/// Σᵢ (xᵢ − cᵢ)² ‖bᵢ*‖² + ‖t⊥‖², where the centre cᵢ = τᵢ − Σⱼ xⱼ μⱼᵢ, summed over j > i,
+/// depends only on the coefficients after i. Fixing coefficients from the last down, each partial sum is a lower
+/// bound for the final distance.
+struct Enumeration<const N: usize> {
+    basis: Vec<Vector<i64, N>>,
+    target: Vector<i64, N>,
+    norms: Vec<f64>,
+    mu: Vec<Vec<f64>>,
+    tau: Vec<f64>,
+    coefficients: Vec<i64>,
+    exclude_zero: bool,
+    best: Vector<i64, N>,
+    best_distance: i128,
+}
+
+impl<const N: usize> Enumeration<N> {
+    fn new(basis: &[Vector<i64, N>], target: &Vector<i64, N>, exclude_zero: bool) -> Self {
+        assert!(
+            basis.len() <= MAX_ENUMERATION_RANK,
+            "lattice enumeration is limited to rank {MAX_ENUMERATION_RANK}"
+        );
+        let mut basis = basis.to_vec();
+        lll_reduce(&mut basis, 0.99);
+        let gram_schmidt = GramSchmidt::new(&basis);
+        let norms: Vec<f64> = gram_schmidt.norms.iter().map(to_f64).collect();
+        let mu: Vec<Vec<f64>> = gram_schmidt
+            .mu
+            .iter()
+            .map(|row| row.iter().map(to_f64).collect())
+            .collect();
+        // ⟨t, bᵢ*⟩ = ⟨t, bᵢ⟩ − Σⱼ μᵢⱼ ⟨t, bⱼ*⟩, summed over j < i
+        let mut tau: Vec<f64> = Vec::with_capacity(basis.len());
+        for (i, b) in basis.iter().enumerate() {
+            let projection = (0..i)
+                .fold(dot(&target.components, &b.components) as f64, |sum, j| {
+                    sum - mu[i][j] * tau[j] * norms[j]
+                });
+            tau.push(projection / norms[i]);
+        }
+        let mut enumeration = Self {
+            coefficients: vec![0; basis.len()],
+            target: *target,
+            norms,
+            mu,
+            tau,
+            exclude_zero,
+            best: Vector::zero(),
+            best_distance: 0,
+            basis,
+        };
+        if exclude_zero {
+            enumeration.best = enumeration.basis[0];
+        } else {
+            for i in (0..enumeration.basis.len()).rev() {
+                enumeration.coefficients[i] = enumeration.center(i).round() as i64;
+            }
+            enumeration.best = enumeration.combination();
+        }
+        enumeration.best_distance = enumeration.distance(&enumeration.best);
+        enumeration
+    }
+
+    fn search(mut self) -> Vector<i64, N> {
+        self.visit(self.basis.len(), 0.0);
+        self.best
+    }
+
+    /// Enumerates every choice of the first `level` coefficients, the later ones being fixed,
+    /// whose partial squared distance `length` may still beat the best point so far.
+    fn visit(&mut self, level: usize, length: f64) {
+        if level == 0 {
+            let point = self.combination();
+            if self.exclude_zero && point.is_zero() {
+                return;
+            }
+            let distance = self.distance(&point);
+            if distance < self.best_distance {
+                self.best = point;
+                self.best_distance = distance;
+            }
+            return;
+        }
+        let i = level - 1;
+        let center = self.center(i);
+        // A little slack keeps rounding in the floating-point bound from pruning a candidate.
+        let bound = self.best_distance as f64 * (1.0 + 1e-9) + 1e-9;
+        let radius = ((bound - length) / self.norms[i]).max(0.0).sqrt();
+        for x in (center - radius).ceil() as i64..=(center + radius).floor() as i64 {
+            self.coefficients[i] = x;
+            let offset = x as f64 - center;
+            self.visit(i, length + offset * offset * self.norms[i]);
+        }
+    }
+
+    fn center(&self, i: usize) -> f64 {
+        (i + 1..self.basis.len()).fold(self.tau[i], |c, j| {
+            c - self.coefficients[j] as f64 * self.mu[j][i]
+        })
+    }
+
+    fn combination(&self) -> Vector<i64, N> {
+        self.basis
+            .iter()
+            .zip(&self.coefficients)
+            .fold(Vector::zero(), |sum, (b, &x)| {
+                sum + Vector::new(b.components.map(|y| x * y))
+            })
+    }
+
+    /// Returns the exact squared distance from point to the target.
+    fn distance(&self, point: &Vector<i64, N>) -> i128 {
+        point
+            .components
+            .iter()
+            .zip(&self.target.components)
+            .map(|(&x, &y)| (x as i128 - y as i128).pow(2))
+            .sum()
+    }
+}
+
+/// The Gram–Schmidt data of a lattice basis b₀, ..., bₙ₋₁: the squared lengths ‖bᵢ*‖² and the
+/// coefficients μᵢⱼ = ⟨bᵢ, bⱼ*⟩ / ‖bⱼ*‖² for j < i.
+struct GramSchmidt {
+    norms: Vec<Ratio<i64>>,
+    mu: Vec<Vec<Ratio<i64>>>,
+}
+
+impl GramSchmidt {
+    fn new<const N: usize>(basis: &[Vector<i64, N>]) -> Self {
+        let mut orthogonal: Vec<Vector<Ratio<i64>, N>> = Vec::with_capacity(basis.len());
+        let mut norms: Vec<Ratio<i64>> = Vec::with_capacity(basis.len());
+        let mut mu = Vec::with_capacity(basis.len());
+        for b in basis {
+            let b = Vector::new(b.components.map(Ratio::from));
+            let row: Vec<Ratio<i64>> = orthogonal
+                .iter()
+                .zip(&norms)
+                .map(|(star, norm)| b.inner_product(star) / norm.clone())
+                .collect();
+            let mut star = b;
+            for (previous, m) in orthogonal.iter().zip(&row) {
+                star -= previous.scale(m);
+            }
+            let norm = star.inner_product(&star);
+            assert!(
+                !norm.is_zero(),
+                "lattice basis vectors must be linearly independent"
+            );
+            orthogonal.push(star);
+            norms.push(norm);
+            mu.push(row);
+        }
+        Self { norms, mu }
+    }
+}
+
+/// Returns the integer nearest to q, rounding halves towards zero so that a coefficient
+/// |μ| = 1/2, which is already size-reduced, is left alone.
+fn round(q: &Ratio<i64>) -> i64 {
+    // The canonical denominator is positive, and ⌈x − 1/2⌉ = ⌊(2n + d − 1) / 2d⌋ for x = n / d.
+    let (n, d) = (*q.numer(), *q.denom());
+    n.signum() * (2 * n.abs() + d - 1).div_euclid(2 * d)
+}
+
+fn to_f64(q: &Ratio<i64>) -> f64 {
+    *q.numer() as f64 / *q.denom() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::SplitMix64;
+
+    fn q(n: i64, d: i64) -> Ratio<i64> {
+        Ratio::new(n, d)
+    }
+
+    fn v(components: [(i64, i64); 3]) -> Vector<Ratio<i64>, 3> {
+        Vector::new(components.map(|(n, d)| q(n, d)))
+    }
+
+    #[test]
+    fn inner_product_is_bilinear() {
+        let u = v([(1, 1), (-2, 3), (5, 1)]);
+        let w = v([(0, 1), (1, 2), (7, 4)]);
+        let x = v([(3, 1), (1, 1), (-1, 5)]);
+        let (a, b) = (q(2, 3), q(-4, 1));
+
+        let left = u.scale(&a) + w.scale(&b);
+        assert_eq!(
+            left.inner_product(&x),
+            a.clone() * u.inner_product(&x) + b.clone() * w.inner_product(&x)
+        );
+        let right = w.scale(&a) + x.scale(&b);
+        assert_eq!(
+            u.inner_product(&right),
+            a * u.inner_product(&w) + b * u.inner_product(&x)
+        );
+        assert_eq!(u.inner_product(&w), w.inner_product(&u));
+    }
+
+    #[test]
+    fn scalar_multiplication_distributes() {
+        let u = v([(1, 2), (0, 1), (-3, 1)]);
+        let w = v([(2, 1), (1, 3), (1, 1)]);
+        let (a, b) = (q(5, 2), q(-1, 3));
+
+        assert_eq!((u.clone() + w.clone()).scale(&a), u.scale(&a) + w.scale(&a));
+        assert_eq!(u.scale(&(a.clone() + b.clone())), u.scale(&a) + u.scale(&b));
+        assert_eq!(u.scale(&(a.clone() * b.clone())), u.scale(&b).scale(&a));
+        assert_eq!(u.scale(&q(1, 1)), u);
+    }
+
+    #[test]
+    fn empty_inner_product_is_zero() {
+        let empty = Vector::<Ratio<i64>, 0>::new([]);
+        assert!(empty.inner_product(&empty).is_zero());
+        assert!(empty.is_zero());
+        assert_eq!(empty.dimension(), Some(0));
+    }
+
+    #[test]
+    fn integer_vectors_form_a_group() {
+        let u = Vector::new([1i64, -2, 3]);
+        let w = Vector::from([4i64, 0, -1]);
+        assert_eq!(u + w, Vector::new([5, -2, 2]));
+        assert_eq!(u - u, Vector::zero());
+        assert_eq!(-u, Vector::new([-1, 2, -3]));
+        assert_eq!(w[2], -1);
+    }
+
+    #[test]
+    fn approximate_equality_within_a_tolerance() {
+        let a = Vector::new([0.1 + 0.2, 1.0]);
+        let b = Vector::new([0.3, 1.0 + 1e-12]);
+        assert_ne!(a, b);
+        assert!(a.approx_eq(&b, &1e-11));
+        assert!(!a.approx_eq(&b, &1e-13));
+    }
+
+    #[test]
+    fn display_separates_components() {
+        assert_eq!(Vector::new([1.0, -2.5, 10.0]).to_string(), "[1  -2.5  10]");
+        assert_eq!(
+            format!("{:.2}", Vector::new([1.0, -0.125])),
+            "[1.00  -0.12]"
+        );
+        assert_eq!(v([(1, 2), (-3, 1), (0, 1)]).to_string(), "[1/2  -3/1  0/1]");
+        assert_eq!(Vector::<i64, 0>::new([]).to_string(), "[]");
+    }
+
+    #[test]
+    fn cross_product_of_the_standard_basis() {
+        let [e1, e2, e3] = [[1i64, 0, 0], [0, 1, 0], [0, 0, 1]].map(Vector::new);
+        assert_eq!(e1.cross(&e2), e3);
+        assert_eq!(e2.cross(&e3), e1);
+        assert_eq!(e3.cross(&e1), e2);
+        assert_eq!(e2.cross(&e1), -e3);
+    }
+
+    #[test]
+    fn cross_product_is_antisymmetric() {
+        let u = v([(1, 2), (-3, 1), (2, 5)]);
+        let w = v([(4, 1), (0, 1), (-1, 3)]);
+        assert_eq!(u.cross(&w), -w.cross(&u));
+        assert!(u.cross(&u).is_zero());
+        assert_eq!(u.cross(&u.scale(&q(-7, 2))), Vector::zero());
+    }
+
+    #[test]
+    fn cross_product_is_orthogonal_to_its_operands() {
+        let u = Vector::new([0.3f64, -1.7, 2.2]);
+        let w = Vector::new([1.9, 0.4, -0.8]);
+        let c = u.cross(&w);
+        assert!(c.inner_product(&u).abs() < 1e-12);
+        assert!(c.inner_product(&w).abs() < 1e-12);
+        let exact = v([(1, 1), (2, 3), (-5, 4)]).cross(&v([(0, 1), (7, 2), (1, 1)]));
+        assert!(exact.inner_product(&v([(1, 1), (2, 3), (-5, 4)])).is_zero());
+    }
+
+    #[test]
+    fn hadamard_multiplies_componentwise() {
+        let u = Vector::new([1.5, -2.0, 0.0, 4.0]);
+        let w = Vector::new([2.0, 3.0, 7.0, 0.25]);
+        assert_eq!(u.hadamard(&w), Vector::new([3.0, -6.0, 0.0, 1.0]));
+        assert_eq!(u.hadamard(&w), w.hadamard(&u));
+    }
+
+    #[test]
+    fn outer_product_of_two_vectors() {
+        let u = Vector::new([1.0, 2.0]);
+        let w = Vector::new([3.0, 4.0]);
+        assert_eq!(u.outer(&w), Matrix::from_rows([[3.0, 4.0], [6.0, 8.0]]));
+    }
+
+    #[test]
+    fn outer_product_has_rank_one() {
+        let u = Vector::new([1.0, -2.0, 0.5]);
+        let w = Vector::new([3.0, 0.0, -4.0, 2.5]);
+        let a = u.outer(&w);
+        assert!(!a.is_zero());
+        // Every 2 × 2 minor vanishes, so no two rows are independent.
+        for (i, k) in [(0, 1), (0, 2), (1, 2)] {
+            for j in 0..4 {
+                for l in j + 1..4 {
+                    assert_eq!(a[(i, j)] * a[(k, l)] - a[(i, l)] * a[(k, j)], 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn trace_of_outer_product_is_the_inner_product() {
+        let u = v([(1, 2), (-3, 1), (5, 4)]);
+        let w = v([(2, 3), (1, 5), (-1, 1)]);
+        let a = u.outer(&w);
+        let trace = (0..3).fold(Ratio::zero(), |sum, i| sum + a[(i, i)].clone());
+        assert_eq!(trace, u.inner_product(&w));
+    }
+
+    fn spd() -> Matrix<f64, 3, 3> {
+        Matrix::from_rows([[4.0, 1.0, 0.0], [1.0, 3.0, -1.0], [0.0, -1.0, 2.0]])
+    }
+
+    fn apply(a: &Matrix<f64, 3, 3>, x: &Vector<f64, 3>) -> Vector<f64, 3> {
+        Vector::map(|i| (0..3).map(|j| a[(i, j)] * x[j]).sum())
+    }
+
+    #[test]
+    fn conjugate_gradient_solves_a_positive_definite_system() {
+        let a = spd();
+        let expected = Vector::new([1.0, -2.0, 3.0]);
+        let b = apply(&a, &expected);
+        let x = conjugate_gradient(&|x| apply(&a, x), &b, 1e-12, 3).unwrap();
+        assert!(x.approx_eq(&expected, &1e-10));
+    }
+
+    #[test]
+    fn conjugate_gradient_drives_the_residual_below_tolerance() {
+        let a = spd();
+        let b = Vector::new([0.3, -1.7, 2.2]);
+        for tol in [1e-2, 1e-6, 1e-10] {
+            let x = conjugate_gradient(&|x| apply(&a, x), &b, tol, 10).unwrap();
+            let residual = b - apply(&a, &x);
+            assert!(residual.inner_product(&residual).sqrt() <= tol);
+        }
+    }
+
+    #[test]
+    fn conjugate_gradient_of_a_zero_right_hand_side_is_zero() {
+        let a = spd();
+        let x = conjugate_gradient(&|x| apply(&a, x), &Vector::zero(), 1e-12, 0);
+        assert_eq!(x, Some(Vector::zero()));
+    }
+
+    #[test]
+    fn conjugate_gradient_gives_up_without_convergence() {
+        let a = spd();
+        let b = Vector::new([1.0, 1.0, 1.0]);
+        // Three distinct eigenvalues need three steps, so one is not enough.
+        assert_eq!(conjugate_gradient(&|x| apply(&a, x), &b, 1e-12, 1), None);
+        // A negative-definite operator is rejected on its first step.
+        assert_eq!(
+            conjugate_gradient(&|x: &Vector<f64, 3>| -*x, &b, 1e-12, 100),
+            None
+        );
+    }
+
+    #[test]
+    fn scalar_division_halves_components() {
+        let u = Vector::new([3.0, -1.0, 0.5]);
+        assert_eq!(u.scalar_div(&2.0), Vector::new([1.5, -0.5, 0.25]));
+        assert_eq!(u.try_scalar_div(&2.0), Some(u.scale(&0.5)));
+    }
+
+    #[test]
+    fn scalar_division_undoes_scaling() {
+        let u = Vector::new([3.0, -1.0, 0.5]);
+        for c in [2.0, -4.0, 0.125] {
+            assert_eq!(u.scalar_div(&c).scale(&c), u);
+        }
+        let w = v([(1, 2), (-3, 1), (5, 4)]);
+        assert_eq!(w.scalar_div(&q(7, 3)).scale(&q(7, 3)), w);
+    }
+
+    #[test]
+    fn scalar_division_by_zero() {
+        let u = Vector::new([3.0, -1.0, 0.5]);
+        assert_eq!(u.try_scalar_div(&0.0), None);
+        assert_eq!(Vector::<f64, 3>::zero().try_scalar_div(&0.0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero scalar")]
+    fn scalar_division_by_zero_panics() {
+        Vector::new([3.0, -1.0, 0.5]).scalar_div(&0.0);
+    }
+
+    fn lattice<const K: usize, const N: usize>(rows: [[i64; N]; K]) -> Vec<Vector<i64, N>> {
+        rows.iter().map(|&row| Vector::new(row)).collect()
+    }
+
+    fn norm_squared<const N: usize>(v: &Vector<i64, N>) -> i64 {
+        v.components().iter().map(|x| x * x).sum()
+    }
+
+    /// Asserts that basis is size-reduced and satisfies the Lovász condition for δ.
+    fn assert_lll_reduced<const N: usize>(basis: &[Vector<i64, N>], delta: f64) {
+        let gram_schmidt = GramSchmidt::new(basis);
+        for (k, row) in gram_schmidt.mu.iter().enumerate() {
+            for m in row {
+                assert!(to_f64(m).abs() <= 0.5, "μ = {m} in {basis:?}");
+            }
+            if k > 0 {
+                let mu = to_f64(&row[k - 1]);
+                let previous = to_f64(&gram_schmidt.norms[k - 1]);
+                assert!(to_f64(&gram_schmidt.norms[k]) >= (delta - mu * mu) * previous);
+            }
+        }
+    }
+
+    /// Asserts that reduced spans the same lattice as original: each reduced vector has integer
+    /// coordinates in the original basis and the two determinants agree up to sign.
+    fn assert_same_lattice(original: &[Vector<i64, 3>], reduced: &[Vector<i64, 3>]) {
+        let rows = |basis: &[Vector<i64, 3>]| {
+            Matrix::from_rows(std::array::from_fn(|i| *basis[i].components()))
+        };
+        assert_eq!(
+            rows(original).determinant().abs(),
+            rows(reduced).determinant().abs()
+        );
+        let columns: Matrix<Ratio<i64>, 3, 3> = Matrix::from_rows(std::array::from_fn(|i| {
+            std::array::from_fn(|j| Ratio::from(original[j][i]))
+        }));
+        for v in reduced {
+            let target = Vector::new(v.components().map(Ratio::from));
+            let (coordinates, _) = columns.solve_general(&target).unwrap();
+            assert!(coordinates.components().iter().all(|c| *c.denom() == 1));
+        }
+    }
+
+    /// Applies random elementary row operations bᵢ += c bⱼ, which preserve the lattice.
+    fn scramble<const N: usize>(basis: &mut [Vector<i64, N>], rng: &mut SplitMix64, steps: usize) {
+        let n = basis.len() as i64;
+        for _ in 0..steps {
+            let i = rng.range_i64(0, n - 1) as usize;
+            let j = rng.range_i64(0, n - 1) as usize;
+            if i != j {
+                let c = rng.range_i64(-2, 2);
+                let multiple = basis[j];
+                for (x, y) in basis[i].components.iter_mut().zip(multiple.components) {
+                    *x += c * y;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lll_reduces_the_textbook_example() {
+        let mut basis = lattice([[1, 1, 1], [-1, 0, 2], [3, 5, 6]]);
+        let original = basis.clone();
+        lll_reduce(&mut basis, 0.75);
+        assert_eq!(basis, lattice([[0, 1, 0], [1, 0, 1], [-1, 0, 2]]));
+        assert_lll_reduced(&basis, 0.75);
+        assert_same_lattice(&original, &basis);
+    }
+
+    #[test]
+    fn lll_preserves_the_lattice() {
+        let mut rng = SplitMix64::new(203);
+        for _ in 0..50 {
+            let mut basis = lattice([[3, 1, 0], [0, 4, 1], [1, 0, 5]]);
+            scramble(&mut basis, &mut rng, 8);
+            let original = basis.clone();
+            lll_reduce(&mut basis, 0.75);
+            assert_lll_reduced(&basis, 0.75);
+            assert_same_lattice(&original, &basis);
+        }
+    }
+
+    #[test]
+    fn lll_finds_a_short_first_vector() {
+        let mut rng = SplitMix64::new(2030);
+        for _ in 0..50 {
+            // A scrambled basis of ℤ⁴, whose shortest vectors are the unit vectors.
+            let mut basis = lattice([[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]]);
+            scramble(&mut basis, &mut rng, 10);
+            lll_reduce(&mut basis, 0.99);
+            // ‖b₀‖² ≤ (δ − 1/4)^(−3) λ₁² ≈ 2.47 with λ₁ = 1, and ‖b₀‖² is an integer.
+            assert!(norm_squared(&basis[0]) <= 2, "{basis:?}");
+            assert_lll_reduced(&basis, 0.99);
+        }
+        // (1, 1) is the shortest vector of the lattice spanned by (1, 1) and (100, 101).
+        let mut basis = lattice([[100, 101], [1, 1]]);
+        lll_reduce(&mut basis, 0.75);
+        assert_eq!(norm_squared(&basis[0]), 1);
+    }
+
+    #[test]
+    fn lll_of_a_single_vector_is_unchanged() {
+        let mut basis = lattice([[4, -2, 7]]);
+        lll_reduce(&mut basis, 0.75);
+        assert_eq!(basis, lattice([[4, -2, 7]]));
+        let mut empty: Vec<Vector<i64, 3>> = Vec::new();
+        lll_reduce(&mut empty, 0.75);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "linearly independent")]
+    fn lll_rejects_dependent_vectors() {
+        lll_reduce(&mut lattice([[1, 2], [2, 4]]), 0.75);
+    }
+
+    #[test]
+    #[should_panic(expected = "1/4 < δ ≤ 1")]
+    fn lll_rejects_a_small_delta() {
+        lll_reduce(&mut lattice([[1, 2], [0, 3]]), 0.25);
+    }
+
+    /// Returns a shortest non-zero vector by trying every coefficient vector in a box.
+    fn brute_force_shortest(basis: &[Vector<i64, 2>], bound: i64) -> i64 {
+        let mut best = i64::MAX;
+        for x in -bound..=bound {
+            for y in -bound..=bound {
+                let v = Vector::new([
+                    x * basis[0][0] + y * basis[1][0],
+                    x * basis[0][1] + y * basis[1][1],
+                ]);
+                if !v.is_zero() {
+                    best = best.min(norm_squared(&v));
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn shortest_vector_of_a_planar_lattice() {
+        // A skewed basis of the lattice spanned by (1, 1) and (−3, 4), where every vector off the
+        // line through (1, 1) has squared length at least 24.5.
+        let basis = lattice([[112, 133], [37, 44]]);
+        let v = shortest_vector(&basis);
+        assert!(
+            v == Vector::new([1, 1]) || v == Vector::new([-1, -1]),
+            "{v:?}"
+        );
+        // The lattice spanned by (2, 0) and (1, 2) has Gram matrix [[4, 2], [2, 5]], so its
+        // shortest squared length is 4, attained only by ±(2, 0): the next shortest vectors,
+        // ±(1, 2) and ±(−1, 2), have squared length 5.
+        let v = shortest_vector(&lattice([[5, 2], [2, 0]]));
+        assert!(
+            v == Vector::new([2, 0]) || v == Vector::new([-2, 0]),
+            "{v:?}"
+        );
+    }
+
+    #[test]
+    fn shortest_vector_matches_brute_force() {
+        let mut rng = SplitMix64::new(204);
+        for _ in 0..100 {
+            let mut basis = lattice([
+                [rng.range_i64(-20, 20), rng.range_i64(-20, 20)],
+                [rng.range_i64(-20, 20), rng.range_i64(-20, 20)],
+            ]);
+            if basis[0][0] * basis[1][1] == basis[0][1] * basis[1][0] {
+                continue;
+            }
+            let original = basis.clone();
+            scramble(&mut basis, &mut rng, 4);
+            let v = shortest_vector(&basis);
+            assert_eq!(
+                norm_squared(&v),
+                brute_force_shortest(&original, 60),
+                "{original:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn closest_vector_of_a_planar_lattice() {
+        // The lattice 3ℤ × 5ℤ, presented through a skewed basis.
+        let basis = lattice([[3, 5], [6, 15]]);
+        assert_eq!(
+            closest_vector(&basis, &Vector::new([4, 6])),
+            Vector::new([3, 5])
+        );
+        assert_eq!(
+            closest_vector(&basis, &Vector::new([-8, 9])),
+            Vector::new([-9, 10])
+        );
+        assert_eq!(
+            closest_vector(&basis, &Vector::new([6, 0])),
+            Vector::new([6, 0])
+        );
+        // A skewed basis of ℤ², which contains every integer target.
+        let basis = lattice([[1, 0], [50, 1]]);
+        assert_eq!(
+            closest_vector(&basis, &Vector::new([7, 3])),
+            Vector::new([7, 3])
+        );
+    }
+
+    #[test]
+    fn closest_vector_of_lattice_points_is_themselves() {
+        let basis = lattice([[2, 1, 0], [0, 3, 1], [1, 0, 4]]);
+        for (x, y, z) in [(1, 0, 0), (-2, 3, 1), (4, -1, -3)] {
+            let point = Vector::new([2 * x + z, x + 3 * y, y + 4 * z]);
+            assert_eq!(closest_vector(&basis, &point), point);
+        }
+        let empty: Vec<Vector<i64, 2>> = Vec::new();
+        assert_eq!(closest_vector(&empty, &Vector::new([3, 4])), Vector::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "no non-zero vector")]
+    fn shortest_vector_of_no_vectors_panics() {
+        let empty: Vec<Vector<i64, 2>> = Vec::new();
+        shortest_vector(&empty);
+    }
+}